@@ -48,7 +48,7 @@ impl Person {
 }
 
 fn main() {
-    let mut person = Person::new("John".to_string(), "Smith".to_string());
+    let person = Person::new("John".to_string(), "Smith".to_string());
 
     println!("My name is {}. My id is {}", person.full_name(), person.id);
     println!("Serialized json ...");
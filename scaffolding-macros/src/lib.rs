@@ -1,17 +1,41 @@
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Parse, ParseStream, Parser, Result};
 use syn::Expr::Struct;
 use syn::FieldValue;
 use syn::Member;
-use syn::{parse_macro_input, parse_quote, punctuated::Punctuated, ItemStruct, LitStr, Token};
+use syn::{parse_macro_input, parse_quote, punctuated::Punctuated, LitStr, Token};
 
 static ADDRESS: &str = "addresses";
+static ATTACHMENTS: &str = "attachments";
 static EMAIL: &str = "email_addresses";
 static METADATA: &str = "metadata";
+static METADATA_JSON: &str = "metadata_json";
 static PHONE: &str = "phone_numbers";
+static RELATIONSHIPS: &str = "relationships";
+static SKIP_EMPTY: &str = "skip_empty";
+static SOFT_DELETE: &str = "soft_delete";
+static STATUS: &str = "status";
 static NOTES: &str = "notes";
+static OWNERSHIP: &str = "ownership";
 static TAGS: &str = "tags";
+static TENANT: &str = "tenant";
+static ALL: &str = "all";
+static ALL_COLLECTIONS: [&str; 9] = [
+    ADDRESS,
+    ATTACHMENTS,
+    EMAIL,
+    METADATA,
+    METADATA_JSON,
+    NOTES,
+    PHONE,
+    RELATIONSHIPS,
+    TAGS,
+];
+static CONSTRUCTOR: &str = "constructor";
+static DEFAULT: &str = "default";
+static REQUIRED_ATTRS: [&str; 3] = ["id", "created_dtm", "modified_dtm"];
+static EXCLUDABLE_ATTRS: [&str; 3] = ["inactive_dtm", "expired_dtm", "activity"];
 static CORE_ATTRS: [&str; 6] = [
     "id",
     "created_dtm",
@@ -23,7 +47,8 @@ static CORE_ATTRS: [&str; 6] = [
 
 /// Modifying a struct
 ///
-/// Dynammically adds the following public attributes to the struct
+/// Dynammically adds the following public attributes to the struct, (or to each variant of an enum)
+/// The generic parameters and lifetimes of the struct are kept, (e.g.: `struct View<'a> { name: &'a str }`)
 /// + id: String
 /// + created_dtm: i64
 /// + modified_dtm: i64
@@ -33,156 +58,780 @@ static CORE_ATTRS: [&str; 6] = [
 ///
 /// Optionally
 /// + addresses: BTreeMap<String, Address>
+/// + attachments: BTreeMap<String, Attachment>
+/// + email_addresses: BTreeMap<String, EmailAddress>
 /// + metadata: BTreeMap<String, String>
 /// + notes: BTreeMap<String, Note>
+/// + owner: String
+/// + shared_with: Vec<(String, Permission)>
+/// + deleted_dtm: Option<i64>
+/// + status: String
 /// + phone_numbers: BTreeMap<String, PhoneNumber>
+/// + relationships: BTreeMap<String, Relationship>
 /// + tags: Vec<String>
+/// + tenant_id: String
+///
+/// The `"all"` option is shorthand for every optional collection,
+/// (addresses, attachments, email_addresses, metadata, notes, phone_numbers, relationships and tags)
+///
+/// The `"default"` option implements `Default`, (the scaffolding attributes use the same defaults as `#[scaffolding_fn]`
+/// and the other attributes use `Default`)
+///
+/// The `inactive_dtm`, `expired_dtm` and `activity` attributes can be left out using `exclude(...)`,
+/// (e.g.: `#[scaffolding_struct(exclude("activity", "expired_dtm"))]`) and the derives adapt to the missing attributes
+///
+/// The `"skip_empty"` option leaves the empty optional collections out of the serialized object,
+/// (e.g.: no `"notes":{}` or `"tags":[]`)
+///
+/// The `id_type = "<type>"` setting changes the type of the `id` attribute, (e.g.: `id_type = "u64"`)
+/// The type must implement `ScaffoldingId`, (`String`, `u64` and `u128` do)
+///
+/// The `rename_all = "<case>"` setting is passed through to serde, (e.g.: `#[scaffolding_struct("notes", rename_all = "camelCase")]`)
+/// and serde attributes can be added to the generated attributes using `#[scaffolding(<attribute>(serde(...)))]`,
+/// (e.g.: `#[scaffolding(created_dtm(serde(rename = "createdAt")))]`)
 ///
 #[proc_macro_attribute]
 pub fn scaffolding_struct(args: TokenStream, input: TokenStream) -> TokenStream {
-    let mut item_struct: ItemStruct = parse_macro_input!(input as ItemStruct);
-    let attrs = parse_macro_input!(args as Args)
-        .vars
+    let mut item: syn::Item = parse_macro_input!(input as syn::Item);
+    let args = parse_macro_input!(args as Args);
+    let settings = args.settings.clone();
+    let excludes = exclusions(&args);
+    let attrs = options(args);
+
+    match &mut item {
+        syn::Item::Struct(item_struct) => {
+            if let syn::Fields::Named(ref mut fields) = item_struct.fields {
+                add_fields(fields, &attrs, &excludes);
+            }
+        }
+        syn::Item::Enum(item_enum) => {
+            // the attributes are added to each variant
+            for variant in item_enum.variants.iter_mut() {
+                match variant.fields {
+                    syn::Fields::Named(ref mut fields) => {
+                        add_fields(fields, &attrs, &excludes);
+                        // enum variant attributes can't have a visibility
+                        for field in fields.named.iter_mut() {
+                            field.vis = syn::Visibility::Inherited;
+                        }
+                    }
+                    _ => {
+                        return syn::Error::new_spanned(
+                            &*variant,
+                            "scaffolding_struct only supports enum variants with named fields",
+                        )
+                        .to_compile_error()
+                        .into()
+                    }
+                }
+            }
+        }
+        _ => {
+            return syn::Error::new_spanned(&item, "scaffolding_struct expects a struct or enum")
+                .to_compile_error()
+                .into()
+        }
+    }
+
+    if let Err(err) = add_serde_attributes(&mut item, &settings) {
+        return err.to_compile_error().into();
+    }
+    if let Err(err) = set_id_type(&mut item, &settings) {
+        return err.to_compile_error().into();
+    }
+
+    let default = match attrs.contains(&DEFAULT.to_string()) {
+        true => impl_default(&item),
+        false => quote! {},
+    };
+
+    return quote! {
+        #item
+        #default
+    }
+    .into();
+}
+
+// changes the type of the `id` attribute, (e.g.: `id_type = "u64"`)
+fn set_id_type(item: &mut syn::Item, settings: &[(syn::Ident, LitStr)]) -> Result<()> {
+    let id_type: syn::Type = match settings.iter().find(|(key, _)| key == "id_type") {
+        Some((_, value)) => value.parse()?,
+        None => return Ok(()),
+    };
+    let fields: Vec<&mut syn::Fields> = match item {
+        syn::Item::Struct(item_struct) => vec![&mut item_struct.fields],
+        syn::Item::Enum(item_enum) => item_enum
+            .variants
+            .iter_mut()
+            .map(|v| &mut v.fields)
+            .collect(),
+        _ => Vec::new(),
+    };
+    for field in fields.into_iter().flat_map(|f| f.iter_mut()) {
+        if field.ident.as_ref().is_some_and(|i| i == "id") {
+            field.ty = id_type.clone();
+        }
+    }
+    Ok(())
+}
+
+// passes serde attributes through to the struct and its attributes,
+// (e.g.: `rename_all = "camelCase"` or `#[scaffolding(created_dtm(serde(rename = "createdAt")))]`)
+fn add_serde_attributes(item: &mut syn::Item, settings: &[(syn::Ident, LitStr)]) -> Result<()> {
+    let mut container: Vec<syn::Attribute> = Vec::new();
+    for (key, value) in settings.iter() {
+        match key.to_string().as_str() {
+            "rename_all" => container.push(parse_quote! { #[serde(rename_all = #value)] }),
+            "id_type" => {}
+            _ => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "unsupported setting `{}`, (expected `id_type` or `rename_all`)",
+                        key
+                    ),
+                ))
+            }
+        }
+    }
+
+    // the #[scaffolding(<attribute>(serde(...)))] declarations are consumed
+    let item_attrs = match item {
+        syn::Item::Struct(item_struct) => &mut item_struct.attrs,
+        syn::Item::Enum(item_enum) => &mut item_enum.attrs,
+        _ => return Ok(()),
+    };
+    let mut declarations: Vec<syn::Meta> = Vec::new();
+    for attr in item_attrs
         .iter()
-        .map(|a| a.value())
-        .collect::<Vec<_>>();
+        .filter(|a| a.path().is_ident("scaffolding"))
+    {
+        declarations
+            .extend(attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)?);
+    }
+    item_attrs.retain(|a| !a.path().is_ident("scaffolding"));
 
-    if let syn::Fields::Named(ref mut fields) = item_struct.fields {
-        // The unique identifier of the object
-        fields.named.push(
-            syn::Field::parse_named
-                .parse2(quote! { pub id: String })
-                .unwrap(),
-        );
-        // The timestamp when the object was created
-        fields.named.push(
-            syn::Field::parse_named
-                .parse2(quote! { pub created_dtm: i64 })
-                .unwrap(),
-        );
-        // The timestamp when the object was last modified
-        fields.named.push(
-            syn::Field::parse_named
-                .parse2(quote! { pub modified_dtm: i64 })
-                .unwrap(),
-        );
-        // The timestamp when the object is no longer active
-        fields.named.push(
-            syn::Field::parse_named
-                .parse2(quote! { pub inactive_dtm: i64 })
-                .unwrap(),
-        );
-        // The timestamp when the object is expired
-        fields.named.push(
-            syn::Field::parse_named
-                .parse2(quote! { pub expired_dtm: i64 })
-                .unwrap(),
-        );
-
-        // The list of activity performed on the object
-        fields.named.push(
-            syn::Field::parse_named
-                .parse2(quote! { pub activity: Vec<ActivityItem> })
-                .unwrap(),
-        );
-
-        // optional attributes
-        match attrs.contains(&ADDRESS.to_string()) {
-            true => {
-                // The address handler
-                fields.named.push(
-                    syn::Field::parse_named
-                        .parse2(quote! { pub addresses: BTreeMap<String, Address> })
-                        .unwrap(),
-                );
+    let mut passthrough: Vec<(syn::Ident, syn::Attribute)> = Vec::new();
+    for declaration in declarations.iter() {
+        let list = declaration.require_list()?;
+        let field = list.path.require_ident()?;
+        for meta in list.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)? {
+            match meta.path().is_ident("serde") {
+                true => passthrough.push((field.clone(), parse_quote! { #[#meta] })),
+                false => {
+                    return Err(syn::Error::new_spanned(
+                        meta.path(),
+                        "only serde attributes can be passed through, (e.g.: serde(rename = \"createdAt\"))",
+                    ))
+                }
             }
-            false => {}
         }
+    }
 
-        match attrs.contains(&EMAIL.to_string()) {
-            true => {
-                // The phonenumber handler
-                fields.named.push(
-                    syn::Field::parse_named
-                        .parse2(quote! { pub email_addresses: BTreeMap<String, EmailAddress> })
-                        .unwrap(),
-                );
+    // enums rename the attributes of each variant
+    let mut targets: Vec<(&mut Vec<syn::Attribute>, &mut syn::Fields)> = match item {
+        syn::Item::Struct(item_struct) => vec![(&mut item_struct.attrs, &mut item_struct.fields)],
+        syn::Item::Enum(item_enum) => item_enum
+            .variants
+            .iter_mut()
+            .map(|v| (&mut v.attrs, &mut v.fields))
+            .collect(),
+        _ => Vec::new(),
+    };
+    for (attrs, fields) in targets.iter_mut() {
+        attrs.extend(container.iter().cloned());
+        for (field, attr) in passthrough.iter() {
+            match fields.iter_mut().find(|f| f.ident.as_ref() == Some(field)) {
+                Some(f) => f.attrs.push(attr.clone()),
+                None => {
+                    return Err(syn::Error::new(
+                        field.span(),
+                        format!("unknown attribute `{}`", field),
+                    ))
+                }
             }
-            false => {}
         }
+    }
 
-        // optional attributes
-        match attrs.contains(&METADATA.to_string()) {
-            true => {
-                // The metadata handler
-                fields.named.push(
-                    syn::Field::parse_named
-                        .parse2(quote! { pub metadata: BTreeMap<String, String> })
-                        .unwrap(),
-                );
+    Ok(())
+}
+
+// implements Default using the defaults for the scaffolding attributes and Default for the other attributes
+fn impl_default(item: &syn::Item) -> proc_macro2::TokenStream {
+    let item_struct = match item {
+        syn::Item::Struct(item_struct) => item_struct,
+        _ => {
+            return syn::Error::new_spanned(item, "the \"default\" option only supports structs")
+                .to_compile_error()
+        }
+    };
+    let name = &item_struct.ident;
+    let mut generics = item_struct.generics.clone();
+    let params: Vec<syn::Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    for param in params.iter() {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { #param: Default });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let values = item_struct.fields.iter().map(|f| {
+        let ident = f.ident.as_ref().unwrap();
+        let default = default_value(&ident.to_string());
+        quote! { #ident: #default }
+    });
+
+    quote! {
+        impl #impl_generics Default for #name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#values,)*
+                }
             }
-            false => {}
         }
+    }
+}
 
-        // optional attributes
-        match attrs.contains(&NOTES.to_string()) {
-            true => {
-                // The notes handler
-                fields.named.push(
-                    syn::Field::parse_named
-                        .parse2(quote! { pub notes: BTreeMap<String, Note> })
-                        .unwrap(),
-                );
+// adds the core and optional attributes to the named fields
+fn add_fields(fields: &mut syn::FieldsNamed, attrs: &[String], excludes: &[String]) {
+    // The unique identifier of the object
+    fields.named.push(
+        syn::Field::parse_named
+            .parse2(quote! {
+                #[doc = "The unique identifier of the object, (defaults to a generated id, e.g.: uuid v4)"]
+                pub id: String
+            })
+            .unwrap(),
+    );
+    // The timestamp when the object was created
+    fields.named.push(
+        syn::Field::parse_named
+            .parse2(quote! {
+                #[doc = "The timestamp (unix epoch seconds) when the object was created, (defaults to now)"]
+                pub created_dtm: i64
+            })
+            .unwrap(),
+    );
+    // The timestamp when the object was last modified
+    fields.named.push(
+        syn::Field::parse_named
+            .parse2(quote! {
+                #[doc = "The timestamp (unix epoch seconds) when the object was last modified, (defaults to now)"]
+                pub modified_dtm: i64
+            })
+            .unwrap(),
+    );
+    match excludes.contains(&"inactive_dtm".to_string()) {
+        true => {}
+        false => {
+            // The timestamp when the object is no longer active
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The timestamp (unix epoch seconds) when the object is no longer active, (defaults to 90 days from now)"]
+                        pub inactive_dtm: i64
+                    })
+                    .unwrap(),
+            );
+        }
+    }
+    match excludes.contains(&"expired_dtm".to_string()) {
+        true => {}
+        false => {
+            // The timestamp when the object is expired
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The timestamp (unix epoch seconds) when the object is expired, (defaults to 3 years from now)"]
+                        pub expired_dtm: i64
+                    })
+                    .unwrap(),
+            );
+        }
+    }
+    match excludes.contains(&"activity".to_string()) {
+        true => {}
+        false => {
+            // The list of activity performed on the object
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The list of activity performed on the object, (defaults to empty)"]
+                        pub activity: Vec<::scaffolding_core::ActivityItem>
+                    })
+                    .unwrap(),
+            );
+        }
+    }
+
+    // optional attributes
+    match attrs.contains(&ADDRESS.to_string()) {
+        true => {
+            // The address handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The addresses of the object keyed by their id, (defaults to empty)"]
+                        pub addresses: ::std::collections::BTreeMap<String, ::scaffolding_core::Address>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&ATTACHMENTS.to_string()) {
+        true => {
+            // The attachments handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The attachments of the object keyed by their id, (defaults to empty)"]
+                        pub attachments: ::std::collections::BTreeMap<String, ::scaffolding_core::Attachment>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&EMAIL.to_string()) {
+        true => {
+            // The phonenumber handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The email addresses of the object keyed by their id, (defaults to empty)"]
+                        pub email_addresses: ::std::collections::BTreeMap<String, ::scaffolding_core::EmailAddress>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    // optional attributes
+    match attrs.contains(&METADATA.to_string()) {
+        true => {
+            // The metadata handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The metadata of the object as key/value pairs, (defaults to empty)"]
+                        pub metadata: ::std::collections::BTreeMap<String, String>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    // optional attributes
+    match attrs.contains(&METADATA_JSON.to_string()) {
+        true => {
+            // The JSON metadata handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The metadata of the object as key/JSON value pairs, (defaults to empty)"]
+                        pub metadata_json: ::std::collections::BTreeMap<String, ::scaffolding_core::Value>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    // optional attributes
+    match attrs.contains(&NOTES.to_string()) {
+        true => {
+            // The notes handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The notes of the object keyed by their id, (defaults to empty)"]
+                        pub notes: ::std::collections::BTreeMap<String, ::scaffolding_core::Note>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&OWNERSHIP.to_string()) {
+        true => {
+            // The identifier of the principal that owns the object
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The identifier of the principal that owns the object, (defaults to an empty string)"]
+                        pub owner: String
+                    })
+                    .unwrap(),
+            );
+            // The principals the object is shared with and their permission
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The principals the object is shared with and their permission, (defaults to empty)"]
+                        pub shared_with: Vec<(String, ::scaffolding_core::Permission)>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&PHONE.to_string()) {
+        true => {
+            // The phonenumber handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The phone numbers of the object keyed by their id, (defaults to empty)"]
+                        pub phone_numbers: ::std::collections::BTreeMap<String, ::scaffolding_core::PhoneNumber>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&RELATIONSHIPS.to_string()) {
+        true => {
+            // The relationships handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The relationships of the object keyed by their id, (defaults to empty)"]
+                        pub relationships: ::std::collections::BTreeMap<String, ::scaffolding_core::Relationship>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    // optional attributes
+    match attrs.contains(&TAGS.to_string()) {
+        true => {
+            // The tags handler
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The tags of the object, (defaults to empty)"]
+                        pub tags: Vec<String>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&SOFT_DELETE.to_string()) {
+        true => {
+            // The timestamp when the object was soft deleted
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The timestamp (unix epoch seconds) when the object was soft deleted, (defaults to None)"]
+                        pub deleted_dtm: Option<i64>
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&STATUS.to_string()) {
+        true => {
+            // The workflow status of the object
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The workflow status of the object, (defaults to \"draft\")"]
+                        pub status: String
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&TENANT.to_string()) {
+        true => {
+            // The identifier of the tenant that owns the object
+            fields.named.push(
+                syn::Field::parse_named
+                    .parse2(quote! {
+                        #[doc = "The identifier of the tenant that owns the object, (defaults to an empty string)"]
+                        pub tenant_id: String
+                    })
+                    .unwrap(),
+            );
+        }
+        false => {}
+    }
+
+    match attrs.contains(&SKIP_EMPTY.to_string()) {
+        true => {
+            // The empty optional collections are left out of the serialized object
+            for field in fields.named.iter_mut() {
+                let ident = field.ident.as_ref().unwrap().to_string();
+                if ALL_COLLECTIONS.contains(&ident.as_str()) {
+                    let is_empty = match ident == TAGS {
+                        true => "::std::vec::Vec::is_empty",
+                        false => "::std::collections::BTreeMap::is_empty",
+                    };
+                    field
+                        .attrs
+                        .push(parse_quote! { #[serde(default, skip_serializing_if = #is_empty)] });
+                }
             }
-            false => {}
         }
+        false => {}
+    }
+}
 
-        match attrs.contains(&PHONE.to_string()) {
-            true => {
-                // The phonenumber handler
-                fields.named.push(
-                    syn::Field::parse_named
-                        .parse2(quote! { pub phone_numbers: BTreeMap<String, PhoneNumber> })
-                        .unwrap(),
-                );
+// lists the names of the struct's attributes, (or the attributes that all the enum's variants have)
+fn field_names(ast: &syn::DeriveInput) -> Vec<String> {
+    let names = |fields: &syn::Fields| -> Vec<String> {
+        fields
+            .iter()
+            .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+            .collect()
+    };
+    match &ast.data {
+        syn::Data::Struct(data) => names(&data.fields),
+        syn::Data::Enum(data) => {
+            let mut variants = data.variants.iter();
+            let mut common = match variants.next() {
+                Some(variant) => names(&variant.fields),
+                None => Vec::new(),
+            };
+            for variant in variants {
+                let fields = names(&variant.fields);
+                common.retain(|f| fields.contains(f));
             }
-            false => {}
+            common
         }
+        _ => Vec::new(),
+    }
+}
 
-        // optional attributes
-        match attrs.contains(&TAGS.to_string()) {
-            true => {
-                // The tags handler
-                fields.named.push(
-                    syn::Field::parse_named
-                        .parse2(quote! { pub tags: Vec<String> })
-                        .unwrap(),
-                );
+// reports the attributes a derive needs that the struct doesn't have,
+// (e.g.: deriving `ScaffoldingNotes` without `#[scaffolding_struct("notes")]`)
+fn missing_fields(
+    ast: &syn::DeriveInput,
+    derive: &str,
+    option: Option<&str>,
+    fields: &[&str],
+) -> Option<TokenStream> {
+    let names = field_names(ast);
+    let missing: Vec<&str> = fields
+        .iter()
+        .filter(|f| !names.contains(&f.to_string()))
+        .copied()
+        .collect();
+    if missing.is_empty() {
+        return None;
+    }
+    let msg = match option {
+        Some(option) => format!(
+            "#[derive({})] requires the `{}` option, add it to the attribute macro: #[scaffolding_struct(\"{}\")]",
+            derive, option, option
+        ),
+        None => format!(
+            "#[derive({})] requires the scaffolding attributes ({}), add #[scaffolding_struct] above the #[derive]",
+            derive,
+            missing.join(", ")
+        ),
+    };
+    Some(
+        syn::Error::new(ast.ident.span(), msg)
+            .to_compile_error()
+            .into(),
+    )
+}
+
+// the type of the struct's attribute, (or of the attribute in the first variant of the enum)
+fn field_type(ast: &syn::DeriveInput, attr: &str) -> Option<syn::Type> {
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => &data.fields,
+        syn::Data::Enum(data) => &data.variants.first()?.fields,
+        _ => return None,
+    };
+    fields
+        .iter()
+        .find(|f| f.ident.as_ref().is_some_and(|i| i == attr))
+        .map(|f| f.ty.clone())
+}
+
+// finalizes the trait implementation
+// enums don't have attributes, so `self.<attribute>` is rewritten to match the attribute in every variant
+fn expand(ast: &syn::DeriveInput, gen: proc_macro2::TokenStream) -> TokenStream {
+    expand_receivers(ast, gen, &["self"])
+}
+
+fn expand_receivers(
+    ast: &syn::DeriveInput,
+    gen: proc_macro2::TokenStream,
+    receivers: &[&str],
+) -> TokenStream {
+    match &ast.data {
+        syn::Data::Enum(data) => {
+            let variants: Vec<&syn::Ident> = data.variants.iter().map(|v| &v.ident).collect();
+            rewrite_attributes(gen, &field_names(ast), &variants, receivers).into()
+        }
+        _ => gen.into(),
+    }
+}
+
+fn rewrite_attributes(
+    tokens: proc_macro2::TokenStream,
+    fields: &[String],
+    variants: &[&syn::Ident],
+    receivers: &[&str],
+) -> proc_macro2::TokenStream {
+    use proc_macro2::{Delimiter, Group, TokenTree};
+
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut rewritten: Vec<TokenTree> = Vec::new();
+    let mut i = 0;
+
+    while i < trees.len() {
+        // look for <receiver>.<attribute> that isn't a method call
+        let access = match (&trees[i], trees.get(i + 1), trees.get(i + 2)) {
+            (TokenTree::Ident(recv), Some(TokenTree::Punct(dot)), Some(TokenTree::Ident(attr)))
+                if receivers.contains(&recv.to_string().as_str())
+                    && dot.as_char() == '.'
+                    && fields.contains(&attr.to_string()) =>
+            {
+                match trees.get(i + 3) {
+                    Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => None,
+                    _ => Some((recv.clone(), attr.clone())),
+                }
+            }
+            _ => None,
+        };
+
+        match access {
+            Some((recv, attr)) => {
+                // the references, (self and other) are matched as is and the owned values are borrowed
+                let scrutinee = match recv == "self" || recv == "other" {
+                    true => quote! { #recv },
+                    false => quote! { &mut #recv },
+                };
+                let expr = quote! {
+                    (*match #scrutinee { #(Self::#variants { #attr, .. })|* => #attr })
+                };
+                rewritten.extend(expr);
+                i += 3;
+            }
+            None => {
+                match &trees[i] {
+                    TokenTree::Group(g) => {
+                        let mut group = Group::new(
+                            g.delimiter(),
+                            rewrite_attributes(g.stream(), fields, variants, receivers),
+                        );
+                        group.set_span(g.span());
+                        rewritten.push(TokenTree::Group(group));
+                    }
+                    tree => rewritten.push(tree.clone()),
+                }
+                i += 1;
             }
-            false => {}
         }
     }
 
-    return quote! {
-        #item_struct
+    rewritten.into_iter().collect()
+}
+
+// lists the options, ("all" is shorthand for every optional collection)
+fn options(args: Args) -> Vec<String> {
+    let mut attrs = Vec::new();
+    for arg in args.vars.iter().map(|a| a.value()) {
+        match arg == ALL {
+            true => attrs.extend(ALL_COLLECTIONS.iter().map(|c| c.to_string())),
+            false => attrs.push(arg),
+        }
     }
-    .into();
+    attrs
+}
+
+// lists the core attributes that are left out, (e.g.: exclude("activity"))
+fn exclusions(args: &Args) -> Vec<String> {
+    args.excludes.iter().map(|a| a.value()).collect()
 }
 
 #[derive(Debug)]
 struct Args {
     pub vars: Vec<LitStr>,
+    pub settings: Vec<(syn::Ident, LitStr)>,
+    pub excludes: Vec<LitStr>,
 }
 
 impl Parse for Args {
     fn parse(input: ParseStream) -> Result<Self> {
-        let vars = Punctuated::<syn::LitStr, Token![,]>::parse_terminated(input)?;
+        let args = Punctuated::<Arg, Token![,]>::parse_terminated(input)?;
+        let mut vars = Vec::new();
+        let mut settings = Vec::new();
+        let mut excludes = Vec::new();
+        for arg in args.into_iter() {
+            match arg {
+                Arg::Var(var) => vars.push(var),
+                Arg::Setting(key, value) => settings.push((key, value)),
+                Arg::Exclude(attrs) => excludes.extend(attrs),
+            }
+        }
         Ok(Args {
-            vars: vars.into_iter().collect::<Vec<LitStr>>(),
+            vars,
+            settings,
+            excludes,
         })
     }
 }
 
+// an option, (e.g.: "notes"), a setting, (e.g.: rename_all = "camelCase")
+// or the core attributes to leave out, (e.g.: exclude("activity", "expired_dtm"))
+#[derive(Debug)]
+enum Arg {
+    Var(LitStr),
+    Setting(syn::Ident, LitStr),
+    Exclude(Vec<LitStr>),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        match input.peek(LitStr) {
+            true => Ok(Arg::Var(input.parse()?)),
+            false => {
+                let key: syn::Ident = input.parse()?;
+                if key == "exclude" {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    let attrs = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                    for attr in attrs.iter() {
+                        if !EXCLUDABLE_ATTRS.contains(&attr.value().as_str()) {
+                            return Err(syn::Error::new(
+                                attr.span(),
+                                format!(
+                                    "only the {} attributes can be excluded",
+                                    EXCLUDABLE_ATTRS.join(", ")
+                                ),
+                            ));
+                        }
+                    }
+                    return Ok(Arg::Exclude(attrs.into_iter().collect()));
+                }
+                input.parse::<Token![=]>()?;
+                Ok(Arg::Setting(key, input.parse()?))
+            }
+        }
+    }
+}
+
 ///
 /// Implementing the Traits
 ///
@@ -197,19 +846,211 @@ pub fn scaffolding_derive(input: TokenStream) -> TokenStream {
 }
 
 fn impl_scaffolding(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "Scaffolding", None, &REQUIRED_ATTRS) {
+        return err;
+    }
     let name = &ast.ident;
-    let gen = quote! {
-        impl Scaffolding for #name {
-            fn get_activity(&self, name: String) -> Vec<ActivityItem>{
-                self.activity.iter().filter(|a| a.action == name).cloned().collect()
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = field_names(ast);
+    // only soft deletable objects can be deleted
+    let is_deleted = match fields.contains(&"deleted_dtm".to_string()) {
+        true => quote! {
+            fn is_deleted(&self) -> bool {
+                self.deleted_dtm.is_some()
+            }
+        },
+        false => quote! {},
+    };
+    // the activity log can be excluded, (e.g.: exclude("activity")) in which case nothing is logged
+    let activity = match fields.contains(&"activity".to_string()) {
+        true => quote! {
+            fn get_activity(&self, name: String) -> Vec<::scaffolding_core::ActivityItem>{
+                ::scaffolding_core::Scaffolding::get_activity_ref(self, &name).into_iter().cloned().collect()
+            }
+
+            fn get_activity_ref(&self, name: &str) -> Vec<&::scaffolding_core::ActivityItem>{
+                self.activity.iter().filter(|a| a.action == name).collect()
+            }
+
+            fn export_activity(&self, format: ::scaffolding_core::ExportFormat, start: Option<i64>, end: Option<i64>) -> String {
+                let items: Vec<::scaffolding_core::ActivityItem> = self.activity
+                    .iter()
+                    .filter(|a| a.created_dtm >= start.unwrap_or(i64::MIN) && a.created_dtm <= end.unwrap_or(i64::MAX))
+                    .cloned()
+                    .collect();
+                ::scaffolding_core::ActivityItem::export(&items, format)
             }
 
             fn log_activity(&mut self, name: String, descr: String) {
-                self.activity.push(ActivityItem::new(name, descr));
+                ::scaffolding_core::ActivityItem::append(&mut self.activity, ::scaffolding_core::ActivityItem::new(name, descr));
+            }
+
+            fn log_activity_with_data(&mut self, name: String, descr: String, data: ::scaffolding_core::Value) {
+                ::scaffolding_core::ActivityItem::append(&mut self.activity, ::scaffolding_core::ActivityItem::new_with_data(name, descr, data));
+            }
+
+            fn redact_activity(&mut self, predicate: &dyn Fn(&::scaffolding_core::ActivityItem) -> bool) -> usize {
+                let chained = self.activity.last().is_some_and(|a| a.hash.is_some());
+                let mut count = 0;
+                ::scaffolding_core::ActivityItem::rechain(&mut self.activity, |log| {
+                    for item in log.iter_mut().filter(|a| predicate(a)) {
+                        item.redact();
+                        count += 1;
+                    }
+                    // the redaction explains why the hashes of the tamper-evident activity log have changed
+                    if chained && count > 0 {
+                        ::scaffolding_core::ActivityItem::append(log, ::scaffolding_core::ActivityItem::new_with_data(
+                            "redacted".to_string(),
+                            format!("{} activity items have been redacted", count),
+                            ::scaffolding_core::json!({"count": count}),
+                        ));
+                    }
+                });
+                count
+            }
+
+            fn enable_activity_chain(&mut self) {
+                // re-hashing an already chained activity log would hide any tampering
+                if self.activity.last().is_some_and(|a| a.hash.is_some()) {
+                    return;
+                }
+                self.activity.push(::scaffolding_core::ActivityItem::new(
+                    "chained".to_string(),
+                    "The activity log is tamper-evident".to_string(),
+                ));
+                // only the unchained ActivityItems are hashed, (chained to the last hashed one)
+                match self.activity.iter().rposition(|a| a.hash.is_some()) {
+                    Some(last) => {
+                        for item in self.activity.split_off(last + 1) {
+                            ::scaffolding_core::ActivityItem::append(&mut self.activity, item);
+                        }
+                    }
+                    None => ::scaffolding_core::ActivityItem::chain_all(&mut self.activity),
+                }
+            }
+
+            fn verify_activity_chain(&self) -> bool {
+                ::scaffolding_core::ActivityItem::verify_chain(&self.activity)
+            }
+        },
+        false => quote! {
+            fn get_activity(&self, _name: String) -> Vec<::scaffolding_core::ActivityItem>{
+                Vec::new()
+            }
+
+            fn get_activity_ref(&self, _name: &str) -> Vec<&::scaffolding_core::ActivityItem>{
+                Vec::new()
+            }
+
+            fn export_activity(&self, format: ::scaffolding_core::ExportFormat, _start: Option<i64>, _end: Option<i64>) -> String {
+                ::scaffolding_core::ActivityItem::export(&[], format)
+            }
+
+            fn log_activity(&mut self, _name: String, _descr: String) {}
+
+            fn log_activity_with_data(&mut self, _name: String, _descr: String, _data: ::scaffolding_core::Value) {}
+
+            fn redact_activity(&mut self, _predicate: &dyn Fn(&::scaffolding_core::ActivityItem) -> bool) -> usize {
+                0
+            }
+
+            fn enable_activity_chain(&mut self) {}
+
+            // without an activity log there is nothing tamper-evident to verify
+            fn verify_activity_chain(&self) -> bool {
+                false
+            }
+        },
+    };
+    // the lifecycle timestamps can be excluded, (e.g.: exclude("expired_dtm")) in which case they are not tracked
+    let (deactivate, reactivate_inactive) = match fields.contains(&"inactive_dtm".to_string()) {
+        true => (
+            quote! { self.inactive_dtm = ::scaffolding_core::defaults::now(); },
+            quote! { self.inactive_dtm = ::scaffolding_core::defaults::add_days(now, inactive_days); },
+        ),
+        false => (quote! {}, quote! { let _ = inactive_days; }),
+    };
+    let (expire, reactivate_check, reactivate_expired) = match fields
+        .contains(&"expired_dtm".to_string())
+    {
+        true => (
+            quote! { self.expired_dtm = ::scaffolding_core::defaults::now(); },
+            quote! {
+                if self.expired_dtm <= now {
+                    return Err(::scaffolding_core::errors::ScaffoldingError::Recovery);
+                }
+            },
+            quote! { self.expired_dtm = ::scaffolding_core::defaults::add_days(now, expire_days); },
+        ),
+        false => (quote! {}, quote! {}, quote! { let _ = expire_days; }),
+    };
+    let id_type = field_type(ast, "id");
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::Scaffolding for #name #ty_generics #where_clause {
+            type Id = #id_type;
+
+            #is_deleted
+
+            fn get_id(&self) -> Self::Id {
+                self.id.clone()
+            }
+
+            #activity
+
+            fn deactivate(&mut self) {
+                #deactivate
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                ::scaffolding_core::Scaffolding::log_activity(self, "deactivated".to_string(), "The object has been deactivated".to_string());
+            }
+
+            fn expire_now(&mut self) {
+                #expire
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                ::scaffolding_core::Scaffolding::log_activity(self, "expired".to_string(), "The object has been expired".to_string());
+            }
+
+            fn reactivate(&mut self, inactive_days: i64, expire_days: i64) -> Result<(), ::scaffolding_core::errors::ScaffoldingError> {
+                let now = ::scaffolding_core::defaults::now();
+                #reactivate_check
+                #reactivate_inactive
+                #reactivate_expired
+                self.modified_dtm = now;
+                ::scaffolding_core::Scaffolding::log_activity(self, "reactivated".to_string(), "The object has been reactivated".to_string());
+                Ok(())
             }
         }
     };
-    gen.into()
+    expand(ast, gen)
+}
+
+// Full
+/// Implements `Scaffolding` and the behavior traits of the optional collections that the struct has,
+/// (`ScaffoldingAddresses`, `ScaffoldingAttachments`, `ScaffoldingEmailAddresses`, `ScaffoldingMetadata`,
+/// `ScaffoldingMetadataJson`, `ScaffoldingNotes`, `ScaffoldingPhoneNumbers`, `ScaffoldingRelationships` and `ScaffoldingTags`)
+#[proc_macro_derive(ScaffoldingFull, attributes(scaffolding_metadata))]
+pub fn scaffolding_full_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let fields = field_names(&ast);
+    let mut gen = impl_scaffolding(&ast);
+
+    let behaviors: [(&str, fn(&syn::DeriveInput) -> TokenStream); 9] = [
+        (ADDRESS, impl_scaffolding_addresses),
+        (ATTACHMENTS, impl_scaffolding_attachments),
+        (EMAIL, impl_scaffolding_emailaddresses),
+        (METADATA, impl_scaffolding_metadata),
+        (METADATA_JSON, impl_scaffolding_metadata_json),
+        (NOTES, impl_scaffolding_notes),
+        (PHONE, impl_scaffolding_phonenumbers),
+        (RELATIONSHIPS, impl_scaffolding_relationships),
+        (TAGS, impl_scaffolding_tags),
+    ];
+    for (attr, behavior) in behaviors.iter() {
+        if fields.contains(&attr.to_string()) {
+            gen.extend(behavior(&ast));
+        }
+    }
+
+    gen
 }
 
 // Addresses Trait
@@ -221,26 +1062,103 @@ pub fn scaffolding_addresses_derive(input: TokenStream) -> TokenStream {
 }
 
 fn impl_scaffolding_addresses(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingAddresses", Some(ADDRESS), &["addresses"]) {
+        return err;
+    }
     let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // address verification is only available when the `verification` feature is enabled
+    let verification = match cfg!(feature = "verification") {
+        true => quote! {
+            fn verify_address<V>(
+                &mut self,
+                id: String,
+                verifier: &V,
+            ) -> impl ::std::future::Future<Output = Result<::scaffolding_core::AddressVerification, ::scaffolding_core::errors::ScaffoldingError>> + Send
+            where
+                Self: Send + Sized,
+                V: ::scaffolding_core::AddressVerifier + Sync,
+            {
+                async move {
+                    let address = match self.addresses.get(&id) {
+                        Some(address) => address.clone(),
+                        None => return Err(::scaffolding_core::errors::ScaffoldingError::NotFound { id }),
+                    };
+                    let verification = verifier.verify(&address).await?;
+
+                    if let Some(address) = self.addresses.get_mut(&id) {
+                        address.verification = Some(verification.clone());
+                        address.modified_dtm = ::scaffolding_core::defaults::now();
+                    }
+                    ::scaffolding_core::Scaffolding::log_activity(
+                        self,
+                        "verified".to_string(),
+                        format!("The address {} has been verified", id),
+                    );
+                    Ok(verification)
+                }
+            }
+        },
+        false => quote! {},
+    };
     let gen = quote! {
-        impl ScaffoldingAddresses for #name {
-            fn get_address(&self, id: String) -> Option<&Address> {
+        impl #impl_generics ::scaffolding_core::ScaffoldingAddresses for #name #ty_generics #where_clause {
+            fn get_address(&self, id: String) -> Option<&::scaffolding_core::Address> {
                 self.addresses.get(&id)
             }
 
-            fn insert_address(
-                &mut self,
-                category: String,
-                line_1: String,
-                line_2: String,
-                line_3: String,
-                line_4: String,
-                country_code: String,
-            ) -> String {
-                let address = Address::new(category, line_1, line_2, line_3, line_4, country_code);
-                let id = address.id.clone();
-                self.addresses.insert(id.clone(), address);
-                id
+            fn address_as_of(&self, dtm: i64, category: String) -> Option<&::scaffolding_core::Address> {
+                self.addresses
+                    .values()
+                    .filter(|v| v.category == category && v.is_valid_at(dtm))
+                    .max_by_key(|v| (v.primary, v.valid_from.unwrap_or(i64::MIN), v.created_dtm))
+            }
+
+            fn current_address(&self, category: String) -> Option<&::scaffolding_core::Address> {
+                ::scaffolding_core::ScaffoldingAddresses::address_as_of(self, ::scaffolding_core::defaults::now(), category)
+            }
+
+            fn get_primary_address(&self, category: String) -> Option<&::scaffolding_core::Address> {
+                self.addresses.values().find(|v| v.primary && v.category == category)
+            }
+
+            fn set_primary_address(&mut self, id: String) {
+                let category = match self.addresses.get(&id) {
+                    Some(v) => v.category.clone(),
+                    None => return,
+                };
+                for v in self.addresses.values_mut().filter(|v| v.category == category) {
+                    let primary = v.id == id;
+                    if v.primary != primary {
+                        v.primary = primary;
+                        v.modified_dtm = ::scaffolding_core::defaults::now();
+                    }
+                }
+            }
+
+            fn geocode_addresses(&mut self, geocoder: &dyn ::scaffolding_core::Geocoder) -> Vec<String> {
+                self.addresses
+                    .values_mut()
+                    .filter_map(|a| match a.geocode(geocoder) {
+                        Ok(_) => None,
+                        Err(_) => Some(a.id.clone()),
+                    })
+                    .collect()
+            }
+
+            fn insert_address(
+                &mut self,
+                category: String,
+                line_1: String,
+                line_2: String,
+                line_3: String,
+                line_4: String,
+                country_code: String,
+            ) -> String {
+                let address = ::scaffolding_core::Address::new(category, line_1, line_2, line_3, line_4, country_code);
+                let id = address.id.clone();
+                self.addresses.insert(id.clone(), address);
+                id
             }
 
             fn modify_address(&mut self, id: String, category: String, line_1: String, line_2: String, line_3: String, line_4: String, country_code: String) {
@@ -251,202 +1169,2093 @@ fn impl_scaffolding_addresses(ast: &syn::DeriveInput) -> TokenStream {
                 );
             }
 
-            fn search_addresses_by_category(&self, category: String) -> Vec<Address> {
+            fn search_addresses_by_category(&self, category: String) -> Vec<::scaffolding_core::Address> {
+                ::scaffolding_core::ScaffoldingAddresses::search_addresses_by_category_ref(self, &category).into_iter().cloned().collect()
+            }
+
+            fn search_addresses_by_category_ref(&self, category: &str) -> Vec<&::scaffolding_core::Address> {
                 self.addresses
-                    .iter()
-                    .filter(|(k,v)| v.category == category)
-                    .map(|(k,v)| v.clone())
+                    .values()
+                    .filter(|v| v.category == category)
+                    .collect()
+            }
+
+            fn search_addresses_by_country(&self, iso_3_code: String) -> Vec<::scaffolding_core::Address> {
+                self.addresses
+                    .values()
+                    .filter(|v| v.country_code.eq_ignore_ascii_case(&iso_3_code))
+                    .cloned()
+                    .collect()
+            }
+
+            fn search_addresses_by_postal_code(&self, postal_code: String) -> Vec<::scaffolding_core::Address> {
+                let compact = |code: &str| code.to_lowercase().split_whitespace().collect::<String>();
+                self.addresses
+                    .values()
+                    .filter(|v| v.postal_code.as_deref().map(compact) == Some(compact(&postal_code)))
+                    .cloned()
+                    .collect()
+            }
+
+            fn search_addresses_by_region(&self, region: String) -> Vec<::scaffolding_core::Address> {
+                self.addresses
+                    .values()
+                    .filter(|v| v.region.as_ref().is_some_and(|r| r.eq_ignore_ascii_case(&region)))
+                    .cloned()
                     .collect()
             }
 
+            fn search_addresses_within(&self, center: (f64, f64), radius_km: f64) -> Vec<::scaffolding_core::Address> {
+                let mut found: Vec<(f64, ::scaffolding_core::Address)> = self
+                    .addresses
+                    .values()
+                    .filter_map(|v| match v.distance_from(center) {
+                        Some(km) if km <= radius_km => Some((km, v.clone())),
+                        _ => None,
+                    })
+                    .collect();
+                found.sort_by(|a, b| a.0.total_cmp(&b.0));
+                found.into_iter().map(|(_, v)| v).collect()
+            }
+
             fn remove_address(&mut self, id: String) {
                 self.addresses.remove(&id);
             }
+
+            #verification
         }
     };
-    gen.into()
+    expand(ast, gen)
 }
 
-// EmailAddresses Trait
-#[proc_macro_derive(ScaffoldingEmailAddresses)]
-pub fn scaffolding_emailaddresses_derive(input: TokenStream) -> TokenStream {
+// Attachments Trait
+#[proc_macro_derive(ScaffoldingAttachments)]
+pub fn scaffolding_attachments_derive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
 
-    impl_scaffolding_emailaddresses(&ast)
+    impl_scaffolding_attachments(&ast)
 }
 
-fn impl_scaffolding_emailaddresses(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_scaffolding_attachments(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(
+        ast,
+        "ScaffoldingAttachments",
+        Some(ATTACHMENTS),
+        &["attachments"],
+    ) {
+        return err;
+    }
     let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let gen = quote! {
-        impl ScaffoldingEmailAddresses for #name {
-            fn get_email_address(&self, id: String) -> Option<&EmailAddress> {
-                self.email_addresses.get(&id)
+        impl #impl_generics ::scaffolding_core::ScaffoldingAttachments for #name #ty_generics #where_clause {
+            fn get_attachment(&self, id: String) -> Option<&::scaffolding_core::Attachment> {
+                self.attachments.get(&id)
             }
 
-            fn insert_email_address(
-                &mut self,
-                category: String,
-                address: String,
-            ) -> String {
-                let email = EmailAddress::new(category, address);
-                let id = email.id.clone();
-                self.email_addresses.insert(id.clone(), email);
+            fn insert_attachment(&mut self, filename: String, mime_type: String, bytes: Vec<u8>) -> String {
+                let attachment = ::scaffolding_core::Attachment::new(filename, mime_type, bytes);
+                let id = attachment.id.clone();
+                self.attachments.insert(id.clone(), attachment);
                 id
             }
 
-            fn search_email_addresses_by_category(&self, category: String) -> Vec<EmailAddress> {
-                self.email_addresses
-                    .iter()
-                    .filter(|(k,v)| v.category == category)
-                    .map(|(k,v)| v.clone())
+            fn insert_attachment_locator(&mut self, filename: String, mime_type: String, locator: String) -> String {
+                let attachment = ::scaffolding_core::Attachment::new_with_locator(filename, mime_type, locator);
+                let id = attachment.id.clone();
+                self.attachments.insert(id.clone(), attachment);
+                id
+            }
+
+            fn search_attachments_by_filename(&self, search: String) -> Vec<::scaffolding_core::Attachment> {
+                let search = search.to_lowercase();
+                self.attachments
+                    .values()
+                    .filter(|v| v.filename.to_lowercase().contains(&search))
+                    .cloned()
                     .collect()
             }
 
-            fn remove_email_address(&mut self, id: String) {
-                self.email_addresses.remove(&id);
+            fn search_attachments_by_mime_type(&self, mime_type: String) -> Vec<::scaffolding_core::Attachment> {
+                ::scaffolding_core::ScaffoldingAttachments::search_attachments_by_mime_type_ref(self, &mime_type).into_iter().cloned().collect()
+            }
+
+            fn search_attachments_by_mime_type_ref(&self, mime_type: &str) -> Vec<&::scaffolding_core::Attachment> {
+                self.attachments
+                    .values()
+                    .filter(|v| v.is_mime_type(mime_type))
+                    .collect()
+            }
+
+            fn remove_attachment(&mut self, id: String) {
+                self.attachments.remove(&id);
             }
         }
     };
-    gen.into()
+    expand(ast, gen)
 }
 
-// Notes Trait
-#[proc_macro_derive(ScaffoldingNotes)]
-pub fn scaffolding_notes_derive(input: TokenStream) -> TokenStream {
+// Builder
+/// Generates a `<Struct>Builder` with a fluent setter for every attribute and a `<Struct>::builder()` function.
+/// The scaffolding attributes default to the same values as `#[scaffolding_fn]` and can be overridden.
+/// The struct's own attributes are required, (unless they are an `Option`), and validated by `build()`.
+#[proc_macro_derive(ScaffoldingBuilder)]
+pub fn scaffolding_builder_derive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
 
-    impl_scaffolding_notes(&ast)
+    impl_scaffolding_builder(&ast)
 }
 
-fn impl_scaffolding_notes(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_scaffolding_builder(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
+    let vis = &ast.vis;
+    let builder = format_ident!("{}Builder", name);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let generics = &ast.generics;
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+            fields => {
+                return syn::Error::new_spanned(
+                    fields,
+                    "ScaffoldingBuilder only supports structs with named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(ast, "ScaffoldingBuilder only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let idents: Vec<&syn::Ident> = fields.iter().filter_map(|f| f.ident.as_ref()).collect();
+    let types: Vec<&syn::Type> = fields.iter().map(|f| &f.ty).collect();
+    let values = fields
+        .iter()
+        .map(|f| {
+            let ident = f.ident.as_ref().unwrap();
+            match is_scaffolding_attr(&ident.to_string()) || is_option(&f.ty) {
+                true => {
+                    let default = default_value(&ident.to_string());
+                    quote! { self.#ident.unwrap_or_else(|| #default) }
+                }
+                false => quote! { self.#ident.ok_or_else(|| ::scaffolding_core::errors::ScaffoldingError::Build { attribute: stringify!(#ident).to_string() })? },
+            }
+        })
+        .collect::<Vec<_>>();
+
     let gen = quote! {
-        impl ScaffoldingNotes for #name {
-            fn get_note(&self, id: String) -> Option<&Note> {
-                self.notes.get(&id)
+        #vis struct #builder #generics #where_clause {
+            #(#idents: Option<#types>,)*
+        }
+
+        impl #impl_generics Default for #builder #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#idents: None,)*
+                }
             }
+        }
 
-            fn insert_note(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>) -> String {
-                let note = Note::new(auth, cont, acc);
-                let id = note.id.clone();
-                self.notes.insert(id.clone(), note);
-                id
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #(
+                pub fn #idents(mut self, value: impl Into<#types>) -> Self {
+                    self.#idents = Some(value.into());
+                    self
+                }
+            )*
+
+            pub fn build(self) -> Result<#name #ty_generics, ::scaffolding_core::errors::ScaffoldingError> {
+                Ok(#name {
+                    #(#idents: #values,)*
+                })
             }
+        }
 
-            fn modify_note(&mut self, id: String, auth: String, cont: Vec<u8>, acc: Option<String>) {
-                self.notes
-                    .entry(id)
-                    .and_modify(|note|
-                        note.update(auth, cont, acc)
-                    );
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn builder() -> #builder #ty_generics {
+                #builder::default()
             }
+        }
+    };
+    gen.into()
+}
 
-            fn search_notes(&mut self, search: String) -> Vec<Note> {
-                let mut results: Vec<Note> = Vec::new();
+// the default value of an attribute, (the same values that `#[scaffolding_fn]` uses)
+fn default_value(attr: &str) -> proc_macro2::TokenStream {
+    match attr {
+        "id" => quote! { ::scaffolding_core::ScaffoldingId::generate() },
+        "created_dtm" | "modified_dtm" => quote! { ::scaffolding_core::defaults::now() },
+        "inactive_dtm" => {
+            quote! { ::scaffolding_core::defaults::add_days(::scaffolding_core::defaults::now(), 90) }
+        }
+        "expired_dtm" => {
+            quote! { ::scaffolding_core::defaults::add_years(::scaffolding_core::defaults::now(), 3) }
+        }
+        "status" => quote! { ::scaffolding_core::defaults::status() },
+        _ => quote! { Default::default() },
+    }
+}
 
-                for (key, note) in self.notes.iter() {
-                    let mut cont = String::from_utf8(note.content.clone())
-                    .map_err(|non_utf8| String::from_utf8_lossy(non_utf8.as_bytes()).into_owned())
-                    .unwrap();
+// determines if the attribute is added by `#[scaffolding_struct]`
+fn is_scaffolding_attr(attr: &str) -> bool {
+    CORE_ATTRS.contains(&attr)
+        || [
+            ADDRESS,
+            ATTACHMENTS,
+            EMAIL,
+            METADATA,
+            METADATA_JSON,
+            NOTES,
+            PHONE,
+            RELATIONSHIPS,
+            TAGS,
+            STATUS,
+            "owner",
+            "shared_with",
+            "deleted_dtm",
+            "tenant_id",
+        ]
+        .contains(&attr)
+}
 
-                    match cont.contains(&search) {
-                        true => {
-                            results.push(note.clone())
-                        },
-                        false => {},
-                    }
+// determines if the type is an `Option`
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|s| s.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+// Convert Trait
+#[proc_macro_derive(ScaffoldingConvert)]
+pub fn scaffolding_convert_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_convert(&ast)
+}
+
+fn impl_scaffolding_convert(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingConvert", None, &REQUIRED_ATTRS) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = field_names(ast);
+    // only the attributes that the struct has are exported and replaced
+    let optional = [
+        "inactive_dtm",
+        "expired_dtm",
+        "activity",
+        ADDRESS,
+        ATTACHMENTS,
+        EMAIL,
+        METADATA,
+        METADATA_JSON,
+        NOTES,
+        PHONE,
+        RELATIONSHIPS,
+        TAGS,
+        "owner",
+        "shared_with",
+        "deleted_dtm",
+        STATUS,
+        "tenant_id",
+    ];
+    let exports = optional
+        .iter()
+        .map(|attr| {
+            let field = format_ident!("{}", attr);
+            match fields.contains(&attr.to_string()) {
+                true => quote! { #field: Some(self.#field.clone()) },
+                false => quote! { #field: None },
+            }
+        })
+        .collect::<Vec<_>>();
+    let imports = optional
+        .iter()
+        .filter(|attr| fields.contains(&attr.to_string()))
+        .map(|attr| {
+            let field = format_ident!("{}", attr);
+            quote! {
+                if let Some(value) = core.#field {
+                    self.#field = value;
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingConvert for #name #ty_generics #where_clause {
+            fn core_attributes(&self) -> ::scaffolding_core::CoreAttributes<Self::Id> {
+                ::scaffolding_core::CoreAttributes {
+                    id: self.id.clone(),
+                    created_dtm: self.created_dtm,
+                    modified_dtm: self.modified_dtm,
+                    #(#exports,)*
                 }
+            }
 
-                results
+            fn set_core_attributes(&mut self, core: ::scaffolding_core::CoreAttributes<Self::Id>) {
+                self.id = core.id;
+                self.created_dtm = core.created_dtm;
+                self.modified_dtm = core.modified_dtm;
+                #(#imports)*
             }
+        }
+    };
+    expand(ast, gen)
+}
 
-            fn remove_note(&mut self, id: String) {
-                self.notes.remove(&id);
+// Display
+/// Implements a concise `Display`, (type name, id, lifecycle state and created date),
+/// e.g.: `MyEntity 54324f57-9e6b-4142-b68d-1d4c86572d0a (active, created 2024-03-24)`
+/// so the object can be logged without the attributes that may hold PII, (e.g.: notes).
+#[proc_macro_derive(ScaffoldingDisplay)]
+pub fn scaffolding_display_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_display(&ast)
+}
+
+fn impl_scaffolding_display(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingDisplay", None, &REQUIRED_ATTRS) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = field_names(ast);
+    // the lifecycle state is the first of deleted, expired or inactive that applies, (otherwise active)
+    let states = [
+        (
+            "deleted_dtm",
+            quote! { self.deleted_dtm.is_some() },
+            "deleted",
+        ),
+        (
+            "expired_dtm",
+            quote! { self.expired_dtm <= ::scaffolding_core::defaults::now() },
+            "expired",
+        ),
+        (
+            "inactive_dtm",
+            quote! { self.inactive_dtm <= ::scaffolding_core::defaults::now() },
+            "inactive",
+        ),
+    ]
+    .into_iter()
+    .filter(|(attr, _, _)| fields.contains(&attr.to_string()))
+    .map(|(_, check, state)| quote! { if #check { #state } else })
+    .collect::<Vec<_>>();
+    let label = name.to_string();
+
+    let gen = quote! {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let state = #(#states)* { "active" };
+                write!(
+                    f,
+                    "{} {} ({}, created {})",
+                    #label,
+                    self.id,
+                    state,
+                    ::scaffolding_core::defaults::date(self.created_dtm)
+                )
             }
         }
     };
-    gen.into()
+    expand(ast, gen)
 }
 
-// PhoneNumber Trait
-#[proc_macro_derive(ScaffoldingPhoneNumbers)]
-pub fn scaffolding_phonenumbers_derive(input: TokenStream) -> TokenStream {
+// Duplicates Trait
+#[proc_macro_derive(ScaffoldingDuplicates)]
+pub fn scaffolding_duplicates_derive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
 
-    impl_scaffolding_phonenumbers(&ast)
+    impl_scaffolding_duplicates(&ast)
 }
 
-fn impl_scaffolding_phonenumbers(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_scaffolding_duplicates(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = field_names(ast);
+    // only compare the optional attributes that the struct has
+    let addresses = match fields.contains(&ADDRESS.to_string()) {
+        true => quote! {
+            keys.insert(
+                "addresses".to_string(),
+                self.addresses
+                    .values()
+                    .filter(|a| a.line_1 != redacted)
+                    .map(|a| a.normalized())
+                    .filter(|a| !a.is_empty())
+                    .collect(),
+            );
+        },
+        false => quote! {},
+    };
+    let email_addresses = match fields.contains(&EMAIL.to_string()) {
+        true => quote! {
+            keys.insert(
+                "email_addresses".to_string(),
+                self.email_addresses
+                    .values()
+                    .filter(|e| e.address != redacted)
+                    .map(|e| e.normalized())
+                    .filter(|e| !e.is_empty())
+                    .collect(),
+            );
+        },
+        false => quote! {},
+    };
+    let metadata = match fields.contains(&METADATA.to_string()) {
+        true => quote! {
+            let name: Vec<String> = self.metadata
+                .iter()
+                .filter(|(k, v)| k.to_lowercase().contains("name") && **v != redacted)
+                .flat_map(|(_, v)| {
+                    v.to_lowercase()
+                        .split(|c: char| !c.is_alphanumeric())
+                        .filter(|w| !w.is_empty())
+                        .map(|w| w.to_string())
+                        .collect::<Vec<String>>()
+                })
+                .collect();
+            if !name.is_empty() {
+                keys.insert("name".to_string(), vec![name.join(" ")]);
+            }
+        },
+        false => quote! {},
+    };
+    let phone_numbers = match fields.contains(&PHONE.to_string()) {
+        true => quote! {
+            keys.insert(
+                "phone_numbers".to_string(),
+                self.phone_numbers
+                    .values()
+                    .filter(|p| p.number != redacted)
+                    .map(|p| p.normalized())
+                    .filter(|p| !p.is_empty())
+                    .collect(),
+            );
+        },
+        false => quote! {},
+    };
     let gen = quote! {
-        impl ScaffoldingPhoneNumbers for #name {
-            fn get_phone_number(&self, id: String) -> Option<&PhoneNumber> {
-                self.phone_numbers.get(&id)
+        impl #impl_generics ::scaffolding_core::ScaffoldingDuplicates for #name #ty_generics #where_clause {
+            fn duplicate_keys(&self) -> ::std::collections::BTreeMap<String, Vec<String>> {
+                let mut keys = ::std::collections::BTreeMap::new();
+                // the erased personal data, (see `ScaffoldingErasure`) is not compared
+                let redacted = ::scaffolding_core::defaults::redacted();
+                #addresses
+                #email_addresses
+                #metadata
+                #phone_numbers
+                keys
             }
+        }
+    };
+    expand(ast, gen)
+}
 
-            fn insert_phone_number(
+// EmailAddresses Trait
+#[proc_macro_derive(ScaffoldingEmailAddresses)]
+pub fn scaffolding_emailaddresses_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_emailaddresses(&ast)
+}
+
+fn impl_scaffolding_emailaddresses(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(
+        ast,
+        "ScaffoldingEmailAddresses",
+        Some(EMAIL),
+        &["email_addresses"],
+    ) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // deliverability checks are only available when the `deliverability` feature is enabled
+    let deliverability = match cfg!(feature = "deliverability") {
+        true => quote! {
+            fn verify_email_address(
+                &mut self,
+                id: String,
+                probe: bool,
+            ) -> impl ::std::future::Future<Output = Result<::scaffolding_core::EmailDeliverability, ::scaffolding_core::errors::ScaffoldingError>> + Send
+            where
+                Self: Send + Sized,
+            {
+                async move {
+                    let mut email = match self.email_addresses.get(&id) {
+                        Some(email) => email.clone(),
+                        None => return Err(::scaffolding_core::errors::ScaffoldingError::NotFound { id }),
+                    };
+                    let deliverability = email.verify_deliverability(probe).await?;
+
+                    self.email_addresses.insert(id.clone(), email);
+                    ::scaffolding_core::Scaffolding::log_activity_with_data(
+                        self,
+                        "verified".to_string(),
+                        format!("The email address {} has been verified", id),
+                        ::scaffolding_core::json!({
+                            "id": id,
+                            "deliverable": deliverability.deliverable,
+                            "mx_hosts": deliverability.mx_hosts,
+                            "mailbox_exists": deliverability.mailbox_exists,
+                        }),
+                    );
+                    Ok(deliverability)
+                }
+            }
+        },
+        false => quote! {},
+    };
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingEmailAddresses for #name #ty_generics #where_clause {
+            fn get_email_address(&self, id: String) -> Option<&::scaffolding_core::EmailAddress> {
+                self.email_addresses.get(&id)
+            }
+
+            fn get_primary_email_address(&self, category: String) -> Option<&::scaffolding_core::EmailAddress> {
+                self.email_addresses.values().find(|v| v.primary && v.category == category)
+            }
+
+            fn set_primary_email_address(&mut self, id: String) {
+                let category = match self.email_addresses.get(&id) {
+                    Some(v) => v.category.clone(),
+                    None => return,
+                };
+                for v in self.email_addresses.values_mut().filter(|v| v.category == category) {
+                    let primary = v.id == id;
+                    if v.primary != primary {
+                        v.primary = primary;
+                        v.modified_dtm = ::scaffolding_core::defaults::now();
+                    }
+                }
+            }
+
+            fn insert_email_address(
                 &mut self,
                 category: String,
-                number: String,
-                country_code: String,
+                address: String,
             ) -> String {
-                let phone = PhoneNumber::new(category, number, country_code);
-                let id = phone.id.clone();
-                self.phone_numbers.insert(id.clone(), phone);
+                let email = ::scaffolding_core::EmailAddress::new(category, address);
+                let id = email.id.clone();
+                self.email_addresses.insert(id.clone(), email);
                 id
             }
 
-            fn search_phone_numbers_by_category(&self, category: String) -> Vec<PhoneNumber> {
-                self.phone_numbers
-                    .iter()
-                    .filter(|(k,v)| v.category == category)
-                    .map(|(k,v)| v.clone())
+            fn insert_email_address_unique(
+                &mut self,
+                category: String,
+                address: String,
+            ) -> String {
+                let email = ::scaffolding_core::EmailAddress::new(category, address);
+                match self.email_addresses.values().find(|e| e.canonical() == email.canonical()) {
+                    Some(existing) => existing.id.clone(),
+                    None => {
+                        let id = email.id.clone();
+                        self.email_addresses.insert(id.clone(), email);
+                        id
+                    }
+                }
+            }
+
+            fn search_email_addresses_by_category(&self, category: String) -> Vec<::scaffolding_core::EmailAddress> {
+                ::scaffolding_core::ScaffoldingEmailAddresses::search_email_addresses_by_category_ref(self, &category).into_iter().cloned().collect()
+            }
+
+            fn search_email_addresses_by_category_ref(&self, category: &str) -> Vec<&::scaffolding_core::EmailAddress> {
+                self.email_addresses
+                    .values()
+                    .filter(|v| v.category == category)
                     .collect()
             }
 
-            fn remove_phone_number(&mut self, id: String) {
-                self.phone_numbers.remove(&id);
+            fn search_email_addresses_by_domain(&self, domain: String) -> Vec<::scaffolding_core::EmailAddress> {
+                let domain = domain.trim().to_lowercase();
+                self.email_addresses
+                    .values()
+                    .filter(|v| v.domain().as_ref() == Some(&domain))
+                    .cloned()
+                    .collect()
+            }
+
+            fn remove_email_address(&mut self, id: String) {
+                self.email_addresses.remove(&id);
             }
+
+            #deliverability
         }
     };
-    gen.into()
+    expand(ast, gen)
 }
 
-// Tagging Trait
-#[proc_macro_derive(ScaffoldingTags)]
-pub fn scaffolding_tags_derive(input: TokenStream) -> TokenStream {
+// Erasure Trait
+#[proc_macro_derive(ScaffoldingErasure)]
+pub fn scaffolding_erasure_derive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
 
-    impl_scaffolding_tags(&ast)
+    impl_scaffolding_erasure(&ast)
 }
 
-fn impl_scaffolding_tags(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_scaffolding_erasure(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingErasure", None, &REQUIRED_ATTRS) {
+        return err;
+    }
     let name = &ast.ident;
-    let gen = quote! {
-        impl ScaffoldingTags for #name {
-            fn add_tag(&mut self, tag: String) {
-                // don't add duplicates
-                match self.has_tag(tag.clone()) {
-                    false => {
-                        self.tags.push(tag);
-                    },
-                    true => {
-                        println!("Ignoring tag {}. Tag already exists!", tag);
-                    },
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = field_names(ast);
+    // only erase the optional attributes that the struct has
+    let addresses = match fields.contains(&ADDRESS.to_string()) {
+        true => quote! {
+            for (_, address) in self.addresses.iter_mut() {
+                address.erase();
+            }
+        },
+        false => quote! {},
+    };
+    let email_addresses = match fields.contains(&EMAIL.to_string()) {
+        true => quote! {
+            for (_, email) in self.email_addresses.iter_mut() {
+                email.erase();
+            }
+        },
+        false => quote! {},
+    };
+    let metadata = match fields.contains(&METADATA.to_string()) {
+        true => quote! {
+            for key in metadata_keys.iter() {
+                if let Some(value) = self.metadata.get_mut(key) {
+                    *value = ::scaffolding_core::defaults::redacted();
                 }
             }
-            fn has_tag(&self, tag: String) -> bool {
-                let results = self.tags.iter().filter(|t| **t == tag).cloned().collect::<String>();
-                match results.len() {
-                    0 => false,
-                    _ => true,
+        },
+        false => quote! {
+            let _ = metadata_keys;
+        },
+    };
+    let metadata_json = match fields.contains(&METADATA_JSON.to_string()) {
+        true => quote! {
+            for key in metadata_keys.iter() {
+                if let Some(value) = self.metadata_json.get_mut(key) {
+                    *value = ::scaffolding_core::Value::String(::scaffolding_core::defaults::redacted());
                 }
             }
-            fn remove_tag(&mut self, tag: String) {
-                let pos = self.tags.iter().position(|t| **t == tag).unwrap();
-                self.tags.remove(pos);
+        },
+        false => quote! {},
+    };
+    let attachments = match fields.contains(&ATTACHMENTS.to_string()) {
+        true => quote! {
+            for (_, attachment) in self.attachments.iter_mut() {
+                attachment.erase();
             }
-        }
+        },
+        false => quote! {},
+    };
+    let notes = match fields.contains(&NOTES.to_string()) {
+        true => quote! {
+            for (_, note) in self.notes.iter_mut() {
+                note.erase();
+            }
+        },
+        false => quote! {},
+    };
+    let phone_numbers = match fields.contains(&PHONE.to_string()) {
+        true => quote! {
+            for (_, phone) in self.phone_numbers.iter_mut() {
+                phone.erase();
+            }
+        },
+        false => quote! {},
+    };
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingErasure for #name #ty_generics #where_clause {
+            fn erase_personal_data(&mut self, metadata_keys: Vec<String>) {
+                #addresses
+                #attachments
+                #email_addresses
+                #metadata
+                #metadata_json
+                #notes
+                #phone_numbers
+                // the descriptions and payloads of the activity can carry the old values, (e.g.: a changed email address)
+                ::scaffolding_core::Scaffolding::redact_activity(self, &|_| true);
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                ::scaffolding_core::Scaffolding::log_activity(self,
+                    "erased".to_string(),
+                    "The personal data has been erased".to_string(),
+                );
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Identity
+/// Implements `PartialEq`, `Eq` and `Hash` using only the `id` attribute,
+/// so objects are compared by identity, (e.g.: as the keys of a `HashSet` or `HashMap`).
+#[proc_macro_derive(ScaffoldingIdentity)]
+pub fn scaffolding_identity_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_identity(&ast)
+}
+
+fn impl_scaffolding_identity(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingIdentity", None, &["id"]) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::std::cmp::PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                self.id == other.id
+            }
+        }
+
+        impl #impl_generics ::std::cmp::Eq for #name #ty_generics #where_clause {}
+
+        impl #impl_generics ::std::hash::Hash for #name #ty_generics #where_clause {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                ::std::hash::Hash::hash(&self.id, state);
+            }
+        }
+    };
+    expand_receivers(ast, gen, &["self", "other"])
+}
+
+// Masking Trait
+#[proc_macro_derive(ScaffoldingMasking)]
+pub fn scaffolding_masking_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_masking(&ast)
+}
+
+fn impl_scaffolding_masking(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = field_names(ast);
+    // only mask the optional attributes that the struct has
+    let addresses = match fields.contains(&ADDRESS.to_string()) {
+        true => quote! {
+            for (_, address) in masked.addresses.iter_mut() {
+                address.mask(&policy.addresses);
+            }
+        },
+        false => quote! {},
+    };
+    let email_addresses = match fields.contains(&EMAIL.to_string()) {
+        true => quote! {
+            for (_, email) in masked.email_addresses.iter_mut() {
+                email.mask(&policy.email_addresses);
+            }
+        },
+        false => quote! {},
+    };
+    let notes = match fields.contains(&NOTES.to_string()) {
+        true => quote! {
+            for (_, note) in masked.notes.iter_mut() {
+                note.mask(&policy.notes);
+            }
+        },
+        false => quote! {},
+    };
+    let phone_numbers = match fields.contains(&PHONE.to_string()) {
+        true => quote! {
+            for (_, phone) in masked.phone_numbers.iter_mut() {
+                phone.mask(&policy.phone_numbers);
+            }
+        },
+        false => quote! {},
+    };
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingMasking for #name #ty_generics #where_clause {
+            fn mask(&self, policy: &::scaffolding_core::MaskPolicy) -> Self {
+                let mut masked = self.clone();
+                #addresses
+                #email_addresses
+                #notes
+                #phone_numbers
+                masked
+            }
+        }
+    };
+    expand_receivers(ast, gen, &["self", "masked"])
+}
+
+// Metadata Trait
+#[proc_macro_derive(ScaffoldingMetadata, attributes(scaffolding_metadata))]
+pub fn scaffolding_metadata_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_metadata(&ast)
+}
+
+fn impl_scaffolding_metadata(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingMetadata", Some(METADATA), &["metadata"]) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // the schema can be declared using #[scaffolding_metadata("key: options", ...)]
+    let mut rules: Vec<LitStr> = Vec::new();
+    for attr in ast.attrs.iter() {
+        if attr.path().is_ident("scaffolding_metadata") {
+            match attr.parse_args::<Args>() {
+                Ok(args) => rules.extend(args.vars),
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+    }
+    for rule in rules.iter() {
+        if rule
+            .value()
+            .split(':')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .is_empty()
+        {
+            return syn::Error::new(rule.span(), "expected a rule written as \"key: options\"")
+                .to_compile_error()
+                .into();
+        }
+    }
+    let schema = match rules.is_empty() {
+        true => quote! { ::scaffolding_core::MetadataSchema::new() },
+        false => quote! { ::scaffolding_core::MetadataSchema::from_rules(vec![#(#rules),*]) },
+    };
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingMetadata for #name #ty_generics #where_clause {
+            fn metadata_schema(&self) -> ::scaffolding_core::MetadataSchema {
+                #schema
+            }
+
+            fn validate_metadata(&self) -> Vec<::scaffolding_core::MetadataViolation> {
+                ::scaffolding_core::ScaffoldingMetadata::metadata_schema(self).validate(&self.metadata)
+            }
+
+            fn search_metadata(&self, key_glob: String, value_contains: String) -> ::std::collections::BTreeMap<String, String> {
+                // `*` matches any characters and `?` matches a single character
+                fn glob(key: &[char], pattern: &[char]) -> bool {
+                    match pattern.split_first() {
+                        None => key.is_empty(),
+                        Some(('*', rest)) => (0..=key.len()).any(|i| glob(&key[i..], rest)),
+                        Some(('?', rest)) => !key.is_empty() && glob(&key[1..], rest),
+                        Some((c, rest)) => key.first() == Some(c) && glob(&key[1..], rest),
+                    }
+                }
+                let pattern: Vec<char> = key_glob.chars().collect();
+                let search = value_contains.to_lowercase();
+                self.metadata
+                    .iter()
+                    .filter(|(k, v)| {
+                        glob(&k.chars().collect::<Vec<char>>(), &pattern)
+                            && v.to_lowercase().contains(&search)
+                    })
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect()
+            }
+
+            fn get_metadata(&self, key: String) -> Option<&String> {
+                self.metadata.get(&key)
+            }
+
+            fn upsert_metadata(&mut self, key: String, value: String) {
+                if self.metadata.get(&key) == Some(&value) {
+                    return;
+                }
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "metadata_changed".to_string(),
+                    format!("The metadata {} was set", key),
+                    ::scaffolding_core::json!({"key": key, "change": "upserted"}),
+                );
+                self.metadata.insert(key, value);
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+            }
+
+            fn remove_metadata(&mut self, key: String) -> Option<String> {
+                let value = self.metadata.remove(&key)?;
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "metadata_changed".to_string(),
+                    format!("The metadata {} was removed", key),
+                    ::scaffolding_core::json!({"key": key, "change": "removed"}),
+                );
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                Some(value)
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Metadata JSON Trait
+#[proc_macro_derive(ScaffoldingMetadataJson)]
+pub fn scaffolding_metadata_json_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_metadata_json(&ast)
+}
+
+fn impl_scaffolding_metadata_json(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(
+        ast,
+        "ScaffoldingMetadataJson",
+        Some(METADATA_JSON),
+        &["metadata_json"],
+    ) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingMetadataJson for #name #ty_generics #where_clause {
+            fn get_metadata_json(&self, key: String) -> Option<&::scaffolding_core::Value> {
+                self.metadata_json.get(&key)
+            }
+
+            fn get_metadata_path(&self, path: String) -> Option<&::scaffolding_core::Value> {
+                let mut segments = path.split('.');
+                let mut value = self.metadata_json.get(segments.next()?)?;
+                for segment in segments {
+                    value = match value {
+                        ::scaffolding_core::Value::Object(map) => map.get(segment)?,
+                        ::scaffolding_core::Value::Array(list) => list.get(segment.parse::<usize>().ok()?)?,
+                        _ => return None,
+                    };
+                }
+                Some(value)
+            }
+
+            fn upsert_metadata_json(&mut self, key: String, value: ::scaffolding_core::Value) {
+                if self.metadata_json.get(&key) == Some(&value) {
+                    return;
+                }
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "metadata_changed".to_string(),
+                    format!("The metadata {} was set", key),
+                    ::scaffolding_core::json!({"key": key, "change": "upserted"}),
+                );
+                self.metadata_json.insert(key, value);
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+            }
+
+            fn remove_metadata_json(&mut self, key: String) -> Option<::scaffolding_core::Value> {
+                let value = self.metadata_json.remove(&key)?;
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "metadata_changed".to_string(),
+                    format!("The metadata {} was removed", key),
+                    ::scaffolding_core::json!({"key": key, "change": "removed"}),
+                );
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                Some(value)
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Notes Trait
+#[proc_macro_derive(ScaffoldingNotes)]
+pub fn scaffolding_notes_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_notes(&ast)
+}
+
+fn impl_scaffolding_notes(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingNotes", Some(NOTES), &["notes"]) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // encrypted notes are only available when the `encryption` feature is enabled
+    let encryption = match cfg!(feature = "encryption") {
+        true => quote! {
+            fn insert_note_encrypted(&mut self, auth: String, cont: Vec<u8>, key: &[u8; 32]) -> String {
+                let note = ::scaffolding_core::Note::new_encrypted(auth, cont, key);
+                let id = note.id.clone();
+                self.notes.insert(id.clone(), note);
+                id
+            }
+
+            fn decrypt_note(&self, id: String, key: &[u8; 32]) -> Result<Vec<u8>, ::scaffolding_core::errors::ScaffoldingError> {
+                match self.notes.get(&id) {
+                    Some(note) => note.decrypt(key),
+                    None => Err(::scaffolding_core::errors::ScaffoldingError::NotFound { id }),
+                }
+            }
+
+            fn insert_note_with_keys(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>, keys: &dyn ::scaffolding_core::KeyProvider) -> String {
+                let mut note = ::scaffolding_core::Note::new(auth, cont, acc);
+                if let Some(key) = keys.key(&note.access) {
+                    note.encrypt(&key);
+                }
+                let id = note.id.clone();
+                self.notes.insert(id.clone(), note);
+                id
+            }
+
+            fn modify_note_with(&mut self, id: String, auth: String, cont: Vec<u8>, acc: Option<String>, keys: &dyn ::scaffolding_core::KeyProvider) {
+                if let Some(note) = self.notes.get_mut(&id) {
+                    note.update(auth, cont, acc);
+                    if let Some(key) = keys.key(&note.access) {
+                        note.encrypt(&key);
+                    }
+                }
+            }
+
+            fn reply_to_note_with(&mut self, id: String, auth: String, cont: Vec<u8>, keys: &dyn ::scaffolding_core::KeyProvider) -> Option<String> {
+                let access = self.notes.get(&id)?.access.clone();
+                let mut note = ::scaffolding_core::Note::new(auth, cont, Some(access));
+                if let Some(key) = keys.key(&note.access) {
+                    note.encrypt(&key);
+                }
+                note.parent_id = Some(id);
+                let reply = note.id.clone();
+                self.notes.insert(reply.clone(), note);
+                Some(reply)
+            }
+
+            fn read_note(&self, id: String, keys: &dyn ::scaffolding_core::KeyProvider) -> Result<Vec<u8>, ::scaffolding_core::errors::ScaffoldingError> {
+                let note = self.notes.get(&id).ok_or_else(|| ::scaffolding_core::errors::ScaffoldingError::NotFound { id: id.clone() })?;
+                match note.encrypted {
+                    true => match keys.key(&note.access) {
+                        Some(key) => note.decrypt(&key),
+                        None => Err(::scaffolding_core::errors::ScaffoldingError::Decrypt),
+                    },
+                    false => Ok(note.content.clone()),
+                }
+            }
+        },
+        false => quote! {},
+    };
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingNotes for #name #ty_generics #where_clause {
+            fn get_note(&self, id: String) -> Option<&::scaffolding_core::Note> {
+                self.notes.get(&id)
+            }
+
+            fn get_notes_for_access(&self, clearance: String) -> Vec<::scaffolding_core::Note> {
+                ::scaffolding_core::ScaffoldingNotes::get_notes_for_access_with_policy(self, clearance, &::scaffolding_core::AccessLevels::default())
+            }
+
+            fn get_notes_for_access_with_policy(&self, clearance: String, policy: &dyn ::scaffolding_core::AccessPolicy) -> Vec<::scaffolding_core::Note> {
+                self.notes
+                    .iter()
+                    .filter(|(_, v)| policy.can_access(&clearance, &v.access))
+                    .map(|(_, v)| v.clone())
+                    .collect()
+            }
+
+            fn insert_note(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>) -> String {
+                let note = ::scaffolding_core::Note::new(auth, cont, acc);
+                let id = note.id.clone();
+                self.notes.insert(id.clone(), note);
+                id
+            }
+
+            fn insert_note_with_mime_type(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>, mime_type: String) -> String {
+                let note = ::scaffolding_core::Note::new_with_mime_type(auth, cont, acc, mime_type);
+                let id = note.id.clone();
+                self.notes.insert(id.clone(), note);
+                id
+            }
+
+            fn modify_note(&mut self, id: String, auth: String, cont: Vec<u8>, acc: Option<String>) {
+                self.notes
+                    .entry(id)
+                    .and_modify(|note|
+                        note.update(auth, cont, acc)
+                    );
+            }
+
+            fn reply_to_note(&mut self, id: String, auth: String, cont: Vec<u8>) -> Option<String> {
+                let access = self.notes.get(&id)?.access.clone();
+                let mut note = ::scaffolding_core::Note::new(auth, cont, Some(access));
+                note.parent_id = Some(id);
+                let reply = note.id.clone();
+                self.notes.insert(reply.clone(), note);
+                Some(reply)
+            }
+
+            fn get_note_thread(&self, id: String) -> Vec<::scaffolding_core::Note> {
+                let mut root = match self.notes.get(&id) {
+                    Some(note) => note,
+                    None => return Vec::new(),
+                };
+                // the ids that have been visited, (so a corrupted thread that loops doesn't hang)
+                let mut seen = ::std::collections::BTreeSet::from([root.id.clone()]);
+                while let Some(parent) = root.parent_id.as_ref().and_then(|p| self.notes.get(p)) {
+                    if !seen.insert(parent.id.clone()) {
+                        break;
+                    }
+                    root = parent;
+                }
+
+                let mut seen = ::std::collections::BTreeSet::new();
+                let mut thread = Vec::new();
+                let mut pending = vec![root];
+                while let Some(note) = pending.pop() {
+                    if !seen.insert(note.id.clone()) {
+                        continue;
+                    }
+                    let mut replies: Vec<&::scaffolding_core::Note> = self.notes
+                        .values()
+                        .filter(|v| v.parent_id.as_ref() == Some(&note.id))
+                        .collect();
+                    // the oldest reply is taken from the stack first
+                    replies.sort_by_key(|v| ::std::cmp::Reverse(v.created_dtm));
+                    pending.extend(replies);
+                    thread.push(note.clone());
+                }
+                thread
+            }
+
+            fn search_notes(&mut self, search: String) -> Vec<::scaffolding_core::Note> {
+                ::scaffolding_core::ScaffoldingNotes::search_notes_ref(self, &search).into_iter().cloned().collect()
+            }
+
+            fn search_notes_ref(&self, search: &str) -> Vec<&::scaffolding_core::Note> {
+                self.notes
+                    .values()
+                    .filter(|v| String::from_utf8_lossy(&v.content).contains(search))
+                    .collect()
+            }
+
+            fn search_notes_by_author(&self, author: String) -> Vec<::scaffolding_core::Note> {
+                ::scaffolding_core::ScaffoldingNotes::search_notes_by_author_ref(self, &author).into_iter().cloned().collect()
+            }
+
+            fn search_notes_by_author_ref(&self, author: &str) -> Vec<&::scaffolding_core::Note> {
+                self.notes
+                    .values()
+                    .filter(|v| v.author == author)
+                    .collect()
+            }
+
+            fn list_notes(
+                &self,
+                sort_by: ::scaffolding_core::NoteSort,
+                order: ::scaffolding_core::SortOrder,
+                offset: usize,
+                limit: usize,
+            ) -> Vec<::scaffolding_core::Note> {
+                let mut notes: Vec<&::scaffolding_core::Note> = self.notes.values().collect();
+                // the notes are already ordered by id, so the sort is stable for equal values
+                notes.sort_by(|a, b| {
+                    let ordering = match sort_by {
+                        ::scaffolding_core::NoteSort::Created => a.created_dtm.cmp(&b.created_dtm),
+                        ::scaffolding_core::NoteSort::Modified => a.modified_dtm.cmp(&b.modified_dtm),
+                        ::scaffolding_core::NoteSort::Author => a.author.cmp(&b.author),
+                        ::scaffolding_core::NoteSort::Access => a.access.cmp(&b.access),
+                    };
+                    match order {
+                        ::scaffolding_core::SortOrder::Ascending => ordering,
+                        ::scaffolding_core::SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+                notes
+                    .into_iter()
+                    .skip(offset)
+                    .take(limit)
+                    .cloned()
+                    .collect()
+            }
+
+            fn count_notes(&self) -> usize {
+                self.notes.len()
+            }
+
+            fn export_notes(&self, dir: String) -> Result<usize, ::scaffolding_core::errors::ScaffoldingError> {
+                let notes: Vec<&::scaffolding_core::Note> = self.notes.values().collect();
+                ::scaffolding_core::Note::export_to_dir(&notes, &dir)
+            }
+
+            fn import_notes(&mut self, dir: String) -> Result<usize, ::scaffolding_core::errors::ScaffoldingError> {
+                let notes = ::scaffolding_core::Note::import_from_dir(&dir)?;
+                let count = notes.len();
+                for note in notes.into_iter() {
+                    self.notes.insert(note.id.clone(), note);
+                }
+                Ok(count)
+            }
+
+            fn search_notes_by_access(&self, level: String) -> Vec<::scaffolding_core::Note> {
+                self.notes
+                    .values()
+                    .filter(|v| v.access == level)
+                    .cloned()
+                    .collect()
+            }
+
+            fn remove_note(&mut self, id: String) {
+                self.notes.remove(&id);
+            }
+
+            #encryption
+        }
+    };
+    expand(ast, gen)
+}
+
+// Ownership Trait
+#[proc_macro_derive(ScaffoldingOwnership)]
+pub fn scaffolding_ownership_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_ownership(&ast)
+}
+
+fn impl_scaffolding_ownership(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(
+        ast,
+        "ScaffoldingOwnership",
+        Some(OWNERSHIP),
+        &["owner", "shared_with"],
+    ) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingOwnership for #name #ty_generics #where_clause {
+            fn can_read(&self, principal: String) -> bool {
+                // an empty principal, (e.g.: unauthenticated) never matches an unowned object
+                if principal.trim().is_empty() {
+                    return false;
+                }
+                self.owner == principal || self.shared_with.iter().any(|(p, _)| *p == principal)
+            }
+
+            fn can_write(&self, principal: String) -> bool {
+                if principal.trim().is_empty() {
+                    return false;
+                }
+                self.owner == principal
+                    || self
+                        .shared_with
+                        .iter()
+                        .any(|(p, perm)| *p == principal && *perm == ::scaffolding_core::Permission::Write)
+            }
+
+            fn share(&mut self, principal: String, permission: ::scaffolding_core::Permission) {
+                if principal.trim().is_empty() {
+                    return;
+                }
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "shared".to_string(),
+                    format!("The object was shared with {}", principal),
+                    ::scaffolding_core::json!({"principal": principal, "permission": permission}),
+                );
+                self.shared_with.retain(|(p, _)| *p != principal);
+                self.shared_with.push((principal, permission));
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+            }
+
+            fn unshare(&mut self, principal: String) {
+                if !self.shared_with.iter().any(|(p, _)| *p == principal) {
+                    return;
+                }
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "unshared".to_string(),
+                    format!("The object is no longer shared with {}", principal),
+                    ::scaffolding_core::json!({"principal": principal}),
+                );
+                self.shared_with.retain(|(p, _)| *p != principal);
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// PhoneNumber Trait
+#[proc_macro_derive(ScaffoldingPhoneNumbers)]
+pub fn scaffolding_phonenumbers_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_phonenumbers(&ast)
+}
+
+fn impl_scaffolding_phonenumbers(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(
+        ast,
+        "ScaffoldingPhoneNumbers",
+        Some(PHONE),
+        &["phone_numbers"],
+    ) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingPhoneNumbers for #name #ty_generics #where_clause {
+            fn get_phone_number(&self, id: String) -> Option<&::scaffolding_core::PhoneNumber> {
+                self.phone_numbers.get(&id)
+            }
+
+            fn get_primary_phone_number(&self, category: String) -> Option<&::scaffolding_core::PhoneNumber> {
+                self.phone_numbers.values().find(|v| v.primary && v.category == category)
+            }
+
+            fn set_primary_phone_number(&mut self, id: String) {
+                let category = match self.phone_numbers.get(&id) {
+                    Some(v) => v.category.clone(),
+                    None => return,
+                };
+                for v in self.phone_numbers.values_mut().filter(|v| v.category == category) {
+                    let primary = v.id == id;
+                    if v.primary != primary {
+                        v.primary = primary;
+                        v.modified_dtm = ::scaffolding_core::defaults::now();
+                    }
+                }
+            }
+
+            fn insert_phone_number(
+                &mut self,
+                category: String,
+                number: String,
+                country_code: String,
+            ) -> String {
+                let phone = ::scaffolding_core::PhoneNumber::new(category, number, country_code);
+                let id = phone.id.clone();
+                self.phone_numbers.insert(id.clone(), phone);
+                id
+            }
+
+            fn search_phone_numbers_by_category(&self, category: String) -> Vec<::scaffolding_core::PhoneNumber> {
+                ::scaffolding_core::ScaffoldingPhoneNumbers::search_phone_numbers_by_category_ref(self, &category).into_iter().cloned().collect()
+            }
+
+            fn search_phone_numbers_by_category_ref(&self, category: &str) -> Vec<&::scaffolding_core::PhoneNumber> {
+                self.phone_numbers
+                    .values()
+                    .filter(|v| v.category == category)
+                    .collect()
+            }
+
+            fn search_phone_numbers_by_capability(&self, capability: ::scaffolding_core::PhoneCapability) -> Vec<::scaffolding_core::PhoneNumber> {
+                self.phone_numbers
+                    .values()
+                    .filter(|v| v.has_capability(capability))
+                    .cloned()
+                    .collect()
+            }
+
+            fn search_phone_numbers_by_number(&self, fragment: String) -> Vec<::scaffolding_core::PhoneNumber> {
+                let countries = ::scaffolding_core::Countries::global();
+                self.phone_numbers
+                    .values()
+                    .filter(|v| v.matches_number(&fragment, countries))
+                    .cloned()
+                    .collect()
+            }
+
+            fn remove_phone_number(&mut self, id: String) {
+                self.phone_numbers.remove(&id);
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Relationships Trait
+#[proc_macro_derive(ScaffoldingRelationships)]
+pub fn scaffolding_relationships_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_relationships(&ast)
+}
+
+fn impl_scaffolding_relationships(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(
+        ast,
+        "ScaffoldingRelationships",
+        Some(RELATIONSHIPS),
+        &["relationships"],
+    ) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingRelationships for #name #ty_generics #where_clause {
+            fn get_relationship(&self, id: String) -> Option<&::scaffolding_core::Relationship> {
+                self.relationships.get(&id)
+            }
+
+            fn insert_relationship(&mut self, kind: String, target_id: String) -> String {
+                let relationship = ::scaffolding_core::Relationship::new(kind, target_id);
+                let id = relationship.id.clone();
+                self.relationships.insert(id.clone(), relationship);
+                id
+            }
+
+            fn search_relationships_by_kind(&self, kind: String) -> Vec<::scaffolding_core::Relationship> {
+                ::scaffolding_core::ScaffoldingRelationships::search_relationships_by_kind_ref(self, &kind).into_iter().cloned().collect()
+            }
+
+            fn search_relationships_by_kind_ref(&self, kind: &str) -> Vec<&::scaffolding_core::Relationship> {
+                self.relationships
+                    .values()
+                    .filter(|v| v.kind == kind)
+                    .collect()
+            }
+
+            fn search_relationships_by_target(&self, target_id: String) -> Vec<::scaffolding_core::Relationship> {
+                self.relationships
+                    .iter()
+                    .filter(|(k,v)| v.target_id == target_id)
+                    .map(|(k,v)| v.clone())
+                    .collect()
+            }
+
+            fn remove_relationship(&mut self, id: String) {
+                self.relationships.remove(&id);
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Retention Trait
+#[proc_macro_derive(ScaffoldingRetention)]
+pub fn scaffolding_retention_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_retention(&ast)
+}
+
+fn impl_scaffolding_retention(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingRetention", None, &REQUIRED_ATTRS) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = field_names(ast);
+    // the excluded core attributes, (e.g.: exclude("activity")) have nothing to retain
+    let activity = match fields.contains(&"activity".to_string()) {
+        true => quote! {
+            "activity" => {
+                let chained = self.activity.last().is_some_and(|a| a.hash.is_some());
+                let count = self.activity.len();
+                ::scaffolding_core::ActivityItem::rechain(&mut self.activity, |log| {
+                    log.retain(|a| a.created_dtm >= cutoff);
+                    let removed = count - log.len();
+                    // the pruning explains why the hashes of the tamper-evident activity log have changed
+                    if chained && removed > 0 {
+                        ::scaffolding_core::ActivityItem::append(log, ::scaffolding_core::ActivityItem::new_with_data(
+                            "pruned".to_string(),
+                            format!("{} activity items have been removed by the retention policy", removed),
+                            ::scaffolding_core::json!({"count": removed, "cutoff": cutoff}),
+                        ));
+                    }
+                    report.activity += removed;
+                });
+            }
+        },
+        false => quote! {},
+    };
+    let lifecycle = ["inactive_dtm", "expired_dtm"]
+        .iter()
+        .all(|attr| fields.contains(&attr.to_string()));
+    let expire = match lifecycle {
+        true => quote! {
+            let expired_dtm = ::scaffolding_core::defaults::add_years(self.inactive_dtm, *years);
+            if self.expired_dtm != expired_dtm {
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "expiration_changed".to_string(),
+                    format!("The object expires {} years after it became inactive", years),
+                    ::scaffolding_core::json!({"expired_dtm": expired_dtm, "years": years}),
+                );
+                self.expired_dtm = expired_dtm;
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+            }
+        },
+        false => quote! { let _ = years; },
+    };
+    let expired = match fields.contains(&"expired_dtm".to_string()) {
+        true => quote! {
+            if self.expired_dtm <= ::scaffolding_core::defaults::now() {
+                report.entities.push(self.id.to_string());
+            }
+        },
+        false => quote! {},
+    };
+    // only apply the retention to the optional attributes that the struct has
+    let records = [ADDRESS, ATTACHMENTS, EMAIL, NOTES, PHONE]
+        .iter()
+        .filter(|attr| fields.contains(&attr.to_string()))
+        .map(|attr| {
+            let field = format_ident!("{}", attr);
+            quote! {
+                #attr => {
+                    let ids: Vec<String> = self.#field
+                        .iter()
+                        .filter(|(_, v)| v.created_dtm < cutoff)
+                        .map(|(k, _)| k.clone())
+                        .collect();
+                    for id in ids.iter() {
+                        self.#field.remove(id);
+                    }
+                    if !ids.is_empty() {
+                        ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                            "pruned".to_string(),
+                            format!("{} {} have been removed by the retention policy", ids.len(), attribute),
+                            ::scaffolding_core::json!({"attribute": attribute, "count": ids.len(), "cutoff": cutoff}),
+                        );
+                        self.modified_dtm = ::scaffolding_core::defaults::now();
+                    }
+                    report.records.entry(attribute.clone()).or_default().extend(ids);
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingRetention for #name #ty_generics #where_clause {
+            fn apply_retention(&mut self, policies: &[::scaffolding_core::RetentionPolicy]) -> ::scaffolding_core::RetentionReport {
+                let mut report = ::scaffolding_core::RetentionReport::new();
+
+                for policy in policies.iter() {
+                    match policy {
+                        ::scaffolding_core::RetentionPolicy::RemoveOlderThan { attribute, days } => {
+                            let cutoff = ::scaffolding_core::defaults::add_days(::scaffolding_core::defaults::now(), -days);
+                            match attribute.as_str() {
+                                #activity
+                                #(#records)*
+                                // a misconfigured policy, (e.g.: a typo) is reported rather than silently ignored
+                                _ => {
+                                    if !report.unknown_attributes.contains(attribute) {
+                                        report.unknown_attributes.push(attribute.clone());
+                                    }
+                                }
+                            }
+                        }
+                        ::scaffolding_core::RetentionPolicy::ExpireAfterInactivity { years } => {
+                            #expire
+                        }
+                    }
+                }
+
+                #expired
+
+                report
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Setters
+/// Generates a `set_<attribute>()` function for each of the struct's own attributes that also updates the `modified_dtm`.
+/// Use `#[scaffolding_setters("log")]` to also log an `updated` activity for every change.
+#[proc_macro_derive(ScaffoldingSetters, attributes(scaffolding_setters))]
+pub fn scaffolding_setters_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_setters(&ast)
+}
+
+fn impl_scaffolding_setters(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingSetters", None, &REQUIRED_ATTRS) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => data
+            .fields
+            .iter()
+            .filter(|f| !is_scaffolding_attr(&f.ident.as_ref().unwrap().to_string()))
+            .collect::<Vec<_>>(),
+        _ => {
+            return syn::Error::new_spanned(ast, "ScaffoldingSetters only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+    // logging the changes is opt-in using #[scaffolding_setters("log")]
+    let mut log = false;
+    for attr in ast.attrs.iter() {
+        if attr.path().is_ident("scaffolding_setters") {
+            match attr.parse_args::<Args>() {
+                Ok(args) => log = log || args.vars.iter().any(|v| v.value() == "log"),
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+    }
+
+    let setters = fields
+        .iter()
+        .map(|f| {
+            let ident = f.ident.as_ref().unwrap();
+            let ty = &f.ty;
+            let setter = format_ident!("set_{}", ident);
+            let activity = match log {
+                true => {
+                    let descr = format!("The {} attribute has been updated", ident);
+                    quote! {
+                        ::scaffolding_core::Scaffolding::log_activity(self, "updated".to_string(), #descr.to_string());
+                    }
+                }
+                false => quote! {},
+            };
+            quote! {
+                pub fn #setter(&mut self, value: impl Into<#ty>) {
+                    self.#ident = value.into();
+                    self.modified_dtm = ::scaffolding_core::defaults::now();
+                    #activity
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let gen = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#setters)*
+        }
+    };
+    gen.into()
+}
+
+// SoftDelete Trait
+#[proc_macro_derive(ScaffoldingSoftDelete)]
+pub fn scaffolding_softdelete_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_softdelete(&ast)
+}
+
+fn impl_scaffolding_softdelete(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(
+        ast,
+        "ScaffoldingSoftDelete",
+        Some(SOFT_DELETE),
+        &["deleted_dtm"],
+    ) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // objects without an expiration, (e.g.: exclude("expired_dtm")) can always be restored
+    let expired = match field_names(ast).contains(&"expired_dtm".to_string()) {
+        true => quote! {
+            if self.expired_dtm <= ::scaffolding_core::defaults::now() {
+                return Err(::scaffolding_core::errors::ScaffoldingError::Recovery);
+            }
+        },
+        false => quote! {},
+    };
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingSoftDelete for #name #ty_generics #where_clause {
+            fn delete(&mut self) {
+                self.deleted_dtm = Some(::scaffolding_core::defaults::now());
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                ::scaffolding_core::Scaffolding::log_activity(self, "deleted".to_string(), "The object has been deleted".to_string());
+            }
+
+            fn restore(&mut self) -> Result<(), ::scaffolding_core::errors::ScaffoldingError> {
+                #expired
+                self.deleted_dtm = None;
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                ::scaffolding_core::Scaffolding::log_activity(self, "restored".to_string(), "The object has been restored".to_string());
+                Ok(())
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Status Trait
+#[proc_macro_derive(ScaffoldingStatus, attributes(scaffolding_status))]
+pub fn scaffolding_status_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_status(&ast)
+}
+
+fn impl_scaffolding_status(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingStatus", Some(STATUS), &["status"]) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // the transition table can be declared using #[scaffolding_status("from -> to", ...)]
+    let mut rules: Vec<LitStr> = Vec::new();
+    for attr in ast.attrs.iter() {
+        if attr.path().is_ident("scaffolding_status") {
+            match attr.parse_args::<Args>() {
+                Ok(args) => rules.extend(args.vars),
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+    }
+    for rule in rules.iter() {
+        if !rule.value().contains("->") {
+            return syn::Error::new(
+                rule.span(),
+                "expected a transition written as \"from -> to\"",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+    let transitions = match rules.is_empty() {
+        true => quote! { ::scaffolding_core::StatusTransitions::default() },
+        false => quote! { ::scaffolding_core::StatusTransitions::from_rules(vec![#(#rules),*]) },
+    };
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingStatus for #name #ty_generics #where_clause {
+            fn status_transitions(&self) -> ::scaffolding_core::StatusTransitions {
+                #transitions
+            }
+
+            fn can_transition_to(&self, status: String) -> bool {
+                ::scaffolding_core::ScaffoldingStatus::status_transitions(self).is_allowed(&self.status, &status)
+            }
+
+            fn transition_to(&mut self, status: String) -> Result<(), ::scaffolding_core::errors::ScaffoldingError> {
+                if !::scaffolding_core::ScaffoldingStatus::can_transition_to(self, status.clone()) {
+                    return Err(::scaffolding_core::errors::ScaffoldingError::Transition { from: self.status.clone(), to: status });
+                }
+                let old = self.status.clone();
+                ::scaffolding_core::Scaffolding::log_activity_with_data(self,
+                    "status_changed".to_string(),
+                    format!("The status changed from {} to {}", old, status),
+                    ::scaffolding_core::json!({"old": old, "new": status}),
+                );
+                self.status = status;
+                self.modified_dtm = ::scaffolding_core::defaults::now();
+                Ok(())
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Tenant Trait
+#[proc_macro_derive(ScaffoldingTenant)]
+pub fn scaffolding_tenant_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_tenant(&ast)
+}
+
+fn impl_scaffolding_tenant(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingTenant", Some(TENANT), &["tenant_id"]) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingTenant for #name #ty_generics #where_clause {
+            fn belongs_to_tenant(&self, tenant_id: String) -> bool {
+                self.tenant_id == tenant_id
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Tagging Trait
+#[proc_macro_derive(ScaffoldingTags)]
+pub fn scaffolding_tags_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_tags(&ast)
+}
+
+fn impl_scaffolding_tags(ast: &syn::DeriveInput) -> TokenStream {
+    if let Some(err) = missing_fields(ast, "ScaffoldingTags", Some(TAGS), &["tags"]) {
+        return err;
+    }
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingTags for #name #ty_generics #where_clause {
+            fn add_tag(&mut self, tag: String) {
+                ::scaffolding_core::ScaffoldingTags::add_tag_with_policy(self, tag, &::scaffolding_core::TagPolicy::default())
+            }
+            fn add_tag_with_policy(&mut self, tag: String, policy: &::scaffolding_core::TagPolicy) {
+                if !::scaffolding_core::ScaffoldingTags::try_add_tag_with_policy(self, tag.clone(), policy) {
+                    println!("Ignoring tag {}. Tag already exists!", tag);
+                }
+            }
+            fn try_add_tag(&mut self, tag: String) -> bool {
+                ::scaffolding_core::ScaffoldingTags::try_add_tag_with_policy(self, tag, &::scaffolding_core::TagPolicy::default())
+            }
+            fn try_add_tag_with_policy(&mut self, tag: String, policy: &::scaffolding_core::TagPolicy) -> bool {
+                // don't add duplicates
+                match ::scaffolding_core::ScaffoldingTags::has_tag_with_policy(self, tag.clone(), policy) {
+                    false => {
+                        self.tags.push(policy.normalize(&tag));
+                        true
+                    },
+                    true => false,
+                }
+            }
+            fn has_tag(&self, tag: String) -> bool {
+                ::scaffolding_core::ScaffoldingTags::has_tag_with_policy(self, tag, &::scaffolding_core::TagPolicy::default())
+            }
+            fn has_tag_with_policy(&self, tag: String, policy: &::scaffolding_core::TagPolicy) -> bool {
+                let tag = policy.normalize(&tag);
+                self.tags.iter().any(|t| policy.normalize(t) == tag)
+            }
+            fn remove_tag(&mut self, tag: String) {
+                ::scaffolding_core::ScaffoldingTags::remove_tag_with_policy(self, tag, &::scaffolding_core::TagPolicy::default())
+            }
+            fn remove_tag_with_policy(&mut self, tag: String, policy: &::scaffolding_core::TagPolicy) {
+                let tag = policy.normalize(&tag);
+                self.tags.retain(|t| policy.normalize(t) != tag);
+            }
+            fn rename_tag(&mut self, old: String, new: String) -> bool {
+                let policy = ::scaffolding_core::TagPolicy::default();
+                let old = policy.normalize(&old);
+                let pos = match self.tags.iter().position(|t| policy.normalize(t) == old) {
+                    Some(pos) => pos,
+                    None => return false,
+                };
+                let new = policy.normalize(&new);
+                // the other tags that are the same as the new tag or the old tag are duplicates
+                let mut index = 0;
+                self.tags.retain(|t| {
+                    let tag = policy.normalize(t);
+                    let keep = index == pos || (tag != new && tag != old);
+                    index += 1;
+                    keep
+                });
+                let pos = self.tags.iter().position(|t| policy.normalize(t) == old).unwrap();
+                self.tags[pos] = new;
+                true
+            }
+            fn replace_tag(&mut self, old: String, new: String) {
+                if !::scaffolding_core::ScaffoldingTags::rename_tag(self, old, new.clone()) {
+                    ::scaffolding_core::ScaffoldingTags::add_tag(self, new);
+                }
+            }
+            fn has_tag_prefix(&self, prefix: String) -> bool {
+                let policy = ::scaffolding_core::TagPolicy::default();
+                self.tags.iter().any(|t| policy.has_prefix(t, &prefix))
+            }
+            fn tags_in_namespace(&self, namespace: String) -> Vec<String> {
+                let policy = ::scaffolding_core::TagPolicy::default();
+                let namespace = policy.normalize(&namespace);
+                self.tags
+                    .iter()
+                    .filter(|t| policy.has_prefix(t, &namespace) && policy.normalize(t) != namespace)
+                    .cloned()
+                    .collect()
+            }
+            fn add_tag_value(&mut self, key: String, value: String) {
+                let policy = ::scaffolding_core::TagPolicy::default();
+                let key = policy.normalize(&key);
+                // a key only has one value
+                self.tags.retain(|t| policy.key_value(t).0 != key);
+                self.tags.push(policy.tag_value(&key, &value));
+            }
+            fn get_tag_value(&self, key: String) -> Option<String> {
+                let policy = ::scaffolding_core::TagPolicy::default();
+                let key = policy.normalize(&key);
+                self.tags
+                    .iter()
+                    .map(|t| policy.key_value(t))
+                    .find(|(k, _)| *k == key)
+                    .and_then(|(_, v)| v)
+            }
+            fn has_tag_value(&self, key: String, value: Option<String>) -> bool {
+                let policy = ::scaffolding_core::TagPolicy::default();
+                let key = policy.normalize(&key);
+                let value = value.map(|v| policy.normalize(&v));
+                self.tags
+                    .iter()
+                    .map(|t| policy.key_value(t))
+                    .any(|(k, v)| k == key && (value.is_none() || v == value))
+            }
+            fn search_tags(&self, prefix: String) -> Vec<String> {
+                ::scaffolding_core::TagPolicy::default().search(&self.tags, &prefix)
+            }
+            fn suggest_tags(&self, partial: String) -> Vec<String> {
+                ::scaffolding_core::TagPolicy::default().suggest(&self.tags, &partial)
+            }
+            fn search_tags_matching(&self, pattern: String) -> Vec<String> {
+                let policy = ::scaffolding_core::TagPolicy::default();
+                self.tags
+                    .iter()
+                    .filter(|t| policy.matches(t, &pattern))
+                    .cloned()
+                    .collect()
+            }
+        }
+    };
+    expand(ast, gen)
+}
+
+// Validate Trait
+#[proc_macro_derive(ScaffoldingValidate, attributes(validate))]
+pub fn scaffolding_validate_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    impl_scaffolding_validate(&ast)
+}
+
+fn impl_scaffolding_validate(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => data.fields.iter().collect::<Vec<_>>(),
+        _ => {
+            return syn::Error::new_spanned(ast, "ScaffoldingValidate only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+    // the rules are declared on the attributes using #[validate(...)]
+    let mut checks: Vec<proc_macro2::TokenStream> = Vec::new();
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().unwrap();
+        let label = ident.to_string();
+        let optional = is_option(&field.ty);
+        let mut rules: Vec<proc_macro2::TokenStream> = Vec::new();
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("email") {
+                    rules.push(quote! {
+                        if !::scaffolding_core::EmailAddress::is_valid_address(value) {
+                            report.add(#label, "email", "must be a valid email address");
+                        }
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("required") {
+                    if !optional {
+                        return Err(meta.error("`required` only applies to Option attributes"));
+                    }
+                    checks.push(quote! {
+                        if self.#ident.is_none() {
+                            report.add(#label, "required", "is required");
+                        }
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("length") || meta.path.is_ident("range") {
+                    let length = meta.path.is_ident("length");
+                    let mut bounds: Vec<proc_macro2::TokenStream> = Vec::new();
+                    meta.parse_nested_meta(|bound| {
+                        let (check, message) = match bound.path.get_ident().map(|i| i.to_string()) {
+                            Some(b) if b == "min" => (quote! { < }, "at least"),
+                            Some(b) if b == "max" => (quote! { > }, "at most"),
+                            _ => return Err(bound.error("expected `min` or `max`")),
+                        };
+                        let limit = bound.value()?;
+                        bounds.push(match length {
+                            true => {
+                                let limit = limit.parse::<syn::LitInt>()?.base10_parse::<usize>()?;
+                                let message = format!("must have a length of {} {}", message, limit);
+                                quote! {
+                                    if ::scaffolding_core::ValidateLength::validate_length(value) #check #limit {
+                                        report.add(#label, "length", #message);
+                                    }
+                                }
+                            }
+                            false => {
+                                // the limit can be a negative or decimal number, (e.g.: `min = -0.5`)
+                                let negative = limit.parse::<Option<Token![-]>>()?.is_some();
+                                let limit = match limit.parse::<syn::Lit>()? {
+                                    syn::Lit::Int(lit) => lit.base10_parse::<f64>()?,
+                                    syn::Lit::Float(lit) => lit.base10_parse::<f64>()?,
+                                    lit => return Err(syn::Error::new(lit.span(), "expected a number")),
+                                };
+                                let limit = if negative { -limit } else { limit };
+                                let message = format!("must be {} {}", message, limit);
+                                quote! {
+                                    if ::scaffolding_core::ValidateRange::validate_range(value) #check #limit {
+                                        report.add(#label, "range", #message);
+                                    }
+                                }
+                            }
+                        });
+                        Ok(())
+                    })?;
+                    rules.extend(bounds);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `length`, `range`, `email` or `required`"))
+                }
+            });
+            if let Err(err) = parsed {
+                return err.to_compile_error().into();
+            }
+        }
+        if rules.is_empty() {
+            continue;
+        }
+        checks.push(match optional {
+            true => quote! {
+                if let Some(value) = &self.#ident {
+                    #(#rules)*
+                }
+            },
+            false => quote! {
+                let value = &self.#ident;
+                #(#rules)*
+            },
+        });
+    }
+    // the embedded addresses, email addresses and phone numbers are checked using their own validation
+    let names = field_names(ast);
+    if names.contains(&ADDRESS.to_string()) {
+        checks.push(quote! {
+            for (id, address) in self.addresses.iter() {
+                if let Err(err) = address.validate_postal_code(::scaffolding_core::Countries::global()) {
+                    report.add(&format!("addresses.{}.postal_code", id), "postal_code", &err.to_string());
+                }
+                if let Err(err) = address.validate_region(::scaffolding_core::Countries::global()) {
+                    report.add(&format!("addresses.{}.region", id), "region", &err.to_string());
+                }
+            }
+        });
+    }
+    if names.contains(&EMAIL.to_string()) {
+        checks.push(quote! {
+            for (id, email) in self.email_addresses.iter() {
+                if !email.is_valid() {
+                    report.add(&format!("email_addresses.{}.address", id), "email", "must be a valid email address");
+                }
+            }
+        });
+    }
+    if names.contains(&PHONE.to_string()) {
+        checks.push(quote! {
+            for (id, phone) in self.phone_numbers.iter() {
+                if let Err(err) = phone.validate(::scaffolding_core::Countries::global()) {
+                    report.add(&format!("phone_numbers.{}.number", id), "phone_number", &err.to_string());
+                }
+            }
+        });
+    }
+
+    let gen = quote! {
+        impl #impl_generics ::scaffolding_core::ScaffoldingValidate for #name #ty_generics #where_clause {
+            fn validate(&self) -> Result<(), ::scaffolding_core::ValidationReport> {
+                #[allow(unused_mut)]
+                let mut report = ::scaffolding_core::ValidationReport::new();
+                #(#checks)*
+                report.into_result()
+            }
+        }
     };
     gen.into()
 }
@@ -454,181 +3263,445 @@ fn impl_scaffolding_tags(ast: &syn::DeriveInput) -> TokenStream {
 ///
 /// Modifies the following functions
 /// + new - Adds the core attributes to the new struct using the defined or default values
+/// + any function that returns `Self`, (e.g.: `from_parts()`, `with_defaults()`), or is marked with `"constructor"`,
+///   (e.g.: `#[scaffolding_fn("constructor", "notes")]`) - Same as `new`
+/// + constructors that return `Result<Self, E>` or `Option<Self>`, (e.g.: `Ok(Self { .. })`) - Same as `new`
+/// + async constructors and constructors that bind the struct before returning it,
+///   (e.g.: `let entity = Self { .. }; entity.load().await; entity`) - Same as `new`
+///
+/// Struct updates, (e.g.: `Self { name, ..self.clone() }`) are left as is, so they keep the identity,
+/// timestamps and collections of the base.
+///
+/// The options are the same as `#[scaffolding_struct]`, (including the `"all"` shorthand)
 ///
 #[proc_macro_attribute]
 pub fn scaffolding_fn(args: TokenStream, input: TokenStream) -> TokenStream {
     let mut item: syn::Item = syn::parse(input).unwrap();
     let fn_item = match &mut item {
         syn::Item::Fn(fn_item) => fn_item,
-        _ => panic!("expected fn"),
+        _ => {
+            return syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "#[scaffolding_fn] can only be applied to a function",
+            )
+            .to_compile_error()
+            .into()
+        }
     };
-    let attrs = parse_macro_input!(args as Args)
-        .vars
-        .iter()
-        .map(|a| a.value())
-        .collect::<Vec<_>>();
+    let args = parse_macro_input!(args as Args);
+    let excludes = exclusions(&args);
+    let attrs = options(args);
 
     // get the name of the method
     let name = &fn_item.sig.ident.to_string();
 
-    match name.as_ref() {
-        "new" => {
-            print!("Modifying function {} ...", name);
-            // find the line that sets the id attribute
-            for s in 0..fn_item.block.stmts.len() {
-                match &mut fn_item.block.stmts[s] {
-                    syn::Stmt::Expr(expr, None) => match expr {
-                        Struct(expr_struct) => {
-                            // println!("Found a Struct!");
-                            let mut modify_attr_list = vec![
-                                "id",
-                                "created_dtm",
-                                "modified_dtm",
-                                "inactive_dtm",
-                                "expired_dtm",
-                                "activity",
-                            ];
-
-                            match attrs.contains(&ADDRESS.to_string()) {
-                                true => {
-                                    modify_attr_list.push(&ADDRESS);
-                                }
-                                _ => {}
-                            }
+    // only constructors are modified, (any other function is left as is)
+    if name == "new" || attrs.contains(&CONSTRUCTOR.to_string()) || returns_self(&fn_item.sig) {
+        // find the lines that return the struct
+        add_defaults_to_block(&mut fn_item.block, &attrs, &excludes);
+    }
 
-                            match attrs.contains(&EMAIL.to_string()) {
-                                true => {
-                                    modify_attr_list.push(&EMAIL);
-                                }
-                                _ => {}
-                            }
+    item.into_token_stream().into()
+}
 
-                            match attrs.contains(&METADATA.to_string()) {
-                                true => {
-                                    modify_attr_list.push(&METADATA);
-                                }
-                                _ => {}
-                            }
+///
+/// Modifies the constructors of the impl block, (the same functions as `#[scaffolding_fn]` except for `"constructor"`),
+/// so the options don't need to be repeated on each function, (e.g.: `#[scaffolding_impl("notes", "tags")]`)
+///
+/// Functions that have their own `#[scaffolding_fn]` are left to it, and struct updates from a base,
+/// (e.g.: `Self { name, ..self.clone() }`) are left as is.
+///
+#[proc_macro_attribute]
+pub fn scaffolding_impl(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut item: syn::Item = syn::parse(input).unwrap();
+    let impl_item = match &mut item {
+        syn::Item::Impl(impl_item) => impl_item,
+        _ => {
+            return syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "#[scaffolding_impl] can only be applied to an impl block",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let args = parse_macro_input!(args as Args);
+    let excludes = exclusions(&args);
+    let attrs = options(args);
 
-                            match attrs.contains(&NOTES.to_string()) {
-                                true => {
-                                    modify_attr_list.push(&NOTES);
-                                }
-                                _ => {}
-                            }
+    for impl_fn in impl_item.items.iter_mut() {
+        if let syn::ImplItem::Fn(fn_item) = impl_fn {
+            let annotated = fn_item.attrs.iter().any(|a| {
+                a.path()
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "scaffolding_fn")
+            });
+            // only constructors are modified, (any other function is left as is)
+            if !annotated && (fn_item.sig.ident == "new" || returns_self(&fn_item.sig)) {
+                add_defaults_to_block(&mut fn_item.block, &attrs, &excludes);
+            }
+        }
+    }
 
-                            match attrs.contains(&PHONE.to_string()) {
-                                true => {
-                                    modify_attr_list.push(&PHONE);
-                                }
-                                _ => {}
-                            }
+    item.into_token_stream().into()
+}
 
-                            match attrs.contains(&TAGS.to_string()) {
-                                true => {
-                                    modify_attr_list.push(&TAGS);
-                                }
-                                _ => {}
-                            }
+// determines if the function returns `Self`, (e.g.: `Self`, `Result<Self, E>`, `Option<Self>`)
+fn returns_self(sig: &syn::Signature) -> bool {
+    match &sig.output {
+        syn::ReturnType::Type(_, ty) => is_self_type(ty),
+        syn::ReturnType::Default => false,
+    }
+}
 
-                            // first determine if the attributes already exist
-                            for f in 0..expr_struct.fields.len() {
-                                match &expr_struct.fields[f].member {
-                                    Member::Named(mbr) => {
-                                        match CORE_ATTRS.contains(&mbr.to_string().as_str()) {
-                                            true => {
-                                                // core attribute already set, so don't need to add it
-                                                // println!("Ignoring attribute {}", mbr.to_string());
-                                                modify_attr_list
-                                                    .retain_mut(|a| *a != mbr.to_string().as_str());
-                                            }
-                                            false => {}
-                                        }
-                                    }
-                                    _ => {}
-                                }
-                            }
+fn is_self_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => match type_path.path.segments.last() {
+            Some(segment) if segment.ident == "Self" => true,
+            Some(segment)
+                if ["Result", "Option", "Box"].contains(&segment.ident.to_string().as_str()) =>
+            {
+                match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                        Some(syn::GenericArgument::Type(ty)) => is_self_type(ty),
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
 
-                            // then, add the missing attributes
-                            for attr in modify_attr_list.iter() {
-                                // println!("Adding attribute {}", attr);
-                                match *attr {
-                                    "id" => {
-                                        let line: FieldValue = parse_quote! {id: defaults::id()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "created_dtm" => {
-                                        let line: FieldValue =
-                                            parse_quote! {created_dtm: defaults::now()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "modified_dtm" => {
-                                        let line: FieldValue =
-                                            parse_quote! {modified_dtm: defaults::now()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "inactive_dtm" => {
-                                        let line: FieldValue = parse_quote! {inactive_dtm: defaults::add_days(defaults::now(), 90)};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "expired_dtm" => {
-                                        let line: FieldValue = parse_quote! {expired_dtm: defaults::add_years(defaults::now(), 3)};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "activity" => {
-                                        let line: FieldValue = parse_quote! {activity: Vec::new()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "metadata" => {
-                                        let line: FieldValue =
-                                            parse_quote! {metadata: BTreeMap::new()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "notes" => {
-                                        let line: FieldValue =
-                                            parse_quote! {notes: BTreeMap::new()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "tags" => {
-                                        let line: FieldValue = parse_quote! {tags: Vec::new()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "addresses" => {
-                                        let line: FieldValue =
-                                            parse_quote! {addresses: BTreeMap::new()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "email_addresses" => {
-                                        let line: FieldValue =
-                                            parse_quote! {email_addresses: BTreeMap::new()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    "phone_numbers" => {
-                                        let line: FieldValue =
-                                            parse_quote! {phone_numbers: BTreeMap::new()};
-                                        expr_struct.fields.insert(0, line);
-                                    }
-                                    _ => {}
-                                }
-                            }
-                        }
-                        _ => {
-                            // println!("Not an Struct!");
+// finds the struct expressions that are returned, (e.g.: `Self { .. }`, `Ok(Self { .. })`, `return Some(Self { .. });`,
+// `let entity = Self { .. };`, or the branches of an `if` or `match`) and adds the attributes to them
+fn add_defaults_to_block(block: &mut syn::Block, attrs: &[String], excludes: &[String]) {
+    // the bindings that are returned, (e.g.: `let entity = MyEntity { .. }; ... Ok(entity)`)
+    let mut returned: Vec<String> = Vec::new();
+    for stmt in block.stmts.iter() {
+        match stmt {
+            syn::Stmt::Expr(expr, None) => returned_idents(expr, &mut returned),
+            syn::Stmt::Expr(expr @ syn::Expr::Return(_), Some(_)) => {
+                returned_idents(expr, &mut returned)
+            }
+            _ => {}
+        }
+    }
+
+    for stmt in block.stmts.iter_mut() {
+        match stmt {
+            syn::Stmt::Expr(expr, None) => add_defaults_to_expr(expr, attrs, excludes),
+            syn::Stmt::Expr(expr @ syn::Expr::Return(_), Some(_)) => {
+                add_defaults_to_expr(expr, attrs, excludes)
+            }
+            syn::Stmt::Local(local) => {
+                if let Some(init) = &mut local.init {
+                    let binding = match &local.pat {
+                        syn::Pat::Ident(pat) => pat.ident.to_string(),
+                        syn::Pat::Type(pat) => match pat.pat.as_ref() {
+                            syn::Pat::Ident(pat) => pat.ident.to_string(),
+                            _ => String::new(),
+                        },
+                        _ => String::new(),
+                    };
+                    if let Struct(expr_struct) = init.expr.as_mut() {
+                        let is_self = expr_struct.path.segments.first().map(|s| s.ident == "Self")
+                            == Some(true);
+                        if is_self || returned.contains(&binding) {
+                            add_defaults(expr_struct, attrs, excludes);
                         }
-                    },
-                    _ => {
-                        // println!("Not an Expr!");
                     }
                 }
             }
+            _ => {}
         }
-        _ => {
-            print!(
-                "Function {} is unsupported. Nothing to add to function ",
-                name
-            );
+    }
+}
+
+fn returned_idents(expr: &syn::Expr, idents: &mut Vec<String>) {
+    match expr {
+        syn::Expr::Path(path) => {
+            if let Some(ident) = path.path.get_ident() {
+                idents.push(ident.to_string());
+            }
+        }
+        syn::Expr::Call(call) => {
+            let wrapper = match call.func.as_ref() {
+                syn::Expr::Path(path) => path.path.is_ident("Ok") || path.path.is_ident("Some"),
+                _ => false,
+            };
+            if wrapper {
+                for arg in call.args.iter() {
+                    returned_idents(arg, idents);
+                }
+            }
         }
+        syn::Expr::Return(ret) => {
+            if let Some(expr) = &ret.expr {
+                returned_idents(expr, idents);
+            }
+        }
+        syn::Expr::Paren(paren) => returned_idents(&paren.expr, idents),
+        _ => {}
     }
+}
 
-    item.into_token_stream().into()
+fn add_defaults_to_expr(expr: &mut syn::Expr, attrs: &[String], excludes: &[String]) {
+    match expr {
+        Struct(expr_struct) => add_defaults(expr_struct, attrs, excludes),
+        syn::Expr::Call(call) => {
+            let wrapper = match call.func.as_ref() {
+                syn::Expr::Path(path) => path.path.is_ident("Ok") || path.path.is_ident("Some"),
+                _ => false,
+            };
+            if wrapper {
+                for arg in call.args.iter_mut() {
+                    add_defaults_to_expr(arg, attrs, excludes);
+                }
+            }
+        }
+        syn::Expr::Return(ret) => {
+            if let Some(expr) = &mut ret.expr {
+                add_defaults_to_expr(expr, attrs, excludes);
+            }
+        }
+        syn::Expr::If(expr_if) => {
+            add_defaults_to_block(&mut expr_if.then_branch, attrs, excludes);
+            if let Some((_, else_branch)) = &mut expr_if.else_branch {
+                add_defaults_to_expr(else_branch, attrs, excludes);
+            }
+        }
+        syn::Expr::Match(expr_match) => {
+            for arm in expr_match.arms.iter_mut() {
+                add_defaults_to_expr(&mut arm.body, attrs, excludes);
+            }
+        }
+        syn::Expr::Block(expr_block) => {
+            add_defaults_to_block(&mut expr_block.block, attrs, excludes)
+        }
+        syn::Expr::Paren(paren) => add_defaults_to_expr(&mut paren.expr, attrs, excludes),
+        _ => {}
+    }
+}
+
+// adds the core and optional attributes that are not already set to the struct expression
+fn add_defaults(expr_struct: &mut syn::ExprStruct, attrs: &[String], excludes: &[String]) {
+    // a struct update, (e.g.: `Self { name, ..self.clone() }`) takes the remaining fields from the base,
+    // so its identity and collections must not be replaced with new defaults
+    if expr_struct.rest.is_some() {
+        return;
+    }
+
+    let mut modify_attr_list = CORE_ATTRS.to_vec();
+    modify_attr_list.retain(|a| !excludes.contains(&a.to_string()));
+
+    match attrs.contains(&ADDRESS.to_string()) {
+        true => {
+            modify_attr_list.push(&ADDRESS);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&ATTACHMENTS.to_string()) {
+        true => {
+            modify_attr_list.push(&ATTACHMENTS);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&EMAIL.to_string()) {
+        true => {
+            modify_attr_list.push(&EMAIL);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&METADATA.to_string()) {
+        true => {
+            modify_attr_list.push(&METADATA);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&METADATA_JSON.to_string()) {
+        true => {
+            modify_attr_list.push(&METADATA_JSON);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&NOTES.to_string()) {
+        true => {
+            modify_attr_list.push(&NOTES);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&OWNERSHIP.to_string()) {
+        true => {
+            modify_attr_list.push("owner");
+            modify_attr_list.push("shared_with");
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&PHONE.to_string()) {
+        true => {
+            modify_attr_list.push(&PHONE);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&RELATIONSHIPS.to_string()) {
+        true => {
+            modify_attr_list.push(&RELATIONSHIPS);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&TAGS.to_string()) {
+        true => {
+            modify_attr_list.push(&TAGS);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&SOFT_DELETE.to_string()) {
+        true => {
+            modify_attr_list.push("deleted_dtm");
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&STATUS.to_string()) {
+        true => {
+            modify_attr_list.push(&STATUS);
+        }
+        _ => {}
+    }
+
+    match attrs.contains(&TENANT.to_string()) {
+        true => {
+            modify_attr_list.push("tenant_id");
+        }
+        _ => {}
+    }
+
+    // first determine if the attributes already exist
+    for f in 0..expr_struct.fields.len() {
+        match &expr_struct.fields[f].member {
+            Member::Named(mbr) => {
+                match modify_attr_list.contains(&mbr.to_string().as_str()) {
+                    true => {
+                        // attribute already set, so don't need to add it
+                        // println!("Ignoring attribute {}", mbr.to_string());
+                        modify_attr_list.retain_mut(|a| *a != mbr.to_string().as_str());
+                    }
+                    false => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // then, add the missing attributes
+    for attr in modify_attr_list.iter() {
+        // println!("Adding attribute {}", attr);
+        match *attr {
+            "id" => {
+                let line: FieldValue =
+                    parse_quote! {id: ::scaffolding_core::ScaffoldingId::generate()};
+                expr_struct.fields.insert(0, line);
+            }
+            "created_dtm" => {
+                let line: FieldValue =
+                    parse_quote! {created_dtm: ::scaffolding_core::defaults::now()};
+                expr_struct.fields.insert(0, line);
+            }
+            "modified_dtm" => {
+                let line: FieldValue =
+                    parse_quote! {modified_dtm: ::scaffolding_core::defaults::now()};
+                expr_struct.fields.insert(0, line);
+            }
+            "inactive_dtm" => {
+                let line: FieldValue = parse_quote! {inactive_dtm: ::scaffolding_core::defaults::add_days(::scaffolding_core::defaults::now(), 90)};
+                expr_struct.fields.insert(0, line);
+            }
+            "expired_dtm" => {
+                let line: FieldValue = parse_quote! {expired_dtm: ::scaffolding_core::defaults::add_years(::scaffolding_core::defaults::now(), 3)};
+                expr_struct.fields.insert(0, line);
+            }
+            "activity" => {
+                let line: FieldValue = parse_quote! {activity: Vec::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "metadata" => {
+                let line: FieldValue = parse_quote! {metadata: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "metadata_json" => {
+                let line: FieldValue =
+                    parse_quote! {metadata_json: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "notes" => {
+                let line: FieldValue = parse_quote! {notes: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "tags" => {
+                let line: FieldValue = parse_quote! {tags: Vec::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "addresses" => {
+                let line: FieldValue =
+                    parse_quote! {addresses: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "attachments" => {
+                let line: FieldValue =
+                    parse_quote! {attachments: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "email_addresses" => {
+                let line: FieldValue =
+                    parse_quote! {email_addresses: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "owner" => {
+                let line: FieldValue = parse_quote! {owner: String::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "shared_with" => {
+                let line: FieldValue = parse_quote! {shared_with: Vec::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "phone_numbers" => {
+                let line: FieldValue =
+                    parse_quote! {phone_numbers: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "relationships" => {
+                let line: FieldValue =
+                    parse_quote! {relationships: ::std::collections::BTreeMap::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            "deleted_dtm" => {
+                let line: FieldValue = parse_quote! {deleted_dtm: None};
+                expr_struct.fields.insert(0, line);
+            }
+            "status" => {
+                let line: FieldValue =
+                    parse_quote! {status: ::scaffolding_core::defaults::status()};
+                expr_struct.fields.insert(0, line);
+            }
+            "tenant_id" => {
+                let line: FieldValue = parse_quote! {tenant_id: String::new()};
+                expr_struct.fields.insert(0, line);
+            }
+            _ => {}
+        }
+    }
 }
@@ -51,6 +51,20 @@ pub fn add_years(dtm: i64, years: u32) -> i64 {
     dt.timestamp()
 }
 
+/// formats the timestamp as a date, (e.g.: 2024-03-24)
+///
+/// ```rust
+/// use scaffolding_core::defaults::*;
+///
+/// assert_eq!(date(1711295319), "2024-03-24".to_string());
+/// ```
+pub fn date(dtm: i64) -> String {
+    DateTime::from_timestamp(dtm, 0)
+        .unwrap()
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
 /// generates a uuid v4 value
 ///
 /// ```rust
@@ -62,6 +76,39 @@ pub fn id() -> String {
     Uuid::new_v4().to_string()
 }
 
+/// generate the marker that replaces redacted values
+///
+/// ```rust
+/// use scaffolding_core::defaults::*;
+///
+/// assert_eq!(redacted(), "[REDACTED]".to_string());
+/// ```
+pub fn redacted() -> String {
+    "[REDACTED]".to_string()
+}
+
+/// generate the marker that replaces encrypted content when it is masked
+///
+/// ```rust
+/// use scaffolding_core::defaults::*;
+///
+/// assert_eq!(encrypted(), "[ENCRYPTED]".to_string());
+/// ```
+pub fn encrypted() -> String {
+    "[ENCRYPTED]".to_string()
+}
+
+/// provides the default content type of a note
+///
+/// ```rust
+/// use scaffolding_core::defaults::*;
+///
+/// assert_eq!(mime_type(), "text/plain".to_string());
+/// ```
+pub fn mime_type() -> String {
+    "text/plain".to_string()
+}
+
 /// provided the default unix epoch time (UTC) as seconds
 /// for the timestamp: 9999-12-31 23:59:59
 ///
@@ -74,6 +121,17 @@ pub fn never() -> i64 {
     253402261199
 }
 
+/// generate the default value for the status of an object
+///
+/// ```rust
+/// use scaffolding_core::defaults::*;
+///
+/// assert_eq!(status(), "draft".to_string());
+/// ```
+pub fn status() -> String {
+    "draft".to_string()
+}
+
 /// generate the current unix epoch time (UTC) as seconds
 ///
 /// ```rust
@@ -116,11 +174,27 @@ mod tests {
         assert_eq!(add_years(1709208000, 1), 1740744000);
     }
 
+    #[test]
+    fn test_date() {
+        assert_eq!(date(1711295319), "2024-03-24".to_string());
+        assert_eq!(date(never()), "9999-12-31".to_string());
+    }
+
     #[test]
     fn test_never() {
         assert_eq!(never(), 253402261199);
     }
 
+    #[test]
+    fn test_redacted() {
+        assert_eq!(redacted(), "[REDACTED]".to_string());
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(status(), "draft".to_string());
+    }
+
     #[test]
     fn test_now() {
         assert_eq!(now(), Utc::now().timestamp());
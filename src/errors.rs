@@ -1,14 +1,196 @@
+use crate::ValidationReport;
 use std::error;
 use std::fmt;
 
 // struct
-#[derive(Debug, Clone)]
-pub struct DeserializeError;
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhoneNumberError {
+    // The country of the phone number is not known
+    UnknownCountry,
+    // The phone number has characters other than digits and separators
+    InvalidCharacters,
+    // The international calling code doesn't belong to the country
+    WrongCountryCode,
+    // The phone number has fewer digits than the numbers of the country
+    TooShort,
+    // The phone number has more digits than the numbers of the country
+    TooLong,
+}
+
+#[derive(Debug)]
+pub enum ScaffoldingError {
+    // The serialized data couldn't be parsed as the type
+    Deserialize {
+        source: serde_json::Error,
+        type_name: String,
+    },
+    // There is no object with the id
+    NotFound {
+        id: String,
+    },
+    // The object is not valid, (all the violations that were found)
+    Validation(ValidationReport),
+    // The data couldn't be read from or written to the storage
+    Storage(Box<dyn error::Error + Send + Sync>),
+    // The builder is missing a required attribute
+    Build {
+        attribute: String,
+    },
+    // The content couldn't be decrypted, (wrong key, tampered or not encrypted)
+    Decrypt,
+    // The address couldn't be located
+    Geocode,
+    // The postal code is not valid for the country
+    PostalCode,
+    // The postal code pattern of the country is not a valid regular expression
+    PostalCodePattern {
+        iso_3_code: String,
+        source: regex::Error,
+    },
+    // The object has expired beyond recovery
+    Recovery,
+    // The province, state or county is not valid for the country
+    Region,
+    // The status transition is not allowed
+    Transition {
+        from: String,
+        to: String,
+    },
+    // The address couldn't be verified
+    Verification,
+}
 
 //impl
-impl fmt::Display for DeserializeError {
+impl ScaffoldingError {
+    /// Returns the line of the serialized data where the deserialization failed, (None for the other errors).
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            ScaffoldingError::Deserialize { source, .. } => Some(source.line()),
+            _ => None,
+        }
+    }
+
+    /// Returns the column of the serialized data where the deserialization failed, (None for the other errors).
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            ScaffoldingError::Deserialize { source, .. } => Some(source.column()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PhoneNumberError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Unable to deserialize.")
+        match self {
+            PhoneNumberError::UnknownCountry => {
+                write!(f, "The country of the phone number is unknown.")
+            }
+            PhoneNumberError::InvalidCharacters => {
+                write!(f, "The phone number has invalid characters.")
+            }
+            PhoneNumberError::WrongCountryCode => {
+                write!(f, "The calling code doesn't belong to the country.")
+            }
+            PhoneNumberError::TooShort => {
+                write!(f, "The phone number is too short for the country.")
+            }
+            PhoneNumberError::TooLong => write!(f, "The phone number is too long for the country."),
+        }
+    }
+}
+impl error::Error for PhoneNumberError {}
+
+impl fmt::Display for ScaffoldingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldingError::Deserialize { source, type_name } => {
+                write!(f, "Unable to deserialize the {}: {}", type_name, source)
+            }
+            ScaffoldingError::NotFound { id } => write!(f, "The object {} was not found.", id),
+            ScaffoldingError::Validation(report) => {
+                write!(f, "The object is not valid, ({} violations).", report.len())
+            }
+            ScaffoldingError::Storage(source) => {
+                write!(f, "Unable to read or write the storage: {}", source)
+            }
+            ScaffoldingError::Build { attribute } => write!(
+                f,
+                "Unable to build, the required attribute {} is missing.",
+                attribute
+            ),
+            ScaffoldingError::Decrypt => write!(f, "Unable to decrypt."),
+            ScaffoldingError::Geocode => write!(f, "Unable to locate the address."),
+            ScaffoldingError::PostalCode => {
+                write!(f, "The postal code is not valid for the country.")
+            }
+            ScaffoldingError::PostalCodePattern { iso_3_code, source } => write!(
+                f,
+                "The postal code pattern of {} is not valid: {}",
+                iso_3_code, source
+            ),
+            ScaffoldingError::Recovery => write!(f, "The object has expired beyond recovery."),
+            ScaffoldingError::Region => write!(
+                f,
+                "The province, state or county is not valid for the country."
+            ),
+            ScaffoldingError::Transition { from, to } => write!(
+                f,
+                "Status transition from {} to {} is not allowed.",
+                from, to
+            ),
+            ScaffoldingError::Verification => write!(f, "Unable to verify the address."),
+        }
+    }
+}
+impl error::Error for ScaffoldingError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ScaffoldingError::Deserialize { source, .. } => Some(source),
+            ScaffoldingError::PostalCodePattern { source, .. } => Some(source),
+            ScaffoldingError::Validation(report) => Some(report),
+            ScaffoldingError::Storage(source) => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+impl axum::response::IntoResponse for ScaffoldingError {
+    /// Responds with the status code of the error and its description as JSON, (e.g.: `{"error": "..."}`).
+    /// The storage errors are not described, (so the internals are not exposed).
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::StatusCode;
+
+        if let ScaffoldingError::Validation(report) = self {
+            return report.into_response();
+        }
+        let status = match &self {
+            ScaffoldingError::Deserialize { source, .. } if source.is_data() => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            ScaffoldingError::Deserialize { .. } => StatusCode::BAD_REQUEST,
+            ScaffoldingError::NotFound { .. } => StatusCode::NOT_FOUND,
+            ScaffoldingError::Build { .. }
+            | ScaffoldingError::PostalCode
+            | ScaffoldingError::Region => StatusCode::UNPROCESSABLE_ENTITY,
+            ScaffoldingError::Decrypt => StatusCode::FORBIDDEN,
+            ScaffoldingError::Recovery => StatusCode::GONE,
+            ScaffoldingError::Transition { .. } => StatusCode::CONFLICT,
+            ScaffoldingError::Geocode | ScaffoldingError::Verification => StatusCode::BAD_GATEWAY,
+            ScaffoldingError::PostalCodePattern { .. }
+            | ScaffoldingError::Validation(_)
+            | ScaffoldingError::Storage(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let error = match &self {
+            ScaffoldingError::Storage(_) => "Unable to read or write the storage.".to_string(),
+            _ => self.to_string(),
+        };
+        (status, axum::Json(serde_json::json!({ "error": error }))).into_response()
+    }
+}
+
+impl From<ValidationReport> for ScaffoldingError {
+    fn from(report: ValidationReport) -> Self {
+        ScaffoldingError::Validation(report)
     }
 }
-impl error::Error for DeserializeError {}
@@ -94,19 +94,231 @@
 //! // extended behavior
 //! assert_eq!(entity.my_func(), "my function");
 //! ```
-#[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
 
+#[cfg(feature = "encryption")]
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+#[cfg(feature = "encryption")]
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+#[cfg(feature = "axum")]
+use axum::body::Bytes;
+#[cfg(feature = "axum")]
+use axum::extract::{FromRequest, Request};
+#[cfg(feature = "axum")]
+use axum::http::{header, StatusCode};
+#[cfg(feature = "axum")]
+use axum::response::{IntoResponse, Response};
+#[cfg(feature = "axum")]
+use axum::Json;
+#[cfg(feature = "timezones")]
+use chrono::{DateTime, FixedOffset, Offset, TimeZone};
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+#[cfg(feature = "signing")]
+use ed25519_dalek::{Signature, Signer, Verifier};
+#[cfg(feature = "signing")]
+pub use ed25519_dalek::{SigningKey, VerifyingKey};
 use errors::*;
+#[cfg(feature = "deliverability")]
+use hickory_resolver::proto::rr::RData;
+#[cfg(feature = "deliverability")]
+use hickory_resolver::TokioResolver;
+use pulldown_cmark::{html, CowStr, Event, Parser, Tag};
 use regex::Regex;
+#[cfg(feature = "geocoding")]
+use reqwest::blocking::Client;
 pub use scaffolding_macros::*;
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 pub use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::any::Any;
 pub use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+#[cfg(feature = "deliverability")]
+use std::time::Duration;
+#[cfg(feature = "deliverability")]
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+#[cfg(feature = "deliverability")]
+use tokio::net::tcp::OwnedReadHalf;
+#[cfg(feature = "deliverability")]
+use tokio::net::TcpStream;
 
-use serde_json::Value;
+pub use serde_json::{json, Value};
+
+/// The ordered list of access levels, from the least to the most restricted, used to decide which notes a clearance can see
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessLevels {
+    // The access levels, (e.g.: public, internal, confidential)
+    pub levels: Vec<String>,
+}
+
+impl AccessLevels {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let levels = AccessLevels::new(vec![
+    ///     "public".to_string(),
+    ///     "internal".to_string(),
+    ///     "restricted".to_string(),
+    /// ]);
+    ///
+    /// assert!(levels.can_access("restricted", "internal"));
+    /// assert!(!levels.can_access("internal", "restricted"));
+    /// ```
+    pub fn new(levels: Vec<String>) -> Self {
+        Self { levels }
+    }
+}
+
+impl Default for AccessLevels {
+    /// The default access levels are `public`, `internal` and `confidential`
+    fn default() -> Self {
+        Self::new(vec![
+            defaults::access(),
+            "internal".to_string(),
+            "confidential".to_string(),
+        ])
+    }
+}
+
+impl AccessPolicy for AccessLevels {
+    /// A clearance can access its own level and all the less restricted levels.
+    /// Access levels that are not in the list can only be accessed by the exact same clearance.
+    fn can_access(&self, clearance: &str, access: &str) -> bool {
+        let position = |level: &str| self.levels.iter().position(|l| l == level);
+        match (position(clearance), position(access)) {
+            (Some(c), Some(a)) => a <= c,
+            _ => clearance == access,
+        }
+    }
+}
+
+/// The policy hook that decides if a clearance is sufficient to access an access level
+pub trait AccessPolicy {
+    /// This function determines if the clearance can access the access level.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// struct AdminOnly;
+    ///
+    /// impl AccessPolicy for AdminOnly {
+    ///     fn can_access(&self, clearance: &str, _access: &str) -> bool {
+    ///         clearance == "admin"
+    ///     }
+    /// }
+    ///
+    /// assert!(AdminOnly.can_access("admin", "confidential"));
+    /// assert!(!AdminOnly.can_access("internal", "public"));
+    /// assert!(AccessLevels::default().can_access("internal", "public"));
+    /// ```
+    fn can_access(&self, clearance: &str, access: &str) -> bool;
+}
+
+/// The encryption keys bound to access levels, (e.g.: confidential notes are encrypted with the confidential key).
+/// Requires the `encryption` feature.
+#[cfg(feature = "encryption")]
+#[derive(Clone, Default)]
+pub struct AccessKeys {
+    // The encryption keys keyed by their access level
+    pub keys: BTreeMap<String, [u8; 32]>,
+}
+
+#[cfg(feature = "encryption")]
+impl AccessKeys {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let keys = AccessKeys::new().with_key("confidential".to_string(), [7u8; 32]);
+    ///
+    /// assert_eq!(keys.key("confidential"), Some([7u8; 32]));
+    /// assert_eq!(keys.key("public"), None);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            keys: BTreeMap::new(),
+        }
+    }
+
+    /// This function binds the encryption key to the access level.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let keys = AccessKeys::new()
+    ///     .with_key("internal".to_string(), [6u8; 32])
+    ///     .with_key("confidential".to_string(), [7u8; 32]);
+    ///
+    /// assert_eq!(keys.keys.len(), 2);
+    /// ```
+    pub fn with_key(mut self, access: String, key: [u8; 32]) -> Self {
+        self.keys.insert(access, key);
+        self
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl KeyProvider for AccessKeys {
+    fn key(&self, access: &str) -> Option<[u8; 32]> {
+        self.keys.get(access).copied()
+    }
+}
+
+/// The hook that provides the encryption key bound to an access level, (e.g.: from a key management service).
+/// Requires the `encryption` feature.
+#[cfg(feature = "encryption")]
+pub trait KeyProvider {
+    /// This function returns the encryption key for the access level, (None when the access level isn't encrypted).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// struct Vault;
+    ///
+    /// impl KeyProvider for Vault {
+    ///     fn key(&self, access: &str) -> Option<[u8; 32]> {
+    ///         match access {
+    ///             "confidential" => Some([7u8; 32]),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert!(Vault.key("confidential").is_some());
+    /// assert!(Vault.key("public").is_none());
+    /// ```
+    fn key(&self, access: &str) -> Option<[u8; 32]>;
+}
 
 /// Supporting Classes
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -117,6 +329,12 @@ pub struct ActivityItem {
     pub action: String,
     // The textual description of the action that occurred
     pub description: String,
+    // The machine-readable context of the action that occurred, (e.g.: old/new values, reference ids)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+    // The hash of the previous ActivityItem plus this item's content, (only set when the activity log is tamper-evident)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
 }
 
 impl ActivityItem {
@@ -136,6 +354,35 @@ impl ActivityItem {
             created_dtm: defaults::now(),
             action: name,
             description: descr,
+            data: None,
+            hash: None,
+        }
+    }
+
+    /// This is the constructor function for an ActivityItem that carries a structured payload.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let activity_item = ActivityItem::new_with_data(
+    ///     "updated".to_string(),
+    ///     "The status was changed".to_string(),
+    ///     json!({"old": "open", "new": "closed"}),
+    /// );
+    ///
+    /// assert_eq!(activity_item.data.unwrap()["new"], "closed");
+    /// ```
+    pub fn new_with_data(name: String, descr: String, data: Value) -> Self {
+        Self {
+            created_dtm: defaults::now(),
+            action: name,
+            description: descr,
+            data: Some(data),
+            hash: None,
         }
     }
 
@@ -159,14 +406,243 @@ impl ActivityItem {
     /// assert_eq!(activity_item.action, "updated".to_string());
     /// assert_eq!(activity_item.description, "The object has been updated.".to_string());
     /// ```
-    pub fn deserialized(serialized: &[u8]) -> Result<ActivityItem, DeserializeError> {
-        match serde_json::from_slice(&serialized) {
+    pub fn deserialized(serialized: &[u8]) -> Result<ActivityItem, ScaffoldingError> {
+        match serde_json::from_slice(serialized) {
             Ok(item) => Ok(item),
-            Err(err) => {
-                println!("{}", err);
-                Err(DeserializeError)
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "ActivityItem".to_string(),
+            }),
+        }
+    }
+
+    /// This function appends an ActivityItem to the activity log.
+    /// If the activity log is tamper-evident, (the last item has a hash), the new item is chained to it.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let mut log: Vec<ActivityItem> = Vec::new();
+    /// ActivityItem::append(&mut log, ActivityItem::new("created".to_string(), "This was created".to_string()));
+    /// assert!(log[0].hash.is_none());
+    ///
+    /// ActivityItem::chain_all(&mut log);
+    /// ActivityItem::append(&mut log, ActivityItem::new("updated".to_string(), "This was updated".to_string()));
+    /// assert!(log[1].hash.is_some());
+    /// ```
+    pub fn append(log: &mut Vec<ActivityItem>, mut item: ActivityItem) {
+        if let Some(last) = log.last() {
+            if let Some(previous) = &last.hash {
+                item.hash = Some(item.compute_hash(previous));
+            }
+        }
+        log.push(item);
+    }
+
+    /// This function (re)calculates the hash of every ActivityItem in the activity log,
+    /// chaining each item to the one before it so the activity log becomes tamper-evident.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let mut log = vec![
+    ///     ActivityItem::new("created".to_string(), "This was created".to_string()),
+    ///     ActivityItem::new("updated".to_string(), "This was updated".to_string()),
+    /// ];
+    /// ActivityItem::chain_all(&mut log);
+    ///
+    /// assert_eq!(log[1].hash, Some(log[1].compute_hash(log[0].hash.as_ref().unwrap())));
+    /// ```
+    pub fn chain_all(log: &mut [ActivityItem]) {
+        let mut previous = String::new();
+        for item in log.iter_mut() {
+            let hash = item.compute_hash(&previous);
+            item.hash = Some(hash.clone());
+            previous = hash;
+        }
+    }
+
+    /// This function applies a change that rewrites the activity log, (e.g.: redacting or removing ActivityItems),
+    /// and then re-chains a tamper-evident activity log so it can still be verified.
+    /// The activity log is only re-chained if it was intact before the change, so earlier tampering is not hidden.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let mut log = vec![
+    ///     ActivityItem::new("created".to_string(), "This was created".to_string()),
+    ///     ActivityItem::new("updated".to_string(), "This was updated".to_string()),
+    /// ];
+    /// ActivityItem::chain_all(&mut log);
+    /// ActivityItem::rechain(&mut log, |log| {
+    ///     log.remove(0);
+    /// });
+    ///
+    /// assert!(ActivityItem::verify_chain(&log));
+    /// ```
+    pub fn rechain(log: &mut Vec<ActivityItem>, change: impl FnOnce(&mut Vec<ActivityItem>)) {
+        let intact = log.last().is_some_and(|a| a.hash.is_some()) && Self::verify_chain(log);
+        change(log);
+        if intact {
+            Self::chain_all(log);
+        }
+    }
+
+    /// This function calculates the SHA-256 hash (hex encoded) of the previous item's hash plus the ActivityItem's content.
+    /// The first item in an activity log uses an empty string as the previous hash.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let activity_item = ActivityItem::new("updated".to_string(), "This was updated".to_string());
+    ///
+    /// assert_eq!(activity_item.compute_hash("").len(), 64);
+    /// assert_ne!(activity_item.compute_hash(""), activity_item.compute_hash("abc"));
+    /// ```
+    pub fn compute_hash(&self, previous_hash: &str) -> String {
+        let data = match &self.data {
+            Some(d) => d.to_string(),
+            None => String::new(),
+        };
+        let content = serde_json::to_string(&(
+            previous_hash,
+            self.created_dtm,
+            &self.action,
+            &self.description,
+            data,
+        ))
+        .unwrap();
+        format!("{:x}", Sha256::digest(content.as_bytes()))
+    }
+
+    /// This function verifies that every ActivityItem in the activity log has a hash and that
+    /// each hash matches the previous item's hash plus the item's content.
+    /// An empty activity log is considered valid.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let mut log = vec![
+    ///     ActivityItem::new("created".to_string(), "This was created".to_string()),
+    ///     ActivityItem::new("updated".to_string(), "This was updated".to_string()),
+    /// ];
+    /// assert!(!ActivityItem::verify_chain(&log));
+    ///
+    /// ActivityItem::chain_all(&mut log);
+    /// assert!(ActivityItem::verify_chain(&log));
+    ///
+    /// log[0].description = "This was tampered with".to_string();
+    /// assert!(!ActivityItem::verify_chain(&log));
+    /// ```
+    pub fn verify_chain(log: &[ActivityItem]) -> bool {
+        let mut previous = String::new();
+        for item in log.iter() {
+            match &item.hash {
+                Some(hash) if *hash == item.compute_hash(&previous) => previous = hash.clone(),
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// This function converts a list of ActivityItems to the specified export format.
+    /// CSV output includes a header row and escapes fields that contain commas, quotes or line breaks.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let items = vec![
+    ///     ActivityItem::new("updated".to_string(), "This was updated".to_string()),
+    ///     ActivityItem::new("cancelled".to_string(), "This was cancelled, finally".to_string()),
+    /// ];
+    /// let csv = ActivityItem::export(&items, ExportFormat::Csv);
+    /// let jsonl = ActivityItem::export(&items, ExportFormat::JsonLines);
+    ///
+    /// assert_eq!(csv.lines().count(), 3);
+    /// assert!(csv.contains("\"This was cancelled, finally\""));
+    /// assert_eq!(jsonl.lines().count(), 2);
+    /// ```
+    pub fn export(items: &[ActivityItem], format: ExportFormat) -> String {
+        let mut out = String::new();
+
+        match format {
+            ExportFormat::Csv => {
+                out.push_str("created_dtm,action,description,data\n");
+                for item in items.iter() {
+                    let data = match &item.data {
+                        Some(d) => d.to_string(),
+                        None => String::new(),
+                    };
+                    out.push_str(&format!(
+                        "{},{},{},{}\n",
+                        item.created_dtm,
+                        csv_field(&item.action),
+                        csv_field(&item.description),
+                        csv_field(&data)
+                    ));
+                }
+            }
+            ExportFormat::JsonLines => {
+                for item in items.iter() {
+                    out.push_str(&serde_json::to_string(item).unwrap());
+                    out.push('\n');
+                }
             }
         }
+
+        out
+    }
+
+    /// This function replaces the description and payload with the redaction marker,
+    /// while preserving the action and timestamp.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///     
+    /// use scaffolding_core::*;
+    ///
+    /// let mut activity_item = ActivityItem::new_with_data(
+    ///     "updated".to_string(),
+    ///     "Email changed to fsmith@example.com".to_string(),
+    ///     json!({"email": "fsmith@example.com"}),
+    /// );
+    /// activity_item.redact();
+    ///
+    /// assert_eq!(activity_item.action, "updated".to_string());
+    /// assert_eq!(activity_item.description, defaults::redacted());
+    /// assert_eq!(activity_item.data, Some(json!(defaults::redacted())));
+    /// ```
+    pub fn redact(&mut self) {
+        self.description = defaults::redacted();
+        if self.data.is_some() {
+            self.data = Some(Value::String(defaults::redacted()));
+        }
     }
 
     /// This function converts the ActivityItem to a serialize JSON string.
@@ -178,16 +654,47 @@ impl ActivityItem {
     ///     
     /// use scaffolding_core::*;
     ///
-    /// let mut activity_item = ActivityItem::new("updated".to_string(), "This was updated".to_string());
+    /// let activity_item = ActivityItem::new("updated".to_string(), "This was updated".to_string());
     /// let json = activity_item.serialize();
     ///
     /// println!("{}", json);
     /// ```
-    pub fn serialize(&mut self) -> String {
+    pub fn serialize(&self) -> String {
         serde_json::to_string(&self).unwrap()
     }
 }
 
+// quotes a CSV field when it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+// checks if the domain is in the list, (e.g.: "disposable") of the bundled email domains
+fn email_domain_listed(list: &str, domain: Option<String>) -> bool {
+    let domain = match domain {
+        Some(d) => d,
+        None => return false,
+    };
+    let lists: Value = serde_json::from_str(include_str!("email_domains.json")).unwrap();
+    lists[list]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|d| d.as_str() == Some(domain.as_str()))
+}
+
+/// The supported formats for exporting records
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    // Comma separated values with a header row
+    Csv,
+    // One JSON object per line
+    JsonLines,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Address {
     // The unique identifier of the note
@@ -197,7 +704,7 @@ pub struct Address {
     // The timestamp when the note was last modified
     pub modified_dtm: i64,
     // The type of address, (e.g.: Billing, Shipping, Home, Work, etc.)
-    pub category: String,
+    pub category: Category,
     // The first line of the address should contain the location's full name
     pub line_1: String,
     // The second line of the address should include the house number and street address/ PO box address
@@ -208,6 +715,33 @@ pub struct Address {
     pub line_4: String,
     // The country code of the location (Use Alpha 3 codes)
     pub country_code: String,
+    // The city, (parsed from the third line)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    // The province, state, or county, (parsed from the third line)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    // The postal code, (parsed from the third line)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    // The latitude of the location, (see `Address::geocode()`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    // The longitude of the location, (see `Address::geocode()`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    // Whether this is the primary address of its category, (see `set_primary_address()`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub primary: bool,
+    // The result of the last verification, (see `verify_address()`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<AddressVerification>,
+    // The timestamp from when the address is in effect, (None if always)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<i64>,
+    // The timestamp from when the address is no longer in effect, (None if still in effect)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<i64>,
 }
 
 impl Address {
@@ -238,23 +772,33 @@ impl Address {
     /// }
     /// ```
     pub fn new(
-        category: String,
+        category: impl Into<Category>,
         line_1: String,
         line_2: String,
         line_3: String,
         line_4: String,
         country_code: String,
     ) -> Self {
+        let (city, region, postal_code) = Self::parse_locality(&line_3);
         Self {
             id: defaults::id(),
             created_dtm: defaults::now(),
             modified_dtm: defaults::now(),
-            category: category,
-            line_1: line_1,
-            line_2: line_2,
-            line_3: line_3,
-            line_4: line_4,
-            country_code: country_code,
+            category: category.into(),
+            line_1,
+            line_2,
+            line_3,
+            line_4,
+            country_code,
+            city,
+            region,
+            postal_code,
+            latitude: None,
+            longitude: None,
+            primary: false,
+            verification: None,
+            valid_from: None,
+            valid_to: None,
         }
     }
 
@@ -282,13 +826,13 @@ impl Address {
     /// assert_eq!(address.modified_dtm, 1711833619);
     /// assert_eq!(address.category, "shipping".to_string());
     /// ```
-    pub fn deserialized(serialized: &[u8]) -> Result<Address, DeserializeError> {
-        match serde_json::from_slice(&serialized) {
+    pub fn deserialized(serialized: &[u8]) -> Result<Address, ScaffoldingError> {
+        match serde_json::from_slice(serialized) {
             Ok(item) => Ok(item),
-            Err(err) => {
-                println!("{}", err);
-                Err(DeserializeError)
-            }
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "Address".to_string(),
+            }),
         }
     }
 
@@ -299,7 +843,7 @@ impl Address {
     /// ```rust     
     /// use scaffolding_core::*;
     ///
-    /// let mut address = Address::new(
+    /// let address = Address::new(
     ///   "shipping".to_string(),
     ///   "acmes company".to_string(),
     ///   "14 Main Street".to_string(),
@@ -309,11 +853,88 @@ impl Address {
     /// );
     /// println!("{}", address.serialize());
     /// ```
-    pub fn serialize(&mut self) -> String {
+    pub fn serialize(&self) -> String {
         serde_json::to_string(&self).unwrap()
     }
 
-    /// This function updates the Address.
+    /// This function calculates the distance in kilometers to the point, (latitude, longitude)
+    /// using the haversine formula. Returns None if the Address has no coordinates.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut address = Address::new(
+    ///     "office".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "10 Downing Street".to_string(),
+    ///     "London, SW1A 2AA".to_string(),
+    ///     "United Kingdom".to_string(),
+    ///     "GBR".to_string(),
+    /// );
+    /// assert_eq!(address.distance_from((48.8566, 2.3522)), None);
+    ///
+    /// address.latitude = Some(51.5034);
+    /// address.longitude = Some(-0.1276);
+    /// let km = address.distance_from((48.8566, 2.3522)).unwrap();
+    ///
+    /// assert_eq!(km.round(), 343.0);
+    /// ```
+    pub fn distance_from(&self, point: (f64, f64)) -> Option<f64> {
+        let (latitude, longitude) = (self.latitude?, self.longitude?);
+        let (lat_1, lat_2) = (latitude.to_radians(), point.0.to_radians());
+        let d_lat = (point.0 - latitude).to_radians();
+        let d_lon = (point.1 - longitude).to_radians();
+        let a =
+            (d_lat / 2.0).sin().powi(2) + lat_1.cos() * lat_2.cos() * (d_lon / 2.0).sin().powi(2);
+
+        Some(2.0 * EARTH_RADIUS_KM * a.sqrt().asin())
+    }
+
+    /// This function calculates the distance in kilometers to the other Address.
+    /// Returns None if either Address has no coordinates, (see `Address::geocode()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut london = Address::new(
+    ///     "office".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "10 Downing Street".to_string(),
+    ///     "London, SW1A 2AA".to_string(),
+    ///     "United Kingdom".to_string(),
+    ///     "GBR".to_string(),
+    /// );
+    /// london.latitude = Some(51.5034);
+    /// london.longitude = Some(-0.1276);
+    ///
+    /// let mut paris = Address::new(
+    ///     "office".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "55 Rue du Faubourg Saint-Honore".to_string(),
+    ///     "75008 Paris".to_string(),
+    ///     "France".to_string(),
+    ///     "FRA".to_string(),
+    /// );
+    /// assert_eq!(london.distance_to(&paris), None);
+    ///
+    /// paris.latitude = Some(48.8704);
+    /// paris.longitude = Some(2.3167);
+    ///
+    /// assert_eq!(london.distance_to(&paris).unwrap().round(), 341.0);
+    /// ```
+    pub fn distance_to(&self, other: &Address) -> Option<f64> {
+        self.distance_from((other.latitude?, other.longitude?))
+    }
+
+    /// This function turns the Address into a tombstone by replacing the address lines with the redaction marker.
     ///
     /// #Example
     ///
@@ -328,42 +949,26 @@ impl Address {
     ///   "USA".to_string(),
     ///   "USA".to_string()
     /// );
+    /// address.erase();
     ///
-    /// address.update(
-    ///   "billing".to_string(),
-    ///   "acmes company".to_string(),
-    ///   "14 Main Street".to_string(),
-    ///   "Big City, NY 038845".to_string(),
-    ///   "USA".to_string(),
-    ///   "USA".to_string());
-    ///
-    /// assert_eq!(address.category, "billing".to_string());
+    /// assert_eq!(address.line_1, defaults::redacted());
+    /// assert_eq!(address.category, "shipping".to_string());
     /// ```
-    pub fn update(
-        &mut self,
-        category: String,
-        line_1: String,
-        line_2: String,
-        line_3: String,
-        line_4: String,
-        country_code: String,
-    ) {
-        self.category = category;
-        self.line_1 = line_1;
-        self.line_2 = line_2;
-        self.line_3 = line_3;
-        self.line_4 = line_4;
-        self.country_code = country_code;
+    pub fn erase(&mut self) {
+        self.line_1 = defaults::redacted();
+        self.line_2 = defaults::redacted();
+        self.line_3 = defaults::redacted();
+        self.line_4 = defaults::redacted();
+        self.city = None;
+        self.region = None;
+        self.postal_code = None;
+        self.latitude = None;
+        self.longitude = None;
+        self.verification = None;
         self.modified_dtm = defaults::now();
     }
-}
-pub struct Countries {
-    // The list of countries
-    pub list: Vec<Country>,
-}
 
-impl Countries {
-    /// This is the constructor function.
+    /// This function sets the latitude and longitude of the Address using the geocoder.
     ///
     /// #Example
     ///
@@ -371,194 +976,228 @@ impl Countries {
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
+    /// use scaffolding_core::errors::ScaffoldingError;
     ///
-    /// fn main() {
-    ///   let countries = Countries::new();
+    /// struct FixedGeocoder;
+    ///
+    /// impl Geocoder for FixedGeocoder {
+    ///     fn geocode(&self, _address: &Address) -> Result<(f64, f64), ScaffoldingError> {
+    ///         Ok((40.7128, -74.006))
+    ///     }
     /// }
+    ///
+    /// let mut address = Address::new(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// address.geocode(&FixedGeocoder).unwrap();
+    ///
+    /// assert_eq!(address.latitude, Some(40.7128));
+    /// assert_eq!(address.longitude, Some(-74.006));
     /// ```
-    pub fn new() -> Self {
-        let data = include_str!("countries.json");
-        let array: Value = serde_json::from_str(data).unwrap();
-        let countries: Vec<Country> = array
-            .as_array()
-            .unwrap()
-            .iter()
-            .map(|c| {
-                Country::new(
-                    c["country_name"].as_str().unwrap().to_string(),
-                    c["phone_code"].as_str().unwrap().to_string(),
-                    c["iso_2_code"].as_str().unwrap().to_string(),
-                    c["iso_3_code"].as_str().unwrap().to_string(),
-                )
-            })
-            .collect();
-        Self { list: countries }
+    pub fn geocode(&mut self, geocoder: &dyn Geocoder) -> Result<(), ScaffoldingError> {
+        let (latitude, longitude) = geocoder.geocode(self)?;
+        self.latitude = Some(latitude);
+        self.longitude = Some(longitude);
+        self.modified_dtm = defaults::now();
+        Ok(())
     }
 
-    /// Verifies a Country
+    /// This function formats the Address using the conventions of the country of the Address,
+    /// (e.g.: the postal code before the city in Germany) with the country name as the last line.
+    /// If the city could not be parsed, the third line is used as is.
+    ///
+    /// #Example
     ///
-    /// ### Example
     /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// fn main() {
-    ///  let countries = Countries::new();
-    ///   let country = Country::new(
-    ///       "United States".to_string(),
-    ///       "1".to_string(),
-    ///       "US".to_string(),
-    ///       "USA".to_string()
-    ///   );
+    /// let countries = Countries::new();
+    /// let address = Address::new(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "Invalidenstrasse 10".to_string(),
+    ///     "Berlin, 10115".to_string(),
+    ///     "Germany".to_string(),
+    ///     "DEU".to_string(),
+    /// );
     ///
-    ///   assert_eq!(countries.is_valid(country), true);
-    /// }
+    /// assert_eq!(
+    ///     address.format(&countries, AddressStyle::Label),
+    ///     "acmes company\nInvalidenstrasse 10\n10115 Berlin\nGERMANY"
+    /// );
+    /// assert_eq!(
+    ///     address.format(&countries, AddressStyle::SingleLine),
+    ///     "acmes company, Invalidenstrasse 10, 10115 Berlin, Germany"
+    /// );
     /// ```
-    pub fn is_valid(&self, country: Country) -> bool {
-        let found = self.list.iter().filter(|c| {
-            c.name == country.name
-                && c.phone_code == country.phone_code
-                && c.iso_2_code == country.iso_2_code
-                && c.iso_3_code == country.iso_3_code
-        });
-        match found.count() {
-            0 => return false,
-            _ => return true,
+    pub fn format(&self, countries: &Countries, style: AddressStyle) -> String {
+        let mut lines = vec![self.line_1.clone(), self.line_2.clone()];
+        lines.extend(self.locality_lines());
+        lines.push(
+            match countries.get_country_by_iso_3_code(self.country_code.clone()) {
+                Some(country) => country.name.clone(),
+                None => self.line_4.clone(),
+            },
+        );
+        lines.retain(|l| !l.trim().is_empty());
+
+        match style {
+            AddressStyle::Label => {
+                if let Some(country) = lines.last_mut() {
+                    *country = country.to_uppercase();
+                }
+                lines.join("\n")
+            }
+            AddressStyle::SingleLine => lines.join(", "),
         }
     }
 
-    /// Retrieves a Country based on the ISO 2 Code
-    ///
-    /// ### Example
-    /// ```rust
-    /// extern crate scaffolding_core;
-    ///
-    /// use scaffolding_core::*;
-    ///
-    /// fn main() {
-    ///  let countries = Countries::new();
-    ///  let country = countries.get_country_by_iso_2_code("US".to_string()).unwrap();
-    ///
-    ///  assert_eq!(country.name, "United States");
-    ///  assert_eq!(country.phone_code, "1");
-    ///  assert_eq!(country.iso_2_code, "US");
-    ///  assert_eq!(country.iso_3_code, "USA");
-    /// }
-    /// ```
-    pub fn get_country_by_iso_2_code(&self, iso_2_code: String) -> Option<&Country> {
-        let found = self.list.iter().filter(|c| c.iso_2_code == iso_2_code);
+    // orders the city, region and postal code the way the country of the address expects them
+    fn locality_lines(&self) -> Vec<String> {
+        let city = match &self.city {
+            Some(c) => c.clone(),
+            None => return vec![self.line_3.clone()],
+        };
+        let join = |parts: &[&Option<String>]| {
+            parts
+                .iter()
+                .filter_map(|p| p.as_deref())
+                .collect::<Vec<&str>>()
+                .join(" ")
+        };
+        let (region, postal_code) = (&self.region, &self.postal_code);
 
-        return found.last();
+        match self.country_code.as_str() {
+            // postal code before the city
+            "AUT" | "BEL" | "CHE" | "CZE" | "DEU" | "DNK" | "ESP" | "FIN" | "FRA" | "GRC"
+            | "ITA" | "NLD" | "NOR" | "POL" | "PRT" | "SWE" | "TUR" => {
+                vec![join(&[postal_code, &Some(city), region])]
+            }
+            // postal code on its own line
+            "GBR" | "IRL" => vec![join(&[&Some(city), region]), join(&[postal_code])],
+            // city, region and postal code, (e.g.: USA, CAN, AUS)
+            _ => match region {
+                Some(_) => vec![format!("{}, {}", city, join(&[region, postal_code]))],
+                None => vec![join(&[&Some(city), postal_code])],
+            },
+        }
     }
 
-    /// Retrieves a Country based on the ISO 3 Code
+    /// This function masks the address lines using the specified masking style.
     ///
-    /// ### Example
-    /// ```rust
-    /// extern crate scaffolding_core;
+    /// #Example
     ///
+    /// ```rust     
     /// use scaffolding_core::*;
     ///
-    /// fn main() {
-    ///  let countries = Countries::new();
-    ///  let country = countries.get_country_by_iso_3_code("USA".to_string()).unwrap();
+    /// let mut address = Address::new(
+    ///   "shipping".to_string(),
+    ///   "acmes company".to_string(),
+    ///   "14 Main Street".to_string(),
+    ///   "Big City, NY 038845".to_string(),
+    ///   "USA".to_string(),
+    ///   "USA".to_string()
+    /// );
+    /// address.mask(&MaskStyle::Partial);
     ///
-    ///  assert_eq!(country.name, "United States");
-    ///  assert_eq!(country.phone_code, "1");
-    ///  assert_eq!(country.iso_2_code, "US");
-    ///  assert_eq!(country.iso_3_code, "USA");
-    /// }
+    /// assert_eq!(address.line_2, "1* M*** S*****".to_string());
+    /// assert_eq!(address.country_code, "USA".to_string());
     /// ```
-    pub fn get_country_by_iso_3_code(&self, iso_3_code: String) -> Option<&Country> {
-        let found = self.list.iter().filter(|c| c.iso_3_code == iso_3_code);
-
-        return found.last();
+    pub fn mask(&mut self, style: &MaskStyle) {
+        self.line_1 = style.mask(&self.line_1);
+        self.line_2 = style.mask(&self.line_2);
+        self.line_3 = style.mask(&self.line_3);
+        self.line_4 = style.mask(&self.line_4);
+        self.city = self.city.as_ref().map(|c| style.mask(c));
+        self.region = self.region.as_ref().map(|r| style.mask(r));
+        self.postal_code = self.postal_code.as_ref().map(|p| style.mask(p));
+        if *style != MaskStyle::Unmasked {
+            self.latitude = None;
+            self.longitude = None;
+        }
+        if let Some(verification) = self.verification.as_mut() {
+            verification.corrected = verification
+                .corrected
+                .as_ref()
+                .map(|lines| lines.iter().map(|l| style.mask(l)).collect());
+        }
     }
 
-    /// Retrieves a Country based on the international phone code
+    /// This function returns a normalized form of the Address that is used for fuzzy comparisons,
+    /// (lowercase alphanumeric words of all the lines followed by the country code).
+    ///
+    /// #Example
     ///
-    /// ### Example
     /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// fn main() {
-    ///  let countries = Countries::new();
-    ///  let country = countries.get_country_by_phone_code("1".to_string()).unwrap();
+    /// let address = Address::new(
+    ///   "shipping".to_string(),
+    ///   "acmes company".to_string(),
+    ///   "14 Main Street".to_string(),
+    ///   "Big City, NY 038845".to_string(),
+    ///   "USA".to_string(),
+    ///   "USA".to_string()
+    /// );
     ///
-    ///  assert_eq!(country.name, "United States");
-    ///  assert_eq!(country.phone_code, "1");
-    ///  assert_eq!(country.iso_2_code, "US");
-    ///  assert_eq!(country.iso_3_code, "USA");
-    /// }
+    /// assert_eq!(address.normalized(), "acmes company 14 main street big city ny 038845 usa usa".to_string());
     /// ```
-    pub fn get_country_by_phone_code(&self, phone_code: String) -> Option<&Country> {
-        let found = self.list.iter().filter(|c| c.phone_code == phone_code);
-
-        return found.last();
+    pub fn normalized(&self) -> String {
+        let lines = format!(
+            "{} {} {} {} {}",
+            self.line_1, self.line_2, self.line_3, self.line_4, self.country_code
+        );
+        lines
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ")
     }
-}
-
-// A country definition
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct Country {
-    // Textual name of the coutnry
-    pub name: String,
-    // The code used for international phone calls
-    pub phone_code: String,
-    // The 2 char abbreviation
-    pub iso_2_code: String,
-    // The 3 char abbreviation
-    pub iso_3_code: String,
-}
 
-impl Country {
-    /// This is the constructor function.
+    /// This function determines if the Address is in effect at the timestamp,
+    /// (from `valid_from` up to but not including `valid_to`).
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
     ///
-    /// use scaffolding_core::Country;
+    /// use scaffolding_core::*;
     ///
-    /// fn main() {
-    ///    let country = Country::new("United States".to_string(), "1".to_string(), "US".to_string(), "USA".to_string());
+    /// let mut address = Address::new(
+    ///     "home".to_string(),
+    ///     "Peter Petty".to_string(),
+    ///     "23 Corner Lane".to_string(),
+    ///     "Tiny Town, VT 04456".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// assert!(address.is_valid_at(0));
     ///
-    ///    assert_eq!(country.name, "United States".to_string());
-    ///    assert_eq!(country.phone_code, "1".to_string());
-    ///    assert_eq!(country.iso_2_code, "US".to_string());
-    ///    assert_eq!(country.iso_3_code, "USA".to_string());
-    /// }
+    /// address.valid_from = Some(1000);
+    /// address.valid_to = Some(2000);
+    ///
+    /// assert!(!address.is_valid_at(999));
+    /// assert!(address.is_valid_at(1000));
+    /// assert!(!address.is_valid_at(2000));
     /// ```
-    pub fn new(name: String, phone_code: String, iso_2_code: String, iso_3_code: String) -> Self {
-        Self {
-            name: name,
-            phone_code: phone_code,
-            iso_2_code: iso_2_code,
-            iso_3_code: iso_3_code,
-        }
+    pub fn is_valid_at(&self, dtm: i64) -> bool {
+        self.valid_from.is_none_or(|from| from <= dtm) && self.valid_to.is_none_or(|to| dtm < to)
     }
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct EmailAddress {
-    // The unique identifier of the note
-    pub id: String,
-    // The timestamp when the note was created
-    pub created_dtm: i64,
-    // The timestamp when the note was last modified
-    pub modified_dtm: i64,
-    // The type of email address, (e.g.: Login, Personal, Work, Primary Contact, Assistant, etc.)
-    pub category: String,
-    // The email address
-    pub address: String,
-}
 
-impl EmailAddress {
-    /// This is the constructor function.
+    /// This function parses the city, region and postal code from the line of the address,
+    /// (e.g.: "Big City, NY 038845", "London, SW1A 1AA" or "10115 Berlin").
+    /// The postal code is the word(s) with digits at the start or end of the line.
     ///
     /// #Example
     ///
@@ -567,271 +1206,367 @@ impl EmailAddress {
     ///
     /// use scaffolding_core::*;
     ///
-    /// fn main() {
-    ///   let email = EmailAddress::new(
-    ///       "home".to_string(),
-    ///       "myemail@example.com".to_string(),
-    ///   );
-    ///   
-    ///   // scaffolding attributes
-    ///   println!("{}", email.id);
-    ///   println!("{}", email.created_dtm);
-    ///   println!("{}", email.modified_dtm,);
-    /// }
+    /// let (city, region, postal_code) = Address::parse_locality("Big City, NY 038845");
+    ///
+    /// assert_eq!(city, Some("Big City".to_string()));
+    /// assert_eq!(region, Some("NY".to_string()));
+    /// assert_eq!(postal_code, Some("038845".to_string()));
     /// ```
-    pub fn new(category: String, address: String) -> Self {
-        Self {
-            id: defaults::id(),
-            created_dtm: defaults::now(),
-            modified_dtm: defaults::now(),
-            category: category,
-            address: address,
+    pub fn parse_locality(line: &str) -> (Option<String>, Option<String>, Option<String>) {
+        let has_digit = |w: &&str| w.chars().any(|c| c.is_ascii_digit());
+        let join = |words: &[&str]| match words.is_empty() {
+            true => None,
+            false => Some(words.join(" ")),
+        };
+
+        match line.rsplit_once(',') {
+            // e.g.: Big City, NY 038845
+            Some((city, rest)) => {
+                let words: Vec<&str> = rest.split_whitespace().collect();
+                let pos = words.iter().position(has_digit).unwrap_or(words.len());
+                (
+                    join(&city.split_whitespace().collect::<Vec<&str>>()),
+                    join(&words[..pos]),
+                    join(&words[pos..]),
+                )
+            }
+            None => {
+                let words: Vec<&str> = line.split_whitespace().collect();
+                match words.first().is_some_and(has_digit) {
+                    // e.g.: 10115 Berlin
+                    true => {
+                        let pos = words
+                            .iter()
+                            .position(|w| !has_digit(w))
+                            .unwrap_or(words.len());
+                        (join(&words[pos..]), None, join(&words[..pos]))
+                    }
+                    // e.g.: Springfield 62704
+                    false => {
+                        let pos = words.iter().position(has_digit).unwrap_or(words.len());
+                        (join(&words[..pos]), None, join(&words[pos..]))
+                    }
+                }
+            }
         }
     }
 
-    /// This function instantiates a EmailAddress from a JSON string.
+    /// This function validates the postal code using the pattern of the country of the Address.
     ///
     /// #Example
     ///
-    /// ```rust     
+    /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// let serialized = r#"{
-    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
-    ///   "created_dtm":1711833619,
-    ///   "modified_dtm":1711833619,
-    ///   "category":"home",
-    ///   "address":"myemail@example.com"
-    /// }"#;
-    /// let mut email = EmailAddress::deserialized(&serialized.as_bytes()).unwrap();
+    /// let countries = Countries::new();
+    /// let address = Address::new(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
     ///
-    /// assert_eq!(email.created_dtm, 1711833619);
-    /// assert_eq!(email.modified_dtm, 1711833619);
-    /// assert_eq!(email.category, "home".to_string());
+    /// assert!(address.validate_postal_code(&countries).is_ok());
     /// ```
-    pub fn deserialized(serialized: &[u8]) -> Result<EmailAddress, DeserializeError> {
-        match serde_json::from_slice(&serialized) {
-            Ok(item) => Ok(item),
-            Err(err) => {
-                println!("{}", err);
-                Err(DeserializeError)
-            }
+    pub fn validate_postal_code(&self, countries: &Countries) -> Result<(), ScaffoldingError> {
+        let country = match countries.get_country_by_iso_3_code(self.country_code.clone()) {
+            Some(c) => c,
+            None => return Err(ScaffoldingError::PostalCode),
+        };
+
+        country.validate_postal_code_pattern()?;
+        match (&country.postal_code_pattern, &self.postal_code) {
+            (None, _) => Ok(()),
+            (Some(_), Some(code)) if country.is_valid_postal_code(code) => Ok(()),
+            (Some(_), _) => Err(ScaffoldingError::PostalCode),
         }
     }
 
-    /// This function performs a quick check to see if the email address is properly formatted.
-    /// NOTE: This is not a validation that the email address is real.
+    /// This function validates the province, state, or county, (parsed from the third line)
+    /// using the subdivisions of the country of the Address, (see `Country::is_valid_subdivision()`).
+    /// If the country has no ISO 3166-2 subdivisions, (e.g.: Aruba) the Address must not have a region,
+    /// since it cannot be verified.
     ///
     /// #Example
     ///
-    /// ```rust     
+    /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// let email = EmailAddress::new(
-    ///     "home".to_string(),
-    ///     "myemail@example.com".to_string(),
+    /// let countries = Countries::new();
+    /// let address = Address::new(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
     /// );
     ///
-    /// assert_eq!(email.is_valid(), true);
+    /// assert!(address.validate_region(&countries).is_ok());
     /// ```
+    pub fn validate_region(&self, countries: &Countries) -> Result<(), ScaffoldingError> {
+        let country = match countries.get_country_by_iso_3_code(self.country_code.clone()) {
+            Some(c) => c,
+            None => return Err(ScaffoldingError::Region),
+        };
 
-    pub fn is_valid(&self) -> bool {
-        // use regex::Regex;
-        let exp = r#"(?:[a-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*|"(?:[\x01-\x08\x0b\x0c\x0e-\x1f\x21\x23-\x5b\x5d-\x7f]|\\[\x01-\x09\x0b\x0c\x0e-\x7f])*")@(?:(?:[a-z0-9](?:[a-z0-9-]*[a-z0-9])?\.)+[a-z0-9](?:[a-z0-9-]*[a-z0-9])?|\[(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?|[a-z0-9-]*[a-z0-9]:(?:[\x01-\x08\x0b\x0c\x0e-\x1f\x21-\x5a\x53-\x7f]|\\[\x01-\x09\x0b\x0c\x0e-\x7f])+)\])"#;
-        let re = Regex::new(exp).unwrap();
-        re.is_match(&self.address)
+        match (country.subdivisions.is_empty(), &self.region) {
+            (true, None) => Ok(()),
+            (_, Some(region)) if country.is_valid_subdivision(region) => Ok(()),
+            _ => Err(ScaffoldingError::Region),
+        }
     }
-    /// This function converts the EmailAddress to a serialize JSON string.
+
+    /// This function converts the timestamp to the local time of the Address using the time zone of its region,
+    /// (see `Country::get_timezone()`). Returns None if the country or its time zone is unknown, (e.g.: the region
+    /// of an Address in a country that spans several time zones is missing). Requires the `timezones` feature.
     ///
     /// #Example
     ///
-    /// ```rust     
+    /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// let mut email = EmailAddress::new(
-    ///       "home".to_string(),
-    ///       "myemail@example.com".to_string(),
+    /// let address = Address::new(
+    ///     "office".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "10 Downing Street".to_string(),
+    ///     "London, SW1A 2AA".to_string(),
+    ///     "United Kingdom".to_string(),
+    ///     "GBR".to_string(),
     /// );
-    /// println!("{}", email.serialize());
-    /// ```
-    pub fn serialize(&mut self) -> String {
-        serde_json::to_string(&self).unwrap()
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Note {
-    // The unique identifier of the note
-    pub id: String,
-    // The timestamp when the note was created
-    pub created_dtm: i64,
-    // The timestamp when the note was last modified
-    pub modified_dtm: i64,
-    // The identifier of the author of the note
-    pub author: String,
-    // The identifier of access rule of the note, (e.g.: public, internal, confidential)
-    pub access: String,
-    // The comment of the note
-    pub content: Vec<u8>,
-}
-
-impl Note {
-    /// This is the constructor function.
-    ///
-    /// #Example
-    ///
-    /// ```rust    
-    /// extern crate scaffolding_core;
+    /// // 2024-07-01 12:00:00 UTC
+    /// let local = address.local_time(1719835200).unwrap();
     ///
-    /// use scaffolding_core::*;
-    ///
-    /// let note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// assert_eq!(local.to_rfc3339(), "2024-07-01T13:00:00+01:00");
     /// ```
-    pub fn new(auth: String, cont: Vec<u8>, acc: Option<String>) -> Self {
-        Self {
-            id: defaults::id(),
-            created_dtm: defaults::now(),
-            modified_dtm: defaults::now(),
-            author: auth,
-            access: match acc {
-                Some(a) => a,
-                None => defaults::access(),
-            },
-            content: cont,
-        }
+    #[cfg(feature = "timezones")]
+    pub fn local_time(&self, dtm: i64) -> Option<DateTime<FixedOffset>> {
+        let timezone = Countries::global()
+            .get_country_by_iso_3_code(self.country_code.clone())?
+            .get_timezone(self.region.as_deref())?
+            .parse::<Tz>()
+            .ok()?;
+        let local = timezone.timestamp_opt(dtm, 0).single()?;
+        Some(local.with_timezone(&local.offset().fix()))
     }
 
-    /// This function returns the content of the note as a string.
+    /// This function updates the Address.
     ///
     /// #Example
     ///
     /// ```rust     
-    /// extern crate scaffolding_core;
+    /// use scaffolding_core::*;
     ///
-    /// use scaffolding_core::{defaults, Note};
+    /// let mut address = Address::new(
+    ///   "shipping".to_string(),
+    ///   "acmes company".to_string(),
+    ///   "14 Main Street".to_string(),
+    ///   "Big City, NY 038845".to_string(),
+    ///   "USA".to_string(),
+    ///   "USA".to_string()
+    /// );
     ///
-    /// let note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
-    /// assert_eq!(note.content_as_string().unwrap(), "This was updated".to_string());
+    /// address.update(
+    ///   "billing".to_string(),
+    ///   "acmes company".to_string(),
+    ///   "14 Main Street".to_string(),
+    ///   "Big City, NY 038845".to_string(),
+    ///   "USA".to_string(),
+    ///   "USA".to_string());
+    ///
+    /// assert_eq!(address.category, "billing".to_string());
     /// ```
-    pub fn content_as_string(&self) -> Result<String, String> {
-        String::from_utf8(self.content.clone())
-            .map_err(|non_utf8| String::from_utf8_lossy(non_utf8.as_bytes()).into_owned())
+    pub fn update(
+        &mut self,
+        category: impl Into<Category>,
+        line_1: String,
+        line_2: String,
+        line_3: String,
+        line_4: String,
+        country_code: String,
+    ) {
+        let category = category.into();
+        // the primary designation is per category
+        if self.category != category {
+            self.primary = false;
+        }
+        self.category = category;
+        self.line_1 = line_1;
+        self.line_2 = line_2;
+        self.line_3 = line_3;
+        self.line_4 = line_4;
+        self.country_code = country_code;
+        (self.city, self.region, self.postal_code) = Self::parse_locality(&self.line_3);
+        self.latitude = None;
+        self.longitude = None;
+        self.verification = None;
+        self.modified_dtm = defaults::now();
     }
+}
 
-    /// This function instantiates an ActivityItem from a JSON string.
+/// The supported styles for formatting an Address
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AddressStyle {
+    // One line per part with the country in capitals, (e.g.: for printing mailing labels)
+    Label,
+    // All the parts on a single line separated by commas
+    SingleLine,
+}
+
+/// The normalized result of verifying an Address with an address verification provider
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AddressVerification {
+    // Whether mail can be delivered to the address
+    pub deliverable: bool,
+    // The corrected lines of the address, (line_1 - line_4) if the provider suggests changes
+    pub corrected: Option<Vec<String>>,
+    // The confidence of the provider in the result, (0.0 - 1.0)
+    pub confidence: f64,
+    // The timestamp when the address was verified
+    pub verified_dtm: i64,
+}
+
+impl AddressVerification {
+    /// This is the constructor function.
     ///
     /// #Example
     ///
-    /// ```rust     
+    /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// let serialized = r#"{
-    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
-    ///   "created_dtm":1711833619,
-    ///   "modified_dtm":1711833619,
-    ///   "author":"fsmith",
-    ///   "access":"public",
-    ///   "content":[84,104,105,115,32,119,97,115,32,117,112,100,97,116,101,100]
-    /// }"#;
-    /// let mut note = Note::deserialized(&serialized.as_bytes()).unwrap();
+    /// let verification = AddressVerification::new(true, None, 0.9);
     ///
-    /// assert_eq!(note.created_dtm, 1711833619);
-    /// assert_eq!(note.modified_dtm, 1711833619);
-    /// assert_eq!(note.author, "fsmith".to_string());
-    /// assert_eq!(note.access, "public".to_string());
-    /// assert_eq!(note.content, "This was updated".as_bytes().to_vec());
+    /// assert!(verification.deliverable);
+    /// assert_eq!(verification.confidence, 0.9);
     /// ```
-    pub fn deserialized(serialized: &[u8]) -> Result<Note, DeserializeError> {
-        match serde_json::from_slice(&serialized) {
-            Ok(item) => Ok(item),
-            Err(err) => {
-                println!("{}", err);
-                Err(DeserializeError)
-            }
+    pub fn new(deliverable: bool, corrected: Option<Vec<String>>, confidence: f64) -> Self {
+        Self {
+            deliverable,
+            corrected,
+            confidence,
+            verified_dtm: defaults::now(),
         }
     }
+}
 
-    /// This function converts the ActivityItem to a serialize JSON string.
+/// An address verification provider, (e.g.: a postal service API).
+/// Requires the `verification` feature.
+#[cfg(feature = "verification")]
+pub trait AddressVerifier {
+    /// Verifies the Address and returns the normalized result of the provider.
+    fn verify(
+        &self,
+        address: &Address,
+    ) -> impl std::future::Future<Output = Result<AddressVerification, ScaffoldingError>> + Send;
+}
+
+// The mean radius of the earth used for the distance between addresses
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// A Scaffolding entity of any type, (tagged with its kind), so entities of different types can be kept together,
+/// (e.g.: `EntityCollection<AnyEntity>`) and downcast back to their concrete type.
+/// Use the `EntityRegistry` to load them from their serialized form.
+pub struct AnyEntity {
+    // The type tag of the entity, (e.g.: "customer")
+    kind: String,
+    // The entity
+    entity: Box<dyn AnyScaffolding>,
+}
+
+impl AnyEntity {
+    /// This is the constructor function.
     ///
     /// #Example
     ///
-    /// ```rust     
+    /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// let mut note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
-    /// println!("{}", note.serialize());
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    /// struct Customer {}
+    ///
+    /// impl Customer {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let entity = AnyEntity::new("customer", Customer::new());
+    ///
+    /// assert_eq!(entity.kind(), "customer");
+    /// assert!(entity.is::<Customer>());
     /// ```
-    pub fn serialize(&mut self) -> String {
-        serde_json::to_string(&self).unwrap()
+    pub fn new<T>(kind: &str, entity: T) -> Self
+    where
+        T: Scaffolding<Id = String> + Serialize + 'static,
+    {
+        Self {
+            kind: kind.to_string(),
+            entity: Box::new(entity),
+        }
     }
 
-    /// This function updates the note and sets the modified_dtm.
-    /// The modified_dtm will not be changed if the attributes are written to directly.
+    /// Returns the type tag of the entity.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Determines if the entity is of the concrete type.
+    pub fn is<T: 'static>(&self) -> bool {
+        self.entity.as_any().is::<T>()
+    }
+
+    /// Returns a reference to the entity as its concrete type, (or `None` if it is another type).
     ///
     /// #Example
     ///
-    /// ```rust     
+    /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// let serialized = r#"{
-    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
-    ///   "created_dtm":1711833619,
-    ///   "modified_dtm":1711833619,
-    ///   "author":"fsmith",
-    ///   "access":"public",
-    ///   "content":[84,104,105,115,32,119,97,115,32,117,112,100,97,116,101,100]
-    /// }"#;
-    /// let mut note = Note::deserialized(&serialized.as_bytes()).unwrap();
-    /// let first_modified = note.modified_dtm.clone();
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    /// struct Customer {
+    ///     name: String,
+    /// }
     ///
-    /// note.update("fsmith".to_string(), "This was updated again".as_bytes().to_vec(), Some("private".to_string()));
+    /// impl Customer {
+    ///     #[scaffolding_fn]
+    ///     fn new(name: &str) -> Self {
+    ///         Self {
+    ///             name: name.to_string(),
+    ///         }
+    ///     }
+    /// }
     ///
-    /// assert_eq!(note.author, "fsmith".to_string());
-    /// assert_eq!(note.access, "private".to_string());
-    /// assert_eq!(note.content, "This was updated again".as_bytes().to_vec());
-    /// assert!(note.modified_dtm > first_modified);
+    /// let entity = AnyEntity::new("customer", Customer::new("Frank"));
+    ///
+    /// assert_eq!(entity.downcast_ref::<Customer>().unwrap().name, "Frank".to_string());
     /// ```
-    pub fn update(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>) {
-        self.author = auth;
-        self.content = cont;
-        self.access = match acc {
-            Some(a) => a,
-            None => self.access.clone(),
-        };
-        self.modified_dtm = defaults::now();
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.entity.as_any().downcast_ref::<T>()
     }
-}
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct PhoneNumber {
-    // The unique identifier of the note
-    pub id: String,
-    // The timestamp when the note was created
-    pub created_dtm: i64,
-    // The timestamp when the note was last modified
-    pub modified_dtm: i64,
-    // The type of address, (e.g.: Login, Personal, Work, Primary Contact, Assistant, etc.)
-    pub category: String,
-    // The phone number
-    pub number: String,
-    // The country code of the phone number (Use Alpha 3 codes)
-    pub country_code: String,
-}
+    /// Returns a mutable reference to the entity as its concrete type, (or `None` if it is another type).
+    pub fn downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.entity.as_any_mut().downcast_mut::<T>()
+    }
 
-impl PhoneNumber {
-    /// This is the constructor function.
+    /// Converts the entity back to its concrete type, (or returns the AnyEntity if it is another type).
     ///
     /// #Example
     ///
@@ -840,87 +1575,9935 @@ impl PhoneNumber {
     ///
     /// use scaffolding_core::*;
     ///
-    /// fn main() {
-    ///   let phone = PhoneNumber::new(
-    ///       "home".to_string(),
-    ///       "8482493561".to_string(),
-    ///       "USA".to_string(),
-    ///   );
-    ///   
-    ///   // scaffolding attributes
-    ///   println!("{}", phone.id);
-    ///   println!("{}", phone.created_dtm);
-    ///   println!("{}", phone.modified_dtm,);
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    /// struct Customer {}
+    ///
+    /// impl Customer {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
     /// }
+    ///
+    /// let customer = Customer::new();
+    /// let entity = AnyEntity::new("customer", customer.clone());
+    ///
+    /// assert_eq!(entity.downcast::<Customer>().unwrap().id, customer.id);
     /// ```
-    pub fn new(category: String, number: String, country_code: String) -> Self {
-        Self {
-            id: defaults::id(),
-            created_dtm: defaults::now(),
-            modified_dtm: defaults::now(),
-            category: category,
-            number: number,
-            country_code: country_code,
+    pub fn downcast<T: 'static>(self) -> Result<T, AnyEntity> {
+        match self.is::<T>() {
+            true => Ok(*self.entity.into_any().downcast::<T>().unwrap()),
+            false => Err(self),
         }
     }
 
-    /// This function instantiates a PhoneNumber from a JSON string.
-    ///
-    /// #Example
-    ///
-    /// ```rust     
-    /// extern crate scaffolding_core;
-    ///
-    /// use scaffolding_core::*;
-    ///
-    /// let serialized = r#"{
-    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
-    ///   "created_dtm":1711833619,
-    ///   "modified_dtm":1711833619,
-    ///   "category":"home",
-    ///   "number":"8482493561",
-    ///   "country_code": "USA"
+    /// Returns the entity as a Scaffolding trait object.
+    pub fn entity(&self) -> &dyn Scaffolding<Id = String> {
+        self.entity.as_scaffolding()
+    }
+
+    /// Returns the entity as a mutable Scaffolding trait object.
+    pub fn entity_mut(&mut self) -> &mut dyn Scaffolding<Id = String> {
+        self.entity.as_scaffolding_mut()
+    }
+}
+
+impl fmt::Debug for AnyEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnyEntity")
+            .field("kind", &self.kind)
+            .field("id", &self.entity.get_id())
+            .finish()
+    }
+}
+
+impl Serialize for AnyEntity {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("AnyEntity", 2)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("entity", &self.entity.to_value())?;
+        state.end()
+    }
+}
+
+// the AnyEntity delegates to the entity, so it can be used where a Scaffolding object is expected
+impl Scaffolding for AnyEntity {
+    type Id = String;
+
+    fn log_activity(&mut self, name: String, descr: String) {
+        self.entity.log_activity(name, descr)
+    }
+
+    fn log_activity_with_data(&mut self, name: String, descr: String, data: Value) {
+        self.entity.log_activity_with_data(name, descr, data)
+    }
+
+    fn redact_activity(&mut self, predicate: &dyn Fn(&ActivityItem) -> bool) -> usize {
+        self.entity.redact_activity(predicate)
+    }
+
+    fn enable_activity_chain(&mut self) {
+        self.entity.enable_activity_chain()
+    }
+
+    fn verify_activity_chain(&self) -> bool {
+        self.entity.verify_activity_chain()
+    }
+
+    fn get_activity(&self, name: String) -> Vec<ActivityItem> {
+        self.entity.get_activity(name)
+    }
+
+    fn get_activity_ref(&self, name: &str) -> Vec<&ActivityItem> {
+        self.entity.get_activity_ref(name)
+    }
+
+    fn export_activity(
+        &self,
+        format: ExportFormat,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> String {
+        self.entity.export_activity(format, start, end)
+    }
+
+    fn deactivate(&mut self) {
+        self.entity.deactivate()
+    }
+
+    fn expire_now(&mut self) {
+        self.entity.expire_now()
+    }
+
+    fn get_id(&self) -> String {
+        self.entity.get_id()
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.entity.is_deleted()
+    }
+
+    fn reactivate(&mut self, inactive_days: i64, expire_days: i64) -> Result<(), ScaffoldingError> {
+        self.entity.reactivate(inactive_days, expire_days)
+    }
+}
+
+// the behavior the AnyEntity needs from the entity, (Scaffolding, Any and Serialize as trait objects)
+trait AnyScaffolding: Scaffolding<Id = String> {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+    fn as_scaffolding(&self) -> &dyn Scaffolding<Id = String>;
+    fn as_scaffolding_mut(&mut self) -> &mut dyn Scaffolding<Id = String>;
+    fn to_value(&self) -> Value;
+}
+
+impl<T> AnyScaffolding for T
+where
+    T: Scaffolding<Id = String> + Serialize + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_scaffolding(&self) -> &dyn Scaffolding<Id = String> {
+        self
+    }
+
+    fn as_scaffolding_mut(&mut self) -> &mut dyn Scaffolding<Id = String> {
+        self
+    }
+
+    fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Attachment {
+    // The unique identifier of the attachment
+    pub id: String,
+    // The timestamp when the attachment was created
+    pub created_dtm: i64,
+    // The timestamp when the attachment was last modified
+    pub modified_dtm: i64,
+    // The name of the file, (e.g.: invoice.pdf)
+    pub filename: String,
+    // The content type of the file, (e.g.: application/pdf, image/png)
+    pub mime_type: String,
+    // The content of the file when it is stored with the object
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<Vec<u8>>,
+    // The location of the file when it is stored elsewhere, (e.g.: s3://documents/invoice.pdf)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locator: Option<String>,
+}
+
+impl Attachment {
+    /// This is the constructor function for a file that is stored with the object.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let attachment = Attachment::new(
+    ///     "invoice.pdf".to_string(),
+    ///     "application/pdf".to_string(),
+    ///     vec![37, 80, 68, 70],
+    /// );
+    ///
+    /// assert_eq!(attachment.size(), 4);
+    /// ```
+    pub fn new(filename: String, mime_type: String, bytes: Vec<u8>) -> Self {
+        Self {
+            id: defaults::id(),
+            created_dtm: defaults::now(),
+            modified_dtm: defaults::now(),
+            filename,
+            mime_type,
+            bytes: Some(bytes),
+            locator: None,
+        }
+    }
+
+    /// This is the constructor function for a file that is stored elsewhere, (e.g.: in a document store).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let attachment = Attachment::new_with_locator(
+    ///     "photo.png".to_string(),
+    ///     "image/png".to_string(),
+    ///     "s3://documents/photo.png".to_string(),
+    /// );
+    ///
+    /// assert!(attachment.bytes.is_none());
+    /// assert_eq!(attachment.locator, Some("s3://documents/photo.png".to_string()));
+    /// ```
+    pub fn new_with_locator(filename: String, mime_type: String, locator: String) -> Self {
+        Self {
+            id: defaults::id(),
+            created_dtm: defaults::now(),
+            modified_dtm: defaults::now(),
+            filename,
+            mime_type,
+            bytes: None,
+            locator: Some(locator),
+        }
+    }
+
+    /// This function returns the number of bytes that are stored with the object, (0 for a locator).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let attachment = Attachment::new("notes.txt".to_string(), "text/plain".to_string(), "Hi".as_bytes().to_vec());
+    ///
+    /// assert_eq!(attachment.size(), 2);
+    /// ```
+    pub fn size(&self) -> usize {
+        self.bytes.as_ref().map(|b| b.len()).unwrap_or(0)
+    }
+
+    /// This function determines if the attachment has the content type.
+    /// A wildcard subtype matches the whole type, (e.g.: image/*).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let attachment = Attachment::new("photo.png".to_string(), "image/png".to_string(), Vec::new());
+    ///
+    /// assert!(attachment.is_mime_type("IMAGE/PNG"));
+    /// assert!(attachment.is_mime_type("image/*"));
+    /// assert!(!attachment.is_mime_type("application/pdf"));
+    /// ```
+    pub fn is_mime_type(&self, mime_type: &str) -> bool {
+        let actual = self.mime_type.to_lowercase();
+        let expected = mime_type.to_lowercase();
+        match expected.strip_suffix("/*") {
+            Some(kind) => actual.split('/').next() == Some(kind),
+            None => actual.split(';').next().unwrap_or_default().trim() == expected,
+        }
+    }
+
+    /// This function removes the file, (the content and locator) and redacts the filename.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut attachment = Attachment::new("fsmith_passport.png".to_string(), "image/png".to_string(), vec![1, 2, 3]);
+    /// attachment.erase();
+    ///
+    /// assert_eq!(attachment.filename, defaults::redacted());
+    /// assert!(attachment.bytes.is_none());
+    /// ```
+    pub fn erase(&mut self) {
+        self.filename = defaults::redacted();
+        self.bytes = None;
+        self.locator = None;
+        self.modified_dtm = defaults::now();
+    }
+
+    /// This function instantiates an Attachment from a JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let serialized = r#"{
+    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
+    ///   "created_dtm":1711833619,
+    ///   "modified_dtm":1711833619,
+    ///   "filename":"photo.png",
+    ///   "mime_type":"image/png",
+    ///   "locator":"s3://documents/photo.png"
     /// }"#;
-    /// let mut phone = PhoneNumber::deserialized(&serialized.as_bytes()).unwrap();
+    /// let attachment = Attachment::deserialized(&serialized.as_bytes()).unwrap();
     ///
-    /// assert_eq!(phone.created_dtm, 1711833619);
-    /// assert_eq!(phone.modified_dtm, 1711833619);
-    /// assert_eq!(phone.category, "home".to_string());
+    /// assert_eq!(attachment.filename, "photo.png".to_string());
+    /// assert!(attachment.bytes.is_none());
     /// ```
-    pub fn deserialized(serialized: &[u8]) -> Result<PhoneNumber, DeserializeError> {
-        match serde_json::from_slice(&serialized) {
+    pub fn deserialized(serialized: &[u8]) -> Result<Attachment, ScaffoldingError> {
+        match serde_json::from_slice(serialized) {
             Ok(item) => Ok(item),
-            Err(err) => {
-                println!("{}", err);
-                Err(DeserializeError)
-            }
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "Attachment".to_string(),
+            }),
         }
     }
 
-    /// This function converts the PhoneNumber to a serialize JSON string.
+    /// This function converts the Attachment to a serialize JSON string.
     ///
     /// #Example
     ///
-    /// ```rust    
+    /// ```rust
     /// extern crate scaffolding_core;
     ///
     /// use scaffolding_core::*;
     ///
-    /// let mut phone = PhoneNumber::new(
-    ///       "home".to_string(),
-    ///       "8482493561".to_string(),
-    ///       "USA".to_string(),
+    /// let attachment = Attachment::new("photo.png".to_string(), "image/png".to_string(), vec![1, 2]);
+    /// println!("{}", attachment.serialize());
+    /// ```
+    pub fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+/// The category of an Address, EmailAddress or PhoneNumber, (e.g.: `Category::Shipping` or `Category::Other("assistant")`).
+/// The common categories don't allocate and are (de)serialized as their lowercase name, (e.g.: "shipping"),
+/// so the categories are the same strings as before.
+///
+/// #Example
+///
+/// ```rust
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// assert_eq!(Category::from("shipping"), Category::Shipping);
+/// assert_eq!(Category::from("assistant"), Category::Other("assistant".to_string()));
+/// assert_eq!(Category::Home, "home");
+/// assert_eq!(Category::Work.to_string(), "work".to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum Category {
+    Billing,
+    Home,
+    Login,
+    Mailing,
+    Mobile,
+    Office,
+    Personal,
+    Shipping,
+    Work,
+    // Any other category, (kept as is)
+    Other(String),
+}
+
+impl Category {
+    /// Returns the name of the category, (e.g.: "shipping").
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert_eq!(Category::Shipping.as_str(), "shipping");
+    /// assert_eq!(Category::Other("Assistant".to_string()).as_str(), "Assistant");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Category::Billing => "billing",
+            Category::Home => "home",
+            Category::Login => "login",
+            Category::Mailing => "mailing",
+            Category::Mobile => "mobile",
+            Category::Office => "office",
+            Category::Personal => "personal",
+            Category::Shipping => "shipping",
+            Category::Work => "work",
+            Category::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for Category {
+    /// The common categories are matched exactly, (e.g.: "Home" is `Category::Other`)
+    fn from(name: &str) -> Self {
+        match name {
+            "billing" => Category::Billing,
+            "home" => Category::Home,
+            "login" => Category::Login,
+            "mailing" => Category::Mailing,
+            "mobile" => Category::Mobile,
+            "office" => Category::Office,
+            "personal" => Category::Personal,
+            "shipping" => Category::Shipping,
+            "work" => Category::Work,
+            _ => Category::Other(name.to_string()),
+        }
+    }
+}
+
+impl From<String> for Category {
+    fn from(name: String) -> Self {
+        match Category::from(name.as_str()) {
+            Category::Other(_) => Category::Other(name),
+            category => category,
+        }
+    }
+}
+
+impl From<Category> for String {
+    fn from(category: Category) -> Self {
+        match category {
+            Category::Other(name) => name,
+            category => category.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl PartialEq<str> for Category {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Category {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Category {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+/// The scaffolding attributes of an object that are carried over when it is converted to another type,
+/// (see `ScaffoldingConvert`). The optional attributes are `None` when the object doesn't have them.
+#[derive(Clone, Debug, Default)]
+pub struct CoreAttributes<I> {
+    // The unique identifier of the object
+    pub id: I,
+    // The timestamp when the object was created
+    pub created_dtm: i64,
+    // The timestamp when the object was last modified
+    pub modified_dtm: i64,
+    // The timestamp when the object is no longer active
+    pub inactive_dtm: Option<i64>,
+    // The timestamp when the object is expired
+    pub expired_dtm: Option<i64>,
+    // The list of activity performed on the object
+    pub activity: Option<Vec<ActivityItem>>,
+    // The addresses of the object
+    pub addresses: Option<BTreeMap<String, Address>>,
+    // The attachments of the object
+    pub attachments: Option<BTreeMap<String, Attachment>>,
+    // The email addresses of the object
+    pub email_addresses: Option<BTreeMap<String, EmailAddress>>,
+    // The metadata of the object
+    pub metadata: Option<BTreeMap<String, String>>,
+    // The JSON metadata of the object
+    pub metadata_json: Option<BTreeMap<String, Value>>,
+    // The notes of the object
+    pub notes: Option<BTreeMap<String, Note>>,
+    // The phone numbers of the object
+    pub phone_numbers: Option<BTreeMap<String, PhoneNumber>>,
+    // The relationships of the object
+    pub relationships: Option<BTreeMap<String, Relationship>>,
+    // The tags of the object
+    pub tags: Option<Vec<String>>,
+    // The owner of the object
+    pub owner: Option<String>,
+    // The principals the object is shared with
+    pub shared_with: Option<Vec<(String, Permission)>>,
+    // The timestamp when the object was soft deleted
+    pub deleted_dtm: Option<Option<i64>>,
+    // The workflow status of the object
+    pub status: Option<String>,
+    // The identifier of the tenant that owns the object
+    pub tenant_id: Option<String>,
+}
+
+// The bundled countries, (parsed once by `Countries::global()`)
+static COUNTRIES: OnceLock<Countries> = OnceLock::new();
+// The compiled postal code patterns keyed by the pattern, (compiled once by `Country::postal_code_regex()`)
+static POSTAL_CODE_PATTERNS: OnceLock<Mutex<BTreeMap<String, Regex>>> = OnceLock::new();
+
+pub struct Countries {
+    // The list of countries
+    pub list: Vec<Country>,
+    // The positions of the countries in the list keyed by their ISO 2 code
+    iso_2_index: BTreeMap<String, usize>,
+    // The positions of the countries in the list keyed by their ISO 3 code
+    iso_3_index: BTreeMap<String, usize>,
+    // The positions of the countries in the list keyed by their phone code
+    phone_code_index: BTreeMap<String, usize>,
+    // The positions of the countries in the list keyed by their numeric code
+    numeric_index: BTreeMap<u16, usize>,
+    // The positions of the countries in the list keyed by their name
+    name_index: BTreeMap<String, usize>,
+}
+
+impl Countries {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///   let countries = Countries::new();
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        let data = include_str!("countries.json");
+        let array: Value = serde_json::from_str(data).unwrap();
+        let postal_codes: Value = serde_json::from_str(include_str!("postal_codes.json")).unwrap();
+        let phone_numbers: Value =
+            serde_json::from_str(include_str!("phone_numbers.json")).unwrap();
+        // the supplementary data keyed by the ISO 3 code, (so each country is a lookup instead of a scan)
+        let postal_codes: BTreeMap<&str, &Value> = postal_codes
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| (p["iso_3_code"].as_str().unwrap(), p))
+            .collect();
+        let phone_numbers: BTreeMap<&str, &Value> = phone_numbers
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| (p["iso_3_code"].as_str().unwrap(), p))
+            .collect();
+        let currencies: Value = serde_json::from_str(include_str!("currencies.json")).unwrap();
+        let currencies: BTreeMap<&str, &Value> = currencies
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| (c["iso_3_code"].as_str().unwrap(), c))
+            .collect();
+        let languages: Value = serde_json::from_str(include_str!("languages.json")).unwrap();
+        let languages: BTreeMap<&str, &Value> = languages
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|l| (l["iso_3_code"].as_str().unwrap(), &l["languages"]))
+            .collect();
+        let subdivisions: Value = serde_json::from_str(include_str!("subdivisions.json")).unwrap();
+        let timezones: Value = serde_json::from_str(include_str!("timezones.json")).unwrap();
+        let timezones: BTreeMap<&str, &Value> = timezones
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| (t["iso_3_code"].as_str().unwrap(), t))
+            .collect();
+        let names: Value = serde_json::from_str(include_str!("names.json")).unwrap();
+        let names: BTreeMap<&str, &Value> = names
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|n| (n["iso_3_code"].as_str().unwrap(), &n["names"]))
+            .collect();
+        let subdivisions: BTreeMap<&str, &Value> = subdivisions
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| (s["iso_3_code"].as_str().unwrap(), &s["subdivisions"]))
+            .collect();
+        let countries: Vec<Country> = array
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| {
+                let mut country = Country::new(
+                    c["country_name"].as_str().unwrap().to_string(),
+                    c["phone_code"].as_str().unwrap().to_string(),
+                    c["iso_2_code"].as_str().unwrap().to_string(),
+                    c["iso_3_code"].as_str().unwrap().to_string(),
+                );
+                country.numeric_code = c["numeric_code"].as_u64().map(|n| n as u16);
+                country.postal_code_pattern = postal_codes
+                    .get(country.iso_3_code.as_str())
+                    .map(|p| p["pattern"].as_str().unwrap().to_string());
+                country.phone_number_length =
+                    phone_numbers.get(country.iso_3_code.as_str()).map(|p| {
+                        (
+                            p["min_length"].as_u64().unwrap() as usize,
+                            p["max_length"].as_u64().unwrap() as usize,
+                        )
+                    });
+                country.currency = currencies.get(country.iso_3_code.as_str()).map(|c| {
+                    Currency::new(
+                        c["currency_code"].as_str().unwrap().to_string(),
+                        c["currency_name"].as_str().unwrap().to_string(),
+                        c["currency_symbol"].as_str().unwrap().to_string(),
+                    )
+                });
+                country.languages = languages
+                    .get(country.iso_3_code.as_str())
+                    .map(|l| serde_json::from_value((*l).clone()).unwrap())
+                    .unwrap_or_default();
+                country.subdivisions = subdivisions
+                    .get(country.iso_3_code.as_str())
+                    .map(|s| serde_json::from_value((*s).clone()).unwrap())
+                    .unwrap_or_default();
+                if let Some(t) = timezones.get(country.iso_3_code.as_str()) {
+                    country.timezones = serde_json::from_value(t["timezones"].clone()).unwrap();
+                    country.region_timezones = match t.get("regions") {
+                        Some(r) => serde_json::from_value(r.clone()).unwrap(),
+                        None => BTreeMap::new(),
+                    };
+                }
+                country.names = names
+                    .get(country.iso_3_code.as_str())
+                    .map(|n| serde_json::from_value((*n).clone()).unwrap())
+                    .unwrap_or_default();
+                country
+            })
+            .collect();
+        let mut countries = Self {
+            list: countries,
+            iso_2_index: BTreeMap::new(),
+            iso_3_index: BTreeMap::new(),
+            phone_code_index: BTreeMap::new(),
+            numeric_index: BTreeMap::new(),
+            name_index: BTreeMap::new(),
+        };
+        countries.reindex();
+        countries
+    }
+
+    /// Returns the bundled countries, which are only parsed the first time the function is called,
+    /// (use it instead of `new()` when the countries are looked up repeatedly, e.g.: validating in a loop).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::global();
+    ///
+    /// assert_eq!(countries.get_country_by_iso_3_code("USA".to_string()).unwrap().iso_2_code, "US");
+    /// assert!(std::ptr::eq(countries, Countries::global()));
+    /// ```
+    pub fn global() -> &'static Countries {
+        COUNTRIES.get_or_init(Countries::new)
+    }
+
+    /// This is the constructor function for a custom dataset, (a JSON array of countries in the format of
+    /// `Country` or of the bundled countries.json, where the name is `country_name`).
+    /// A postal code pattern that is not a valid regular expression is an error.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let data = r#"[{"name": "Atlantis", "phone_code": "999", "iso_2_code": "XA", "iso_3_code": "XAT"}]"#;
+    /// let countries = Countries::from_json(data.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(countries.list.len(), 1);
+    /// assert_eq!(countries.get_country_by_iso_2_code("XA".to_string()).unwrap().name, "Atlantis");
+    /// ```
+    pub fn from_json(data: &[u8]) -> Result<Self, ScaffoldingError> {
+        match serde_json::from_slice::<Vec<Country>>(data) {
+            Ok(list) => {
+                let mut countries = Self {
+                    list,
+                    iso_2_index: BTreeMap::new(),
+                    iso_3_index: BTreeMap::new(),
+                    phone_code_index: BTreeMap::new(),
+                    numeric_index: BTreeMap::new(),
+                    name_index: BTreeMap::new(),
+                };
+                for country in countries.list.iter() {
+                    country.validate_postal_code_pattern()?;
+                }
+                countries.reindex();
+                Ok(countries)
+            }
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "Countries".to_string(),
+            }),
+        }
+    }
+
+    /// This is the constructor function for a custom dataset that is read from a file, (see `from_json()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert!(Countries::from_json_file("./missing/countries.json").is_err());
+    /// ```
+    pub fn from_json_file(path: &str) -> Result<Self, ScaffoldingError> {
+        match fs::read(path) {
+            Ok(data) => Self::from_json(&data),
+            Err(err) => Err(ScaffoldingError::Storage(Box::new(err))),
+        }
+    }
+
+    /// Replaces the countries that have the same ISO 3 Code as the overrides and adds the others,
+    /// (e.g.: name variants or internal pseudo-countries). The data that an override doesn't have,
+    /// (e.g.: the currency or subdivisions) is kept from the country it replaces.
+    /// A postal code pattern of an override that is not a valid regular expression is an error.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new().with_overrides(vec![
+    ///     Country::new("Türkiye".to_string(), "90".to_string(), "TR".to_string(), "TUR".to_string()),
+    ///     Country::new("Internal".to_string(), "0".to_string(), "XI".to_string(), "XIN".to_string()),
+    /// ]).unwrap();
+    /// let turkey = countries.get_country_by_iso_3_code("TUR".to_string()).unwrap();
+    ///
+    /// assert_eq!(turkey.name, "Türkiye");
+    /// assert_eq!(turkey.currency.as_ref().unwrap().code, "TRY");
+    /// assert!(countries.get_country_by_name("Internal".to_string()).is_some());
+    /// ```
+    pub fn with_overrides(mut self, overrides: Vec<Country>) -> Result<Self, ScaffoldingError> {
+        for mut country in overrides {
+            country.validate_postal_code_pattern()?;
+            let iso_3_code = Self::normalize_code(&country.iso_3_code);
+            match self
+                .list
+                .iter()
+                .position(|c| Self::normalize_code(&c.iso_3_code) == iso_3_code)
+            {
+                Some(i) => {
+                    let existing = &mut self.list[i];
+                    country.numeric_code = country.numeric_code.or(existing.numeric_code);
+                    country.postal_code_pattern = country
+                        .postal_code_pattern
+                        .or(existing.postal_code_pattern.take());
+                    country.phone_number_length =
+                        country.phone_number_length.or(existing.phone_number_length);
+                    country.currency = country.currency.or(existing.currency.take());
+                    if country.languages.is_empty() {
+                        country.languages = std::mem::take(&mut existing.languages);
+                    }
+                    if country.subdivisions.is_empty() {
+                        country.subdivisions = std::mem::take(&mut existing.subdivisions);
+                    }
+                    if country.timezones.is_empty() {
+                        country.timezones = std::mem::take(&mut existing.timezones);
+                    }
+                    if country.region_timezones.is_empty() {
+                        country.region_timezones = std::mem::take(&mut existing.region_timezones);
+                    }
+                    if country.names.is_empty() {
+                        country.names = std::mem::take(&mut existing.names);
+                    }
+                    self.list[i] = country;
+                }
+                None => self.list.push(country),
+            }
+        }
+        self.reindex();
+        Ok(self)
+    }
+
+    /// Retrieves the time zones, (IANA identifiers) of the country based on the ISO 3 Code.
+    /// The principal time zone of the country is first and the list is empty if the country is unknown,
+    /// (see `Country::get_timezone()` for the time zone of a region).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    ///
+    /// assert_eq!(countries.get_timezones("USA".to_string())[0], "America/New_York");
+    /// assert!(countries.get_timezones("USA".to_string()).contains(&"America/Los_Angeles".to_string()));
+    /// assert_eq!(countries.get_timezones("FRA".to_string()), vec!["Europe/Paris".to_string()]);
+    /// ```
+    pub fn get_timezones(&self, iso_3_code: String) -> &[String] {
+        match self.get_country_by_iso_3_code(iso_3_code) {
+            Some(country) => &country.timezones,
+            None => &[],
+        }
+    }
+
+    /// Rebuilds the indexes that the lookups use, (must be called after the list has been changed).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut countries = Countries::new();
+    /// countries.list.push(Country::new("Atlantis".to_string(), "999".to_string(), "XA".to_string(), "XAT".to_string()));
+    /// countries.reindex();
+    ///
+    /// assert_eq!(countries.get_country_by_iso_3_code("XAT".to_string()).unwrap().name, "Atlantis");
+    /// ```
+    pub fn reindex(&mut self) {
+        self.iso_2_index.clear();
+        self.iso_3_index.clear();
+        self.phone_code_index.clear();
+        self.numeric_index.clear();
+        self.name_index.clear();
+        // the last country wins when a code is shared, (e.g.: phone code 1)
+        for (i, country) in self.list.iter().enumerate() {
+            self.iso_2_index
+                .insert(Self::normalize_code(&country.iso_2_code), i);
+            self.iso_3_index
+                .insert(Self::normalize_code(&country.iso_3_code), i);
+            self.phone_code_index
+                .insert(Self::normalize_phone_code(&country.phone_code), i);
+            if let Some(numeric_code) = country.numeric_code {
+                self.numeric_index.insert(numeric_code, i);
+            }
+            self.name_index
+                .insert(Self::normalize_name(&country.name), i);
+        }
+    }
+
+    // the codes are compared in uppercase without the surrounding whitespace
+    fn normalize_code(code: &str) -> String {
+        code.trim().to_uppercase()
+    }
+
+    // the phone codes are compared without the surrounding whitespace and international prefix, (e.g.: +44)
+    fn normalize_phone_code(phone_code: &str) -> String {
+        phone_code.trim().trim_start_matches('+').trim().to_string()
+    }
+
+    // the names are compared in lowercase with the whitespace collapsed
+    fn normalize_name(name: &str) -> String {
+        name.split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    /// Verifies a Country
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///  let countries = Countries::new();
+    ///   let country = Country::new(
+    ///       "United States".to_string(),
+    ///       "1".to_string(),
+    ///       "US".to_string(),
+    ///       "USA".to_string()
+    ///   );
+    ///
+    ///   assert_eq!(countries.is_valid(country), true);
+    /// }
+    /// ```
+    pub fn is_valid(&self, country: Country) -> bool {
+        let found = self.list.iter().filter(|c| {
+            c.name == country.name
+                && c.phone_code == country.phone_code
+                && c.iso_2_code == country.iso_2_code
+                && c.iso_3_code == country.iso_3_code
+        });
+        !matches!(found.count(), 0)
+    }
+
+    /// Retrieves the currency of the country based on the ISO 3 Code, (None if the country is unknown or has no currency).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let currency = countries.get_currency_for("USA".to_string()).unwrap();
+    ///
+    /// assert_eq!(currency.code, "USD");
+    /// assert_eq!(currency.name, "US Dollar");
+    /// assert_eq!(currency.symbol, "$");
+    /// ```
+    pub fn get_currency_for(&self, iso_3_code: String) -> Option<&Currency> {
+        self.get_country_by_iso_3_code(iso_3_code)?
+            .currency
+            .as_ref()
+    }
+
+    /// Retrieves the countries where the language is an official language based on the ISO 639-1 Code, (ignoring case).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let spanish = countries.get_countries_by_language("es".to_string());
+    ///
+    /// assert!(spanish.iter().any(|c| c.iso_3_code == "MEX"));
+    /// assert!(!spanish.iter().any(|c| c.iso_3_code == "BRA"));
+    /// ```
+    pub fn get_countries_by_language(&self, language: String) -> Vec<&Country> {
+        let language = language.trim().to_lowercase();
+        self.list
+            .iter()
+            .filter(|c| c.languages.contains(&language))
+            .collect()
+    }
+
+    /// Retrieves the first-level subdivisions, (states, provinces, etc.) of the country based on the ISO 3 Code.
+    /// The list is empty if the country is unknown or ISO 3166-2 defines no subdivisions for it, (e.g.: Aruba).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let states = countries.get_subdivisions("DEU".to_string());
+    ///
+    /// assert_eq!(states.len(), 16);
+    /// assert!(states.iter().any(|s| s.code == "DE-BY" && s.name == "Bayern"));
+    /// ```
+    pub fn get_subdivisions(&self, iso_3_code: String) -> &[Subdivision] {
+        match self.get_country_by_iso_3_code(iso_3_code) {
+            Some(country) => &country.subdivisions,
+            None => &[],
+        }
+    }
+
+    /// Retrieves a Country based on the ISO 2 Code, (ignoring case and the surrounding whitespace)
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///  let countries = Countries::new();
+    ///  let country = countries.get_country_by_iso_2_code("US".to_string()).unwrap();
+    ///
+    ///  assert_eq!(country.name, "United States");
+    ///  assert_eq!(country.phone_code, "1");
+    ///  assert_eq!(country.iso_2_code, "US");
+    ///  assert_eq!(country.iso_3_code, "USA");
+    /// }
+    /// ```
+    pub fn get_country_by_iso_2_code(&self, iso_2_code: String) -> Option<&Country> {
+        let iso_2_code = Self::normalize_code(&iso_2_code);
+        self.iso_2_index
+            .get(&iso_2_code)
+            .and_then(|i| self.list.get(*i))
+            .filter(|c| Self::normalize_code(&c.iso_2_code) == iso_2_code)
+    }
+
+    /// Retrieves a Country based on the ISO 3 Code, (ignoring case and the surrounding whitespace)
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///  let countries = Countries::new();
+    ///  let country = countries.get_country_by_iso_3_code("USA".to_string()).unwrap();
+    ///
+    ///  assert_eq!(country.name, "United States");
+    ///  assert_eq!(country.phone_code, "1");
+    ///  assert_eq!(country.iso_2_code, "US");
+    ///  assert_eq!(country.iso_3_code, "USA");
+    /// }
+    /// ```
+    pub fn get_country_by_iso_3_code(&self, iso_3_code: String) -> Option<&Country> {
+        let iso_3_code = Self::normalize_code(&iso_3_code);
+        self.iso_3_index
+            .get(&iso_3_code)
+            .and_then(|i| self.list.get(*i))
+            .filter(|c| Self::normalize_code(&c.iso_3_code) == iso_3_code)
+    }
+
+    /// Retrieves a Country based on the name, (ignoring case and extra whitespace)
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///  let countries = Countries::new();
+    ///  let country = countries.get_country_by_name(" united  states ".to_string()).unwrap();
+    ///
+    ///  assert_eq!(country.name, "United States");
+    ///  assert_eq!(country.iso_3_code, "USA");
+    /// }
+    /// ```
+    pub fn get_country_by_name(&self, name: String) -> Option<&Country> {
+        let name = Self::normalize_name(&name);
+        self.name_index
+            .get(&name)
+            .and_then(|i| self.list.get(*i))
+            .filter(|c| Self::normalize_name(&c.name) == name)
+    }
+
+    /// Retrieves a Country based on the ISO numeric code
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///  let countries = Countries::new();
+    ///  let country = countries.get_country_by_numeric_code(840).unwrap();
+    ///
+    ///  assert_eq!(country.name, "United States");
+    ///  assert_eq!(country.numeric_code, Some(840));
+    ///  assert_eq!(country.iso_3_code, "USA");
+    /// }
+    /// ```
+    pub fn get_country_by_numeric_code(&self, numeric_code: u16) -> Option<&Country> {
+        self.numeric_index
+            .get(&numeric_code)
+            .and_then(|i| self.list.get(*i))
+            .filter(|c| c.numeric_code == Some(numeric_code))
+    }
+
+    /// Retrieves a Country based on the international phone code, (ignoring the surrounding whitespace and a leading `+`)
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///  let countries = Countries::new();
+    ///  let country = countries.get_country_by_phone_code("1".to_string()).unwrap();
+    ///
+    ///  assert_eq!(country.name, "United States");
+    ///  assert_eq!(country.phone_code, "1");
+    ///  assert_eq!(country.iso_2_code, "US");
+    ///  assert_eq!(country.iso_3_code, "USA");
+    /// }
+    /// ```
+    pub fn get_country_by_phone_code(&self, phone_code: String) -> Option<&Country> {
+        let phone_code = Self::normalize_phone_code(&phone_code);
+        self.phone_code_index
+            .get(&phone_code)
+            .and_then(|i| self.list.get(*i))
+            .filter(|c| Self::normalize_phone_code(&c.phone_code) == phone_code)
+    }
+
+    /// Searches the countries by a fragment of their name, (e.g.: to resolve imported data to a country).
+    /// The candidates are ranked by how well they match, (the same name, a name that starts with the fragment,
+    /// a name that contains the fragment, then misspelled names by their Levenshtein distance).
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///  let countries = Countries::new();
+    ///
+    ///  assert_eq!(countries.search("Untied States".to_string())[0].iso_3_code, "USA");
+    ///  assert_eq!(countries.search("korea".to_string()).len(), 2);
+    /// }
+    /// ```
+    pub fn search(&self, name_fragment: String) -> Vec<&Country> {
+        let fragment = Self::normalize_name(&name_fragment);
+        if fragment.is_empty() {
+            return Vec::new();
+        }
+        // misspellings are limited to about one edit for every three characters
+        let max_distance = (fragment.chars().count() / 3).max(1);
+
+        let mut candidates: Vec<(usize, usize, &Country)> = self
+            .list
+            .iter()
+            .filter_map(|c| {
+                let name = Self::normalize_name(&c.name);
+                match name.find(&fragment) {
+                    Some(_) if name == fragment => Some((0, 0, c)),
+                    Some(0) => Some((1, 0, c)),
+                    Some(_) => Some((2, 0, c)),
+                    None => {
+                        let distance = Self::levenshtein(&name, &fragment);
+                        match distance <= max_distance {
+                            true => Some((3, distance, c)),
+                            false => None,
+                        }
+                    }
+                }
+            })
+            .collect();
+        candidates.sort_by(|a, b| (a.0, a.1, &a.2.name).cmp(&(b.0, b.1, &b.2.name)));
+        candidates.into_iter().map(|(_, _, c)| c).collect()
+    }
+
+    // the number of single character edits that change one text into the other
+    fn levenshtein(left: &str, right: &str) -> usize {
+        let right: Vec<char> = right.chars().collect();
+        let mut previous: Vec<usize> = (0..=right.len()).collect();
+        for (i, l) in left.chars().enumerate() {
+            let mut current = vec![i + 1];
+            for (j, r) in right.iter().enumerate() {
+                let substitution = previous[j] + usize::from(l != *r);
+                current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+            }
+            previous = current;
+        }
+        previous[right.len()]
+    }
+}
+
+impl Default for Countries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// A country definition
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Country {
+    // Textual name of the coutnry
+    #[serde(alias = "country_name")]
+    pub name: String,
+    // The code used for international phone calls
+    pub phone_code: String,
+    // The 2 char abbreviation
+    pub iso_2_code: String,
+    // The 3 char abbreviation
+    pub iso_3_code: String,
+    // The ISO 3166-1 numeric code, (None if the country doesn't have one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric_code: Option<u16>,
+    // The pattern that the postal codes of the country must match, (None if not known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_code_pattern: Option<String>,
+    // The minimum and maximum digits of the national phone numbers of the country, (None if not known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number_length: Option<(usize, usize)>,
+    // The currency (ISO 4217) of the country, (None if not known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    // The official languages (ISO 639-1) of the country, (empty if not known)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+    // The first-level subdivisions (ISO 3166-2) of the country, (empty if it has none)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subdivisions: Vec<Subdivision>,
+    // The time zones (IANA identifiers) of the country, (the principal time zone first)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timezones: Vec<String>,
+    // The time zones of the subdivisions keyed by their code, (only for countries where the time differs by region)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub region_timezones: BTreeMap<String, String>,
+    // The translated names of the country by language (ISO 639-1), (empty if not known)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub names: BTreeMap<String, String>,
+}
+
+impl Country {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::Country;
+    ///
+    /// fn main() {
+    ///    let country = Country::new("United States".to_string(), "1".to_string(), "US".to_string(), "USA".to_string());
+    ///
+    ///    assert_eq!(country.name, "United States".to_string());
+    ///    assert_eq!(country.phone_code, "1".to_string());
+    ///    assert_eq!(country.iso_2_code, "US".to_string());
+    ///    assert_eq!(country.iso_3_code, "USA".to_string());
+    /// }
+    /// ```
+    pub fn new(name: String, phone_code: String, iso_2_code: String, iso_3_code: String) -> Self {
+        Self {
+            name,
+            phone_code,
+            iso_2_code,
+            iso_3_code,
+            numeric_code: None,
+            postal_code_pattern: None,
+            phone_number_length: None,
+            currency: None,
+            languages: Vec::new(),
+            subdivisions: Vec::new(),
+            timezones: Vec::new(),
+            region_timezones: BTreeMap::new(),
+            names: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the time zone, (IANA identifier) of the region, (a subdivision code or name, see `get_subdivision()`).
+    /// In countries where the time differs by region, (e.g.: USA or Australia) the region must be known,
+    /// otherwise None is returned rather than guessing. The other countries use their principal time zone.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let australia = countries.get_country_by_iso_3_code("AUS".to_string()).unwrap();
+    /// let france = countries.get_country_by_iso_3_code("FRA".to_string()).unwrap();
+    ///
+    /// assert_eq!(australia.get_timezone(Some("NSW")), Some("Australia/Sydney"));
+    /// assert_eq!(australia.get_timezone(Some("Western Australia")), Some("Australia/Perth"));
+    /// assert_eq!(australia.get_timezone(None), None);
+    /// assert_eq!(france.get_timezone(None), Some("Europe/Paris"));
+    /// ```
+    pub fn get_timezone(&self, region: Option<&str>) -> Option<&str> {
+        if self.region_timezones.is_empty() {
+            return self.timezones.first().map(|t| t.as_str());
+        }
+        let subdivision = self.get_subdivision(region?)?;
+        self.region_timezones
+            .get(&subdivision.code)
+            .map(|t| t.as_str())
+    }
+
+    /// Returns the name of the country in the language (ISO 639-1), (e.g.: `de` or `zh-CN`).
+    /// Falls back to the English name when there is no translation for the language.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let country = countries.get_country_by_iso_3_code("DEU".to_string()).unwrap();
+    ///
+    /// assert_eq!(country.name_in("de"), "Deutschland");
+    /// assert_eq!(country.name_in("fr-CA"), "Allemagne");
+    /// assert_eq!(country.name_in("zh"), "德国");
+    /// assert_eq!(country.name_in("en"), "Germany");
+    /// ```
+    pub fn name_in(&self, language: &str) -> &str {
+        let language = language
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match self.names.get(&language) {
+            Some(name) => name,
+            None => &self.name,
+        }
+    }
+
+    /// Returns the international calling code of the country, (e.g.: "1" for "1-684").
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let country = countries.get_country_by_iso_3_code("ASM".to_string()).unwrap();
+    ///
+    /// assert_eq!(country.calling_code(), "1".to_string());
+    /// ```
+    pub fn calling_code(&self) -> String {
+        self.phone_code
+            .split([',', '-'])
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+    }
+
+    /// Returns the digits that international phone numbers of the country start with,
+    /// (e.g.: ["1809", "1829", "1849"] for "1-809, 1-829, 1-849").
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let country = countries.get_country_by_iso_3_code("PRI".to_string()).unwrap();
+    ///
+    /// assert_eq!(country.dialing_prefixes(), vec!["1787".to_string(), "1939".to_string()]);
+    /// ```
+    pub fn dialing_prefixes(&self) -> Vec<String> {
+        self.phone_code
+            .split(',')
+            .map(|code| code.chars().filter(|c| c.is_ascii_digit()).collect())
+            .collect()
+    }
+
+    /// Verifies the postal code against the pattern of the country, (ignoring case).
+    /// Countries without a known pattern accept any postal code.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let country = countries.get_country_by_iso_3_code("CAN".to_string()).unwrap();
+    ///
+    /// assert_eq!(country.is_valid_postal_code("K1A 0B1"), true);
+    /// assert_eq!(country.is_valid_postal_code("12345"), false);
+    /// ```
+    pub fn is_valid_postal_code(&self, postal_code: &str) -> bool {
+        match self.postal_code_regex() {
+            Ok(Some(re)) => re.is_match(&postal_code.trim().to_uppercase()),
+            Ok(None) => true,
+            Err(_) => false,
+        }
+    }
+
+    /// Verifies that the postal code pattern of the country is a valid regular expression.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut country = Country::new("Atlantis".to_string(), "999".to_string(), "XA".to_string(), "XAT".to_string());
+    /// assert!(country.validate_postal_code_pattern().is_ok());
+    ///
+    /// country.postal_code_pattern = Some("[0-9".to_string());
+    /// assert!(country.validate_postal_code_pattern().is_err());
+    /// assert_eq!(country.is_valid_postal_code("12345"), false);
+    /// ```
+    pub fn validate_postal_code_pattern(&self) -> Result<(), ScaffoldingError> {
+        self.postal_code_regex().map(|_| ())
+    }
+
+    // compiles the postal code pattern the first time it is used, (None if the country has no pattern)
+    fn postal_code_regex(&self) -> Result<Option<Regex>, ScaffoldingError> {
+        let pattern = match &self.postal_code_pattern {
+            Some(pattern) => pattern,
+            None => return Ok(None),
+        };
+        let mut patterns = POSTAL_CODE_PATTERNS
+            .get_or_init(|| Mutex::new(BTreeMap::new()))
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        if let Some(re) = patterns.get(pattern) {
+            return Ok(Some(re.clone()));
+        }
+        match Regex::new(&format!("^(?:{})$", pattern)) {
+            Ok(re) => {
+                patterns.insert(pattern.clone(), re.clone());
+                Ok(Some(re))
+            }
+            Err(err) => Err(ScaffoldingError::PostalCodePattern {
+                iso_3_code: self.iso_3_code.clone(),
+                source: err,
+            }),
+        }
+    }
+
+    /// Retrieves the subdivision of the country by its code, (e.g.: `US-NY` or `NY`) or its name, (ignoring case).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let country = countries.get_country_by_iso_3_code("USA".to_string()).unwrap();
+    ///
+    /// assert_eq!(country.get_subdivision("ny").unwrap().name, "New York");
+    /// assert_eq!(country.get_subdivision("New York").unwrap().code, "US-NY");
+    /// assert!(country.get_subdivision("Bayern").is_none());
+    /// ```
+    pub fn get_subdivision(&self, value: &str) -> Option<&Subdivision> {
+        let value = value.trim().to_lowercase();
+        self.subdivisions.iter().find(|s| {
+            s.code.to_lowercase() == value
+                || s.abbreviation().to_lowercase() == value
+                || s.name.to_lowercase() == value
+        })
+    }
+
+    /// Verifies the state, province, etc. against the subdivisions of the country, (see `get_subdivision()`).
+    /// Countries without subdivisions, (e.g.: Aruba) have nothing to verify against, so no value is valid.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let country = countries.get_country_by_iso_3_code("DEU".to_string()).unwrap();
+    ///
+    /// assert_eq!(country.is_valid_subdivision("Bayern"), true);
+    /// assert_eq!(country.is_valid_subdivision("NY"), false);
+    /// ```
+    pub fn is_valid_subdivision(&self, value: &str) -> bool {
+        self.get_subdivision(value).is_some()
+    }
+}
+
+/// The currency of a country
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Currency {
+    // The ISO 4217 code, (e.g.: EUR)
+    pub code: String,
+    // The name of the currency
+    pub name: String,
+    // The symbol that is used locally, (e.g.: €)
+    pub symbol: String,
+}
+
+impl Currency {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let currency = Currency::new("EUR".to_string(), "Euro".to_string(), "€".to_string());
+    ///
+    /// assert_eq!(currency.code, "EUR");
+    /// ```
+    pub fn new(code: String, name: String, symbol: String) -> Self {
+        Self { code, name, symbol }
+    }
+}
+
+/// A pair of entities that are likely duplicates of each other
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DuplicateCandidate {
+    // The unique identifier of the first entity
+    pub left_id: String,
+    // The unique identifier of the second entity
+    pub right_id: String,
+    // The confidence that the entities are duplicates, (0.0 - 1.0)
+    pub score: f64,
+}
+
+impl DuplicateCandidate {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let candidate = DuplicateCandidate::new("a".to_string(), "b".to_string(), 0.75);
+    ///
+    /// assert_eq!(candidate.score, 0.75);
+    /// ```
+    pub fn new(left_id: String, right_id: String, score: f64) -> Self {
+        Self {
+            left_id,
+            right_id,
+            score,
+        }
+    }
+
+    /// This function calculates the similarity (Jaccard index) of two lists of normalized values.
+    /// Returns None if either list is empty, (nothing to compare).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let left = vec!["a@example.com".to_string(), "b@example.com".to_string()];
+    /// let right = vec!["b@example.com".to_string()];
+    ///
+    /// assert_eq!(DuplicateCandidate::similarity(&left, &right), Some(0.5));
+    /// assert_eq!(DuplicateCandidate::similarity(&left, &Vec::new()), None);
+    /// ```
+    pub fn similarity(left: &[String], right: &[String]) -> Option<f64> {
+        if left.is_empty() || right.is_empty() {
+            return None;
+        }
+
+        let mut union: Vec<&String> = left.iter().chain(right.iter()).collect();
+        union.sort();
+        union.dedup();
+        let shared = union
+            .iter()
+            .filter(|v| left.contains(v) && right.contains(v))
+            .count();
+
+        Some(shared as f64 / union.len() as f64)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EmailAddress {
+    // The unique identifier of the note
+    pub id: String,
+    // The timestamp when the note was created
+    pub created_dtm: i64,
+    // The timestamp when the note was last modified
+    pub modified_dtm: i64,
+    // The type of email address, (e.g.: Login, Personal, Work, Primary Contact, Assistant, etc.)
+    pub category: Category,
+    // The email address
+    pub address: String,
+    // Whether this is the primary email address of its category, (see `set_primary_email_address()`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub primary: bool,
+    // The result of the last deliverability check, (see `verify_deliverability()`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deliverability: Option<EmailDeliverability>,
+}
+
+impl EmailAddress {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///   let email = EmailAddress::new(
+    ///       "home".to_string(),
+    ///       "myemail@example.com".to_string(),
+    ///   );
+    ///   
+    ///   // scaffolding attributes
+    ///   println!("{}", email.id);
+    ///   println!("{}", email.created_dtm);
+    ///   println!("{}", email.modified_dtm,);
+    /// }
+    /// ```
+    pub fn new(category: impl Into<Category>, address: String) -> Self {
+        Self {
+            id: defaults::id(),
+            created_dtm: defaults::now(),
+            modified_dtm: defaults::now(),
+            category: category.into(),
+            address,
+            primary: false,
+            deliverability: None,
+        }
+    }
+
+    /// This function instantiates a EmailAddress from a JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let serialized = r#"{
+    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
+    ///   "created_dtm":1711833619,
+    ///   "modified_dtm":1711833619,
+    ///   "category":"home",
+    ///   "address":"myemail@example.com"
+    /// }"#;
+    /// let mut email = EmailAddress::deserialized(&serialized.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(email.created_dtm, 1711833619);
+    /// assert_eq!(email.modified_dtm, 1711833619);
+    /// assert_eq!(email.category, "home".to_string());
+    /// ```
+    pub fn deserialized(serialized: &[u8]) -> Result<EmailAddress, ScaffoldingError> {
+        match serde_json::from_slice(serialized) {
+            Ok(item) => Ok(item),
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "EmailAddress".to_string(),
+            }),
+        }
+    }
+
+    /// This function turns the EmailAddress into a tombstone by replacing the address with the redaction marker.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut email = EmailAddress::new(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    /// email.erase();
+    ///
+    /// assert_eq!(email.address, defaults::redacted());
+    /// ```
+    pub fn erase(&mut self) {
+        self.address = defaults::redacted();
+        self.modified_dtm = defaults::now();
+    }
+
+    /// This function masks the email address using the specified masking style.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut email = EmailAddress::new(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    /// email.mask(&MaskStyle::Partial);
+    ///
+    /// assert_eq!(email.address, "m****@example.com".to_string());
+    /// ```
+    pub fn mask(&mut self, style: &MaskStyle) {
+        self.address = style.mask_email(&self.address);
+    }
+
+    /// This function returns a normalized form of the email address that is used for fuzzy comparisons.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let email = EmailAddress::new(
+    ///     "home".to_string(),
+    ///     " MyEmail@Example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(email.normalized(), "myemail@example.com".to_string());
+    /// ```
+    pub fn normalized(&self) -> String {
+        self.address.trim().to_lowercase()
+    }
+
+    /// This function returns the normalized form of the email address that also ignores the aliases of the
+    /// providers that deliver them to the same mailbox, (the dots and `+tag` of gmail addresses).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let email = EmailAddress::new(
+    ///     "home".to_string(),
+    ///     "My.Email+Newsletters@GoogleMail.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(email.canonical(), "myemail@gmail.com".to_string());
+    /// ```
+    pub fn canonical(&self) -> String {
+        let normalized = self.normalized();
+        match normalized.rsplit_once('@') {
+            Some((local, "gmail.com" | "googlemail.com")) => {
+                let local = local.split('+').next().unwrap_or_default();
+                format!("{}@gmail.com", local.replace('.', ""))
+            }
+            _ => normalized,
+        }
+    }
+
+    /// This function returns the domain of the email address, (lowercase).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let email = EmailAddress::new(
+    ///     "work".to_string(),
+    ///     "myemail@Example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(email.domain(), Some("example.com".to_string()));
+    /// ```
+    pub fn domain(&self) -> Option<String> {
+        self.normalized()
+            .rsplit_once('@')
+            .map(|(_, domain)| domain.to_string())
+            .filter(|domain| !domain.is_empty())
+    }
+
+    /// This function determines if the email address belongs to a disposable (throwaway) email provider,
+    /// (bundled in `email_domains.json`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let email = EmailAddress::new(
+    ///     "login".to_string(),
+    ///     "someone@mailinator.com".to_string(),
+    /// );
+    ///
+    /// assert!(email.is_disposable());
+    /// ```
+    pub fn is_disposable(&self) -> bool {
+        email_domain_listed("disposable", self.domain())
+    }
+
+    /// This function determines if the email address belongs to a free email provider, (e.g.: gmail.com),
+    /// (bundled in `email_domains.json`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let email = EmailAddress::new(
+    ///     "home".to_string(),
+    ///     "someone@gmail.com".to_string(),
+    /// );
+    ///
+    /// assert!(email.is_free_provider());
+    /// assert!(!email.is_disposable());
+    /// ```
+    pub fn is_free_provider(&self) -> bool {
+        email_domain_listed("free", self.domain())
+    }
+
+    /// This function performs a quick check to see if the email address is properly formatted.
+    /// NOTE: This is not a validation that the email address is real.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let email = EmailAddress::new(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(email.is_valid(), true);
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        Self::is_valid_address(&self.address)
+    }
+
+    /// This function performs the same check as `is_valid()` on an email address that is not an EmailAddress object.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert!(EmailAddress::is_valid_address("myemail@example.com"));
+    /// assert!(!EmailAddress::is_valid_address("myemail"));
+    /// ```
+    pub fn is_valid_address(address: &str) -> bool {
+        // use regex::Regex;
+        let exp = r#"(?:[a-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*|"(?:[\x01-\x08\x0b\x0c\x0e-\x1f\x21\x23-\x5b\x5d-\x7f]|\\[\x01-\x09\x0b\x0c\x0e-\x7f])*")@(?:(?:[a-z0-9](?:[a-z0-9-]*[a-z0-9])?\.)+[a-z0-9](?:[a-z0-9-]*[a-z0-9])?|\[(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?|[a-z0-9-]*[a-z0-9]:(?:[\x01-\x08\x0b\x0c\x0e-\x1f\x21-\x5a\x53-\x7f]|\\[\x01-\x09\x0b\x0c\x0e-\x7f])+)\])"#;
+        let re = Regex::new(exp).unwrap();
+        re.is_match(address)
+    }
+    /// This function converts the EmailAddress to a serialize JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let email = EmailAddress::new(
+    ///       "home".to_string(),
+    ///       "myemail@example.com".to_string(),
+    /// );
+    /// println!("{}", email.serialize());
+    /// ```
+    pub fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+
+    /// This function updates the email address and sets the modified_dtm.
+    /// The modified_dtm will not be changed if the attributes are written to directly.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let serialized = r#"{
+    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
+    ///   "created_dtm":1711833619,
+    ///   "modified_dtm":1711833619,
+    ///   "category":"home",
+    ///   "address":"myemail@example.com"
+    /// }"#;
+    /// let mut email = EmailAddress::deserialized(&serialized.as_bytes()).unwrap();
+    ///
+    /// email.update("work".to_string(), "me@acme.com".to_string());
+    ///
+    /// assert_eq!(email.category, "work".to_string());
+    /// assert_eq!(email.address, "me@acme.com".to_string());
+    /// assert!(email.modified_dtm > 1711833619);
+    /// ```
+    pub fn update(&mut self, category: impl Into<Category>, address: String) {
+        let category = category.into();
+        // the primary designation is per category
+        if self.category != category {
+            self.primary = false;
+        }
+        // the deliverability belongs to the previous address
+        if self.normalized() != address.trim().to_lowercase() {
+            self.deliverability = None;
+        }
+        self.category = category;
+        self.address = address;
+        self.modified_dtm = defaults::now();
+    }
+
+    /// This function checks if mail can be delivered to the email address by looking up the mail servers (MX)
+    /// of the domain, (or the address of the domain itself when it has no MX records), and optionally probing the mailbox, (SMTP `RCPT TO` on port 25, which many networks block).
+    /// The result is stored in the `deliverability` of the EmailAddress, (the `verify_email_address()` of the entity
+    /// also logs it as activity). Addresses that are not valid, (see `is_valid()`), are rejected before any lookup.
+    /// Requires the `deliverability` feature and a tokio runtime.
+    ///
+    /// #Example
+    ///
+    /// ```rust,no_run
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// async fn sign_up() {
+    ///     let mut email = EmailAddress::new(
+    ///         "login".to_string(),
+    ///         "myemail@example.com".to_string(),
+    ///     );
+    ///     let result = email.verify_deliverability(false).await.unwrap();
+    ///
+    ///     assert_eq!(email.deliverability, Some(result));
+    /// }
+    /// ```
+    #[cfg(feature = "deliverability")]
+    pub async fn verify_deliverability(
+        &mut self,
+        probe: bool,
+    ) -> Result<EmailDeliverability, ScaffoldingError> {
+        // the address is sent to the mail server, so line breaks could inject SMTP commands
+        if self.address.contains(['\r', '\n']) || !self.is_valid() {
+            return Err(ScaffoldingError::Verification);
+        }
+        let domain = self.domain().ok_or(ScaffoldingError::Verification)?;
+        let resolver = TokioResolver::builder_tokio()
+            .and_then(|builder| builder.build())
+            .map_err(|_| ScaffoldingError::Verification)?;
+        let mut servers: Vec<(u16, String)> = match resolver.mx_lookup(format!("{}.", domain)).await
+        {
+            Ok(lookup) => lookup
+                .answers()
+                .iter()
+                .filter_map(|record| match &record.data {
+                    // a null MX, (RFC 7505), has the root `.` as exchange and becomes an empty host
+                    RData::MX(mx) => Some((
+                        mx.preference,
+                        mx.exchange.to_utf8().trim_end_matches('.').to_string(),
+                    )),
+                    _ => None,
+                })
+                .collect(),
+            Err(err) if err.is_no_records_found() => Vec::new(),
+            Err(_) => return Err(ScaffoldingError::Verification),
+        };
+        servers.sort();
+
+        let mut mx_hosts: Vec<String> = servers.into_iter().map(|(_, host)| host).collect();
+        // without MX records the domain itself is the mail server if it has an address, (implicit MX, RFC 5321)
+        if mx_hosts.is_empty() {
+            match resolver.lookup_ip(format!("{}.", domain)).await {
+                Ok(lookup) if lookup.iter().next().is_some() => mx_hosts.push(domain),
+                Ok(_) => {}
+                Err(err) if err.is_no_records_found() => {}
+                Err(_) => return Err(ScaffoldingError::Verification),
+            }
+        }
+        let mailbox_exists = match (probe, mx_hosts.first()) {
+            (true, Some(host)) if !host.is_empty() => {
+                tokio::time::timeout(Duration::from_secs(30), smtp_probe(host, &self.address))
+                    .await
+                    .ok()
+                    .flatten()
+            }
+            _ => None,
+        };
+        let deliverability = EmailDeliverability::new(mx_hosts, mailbox_exists);
+
+        self.deliverability = Some(deliverability.clone());
+        self.modified_dtm = defaults::now();
+        Ok(deliverability)
+    }
+}
+
+// asks the mail server if it accepts mail for the address, (None if the server doesn't give a definite answer)
+#[cfg(feature = "deliverability")]
+async fn smtp_probe(host: &str, address: &str) -> Option<bool> {
+    if address.contains(['\r', '\n']) {
+        return None;
+    }
+    let stream = TcpStream::connect((host, 25)).await.ok()?;
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    if smtp_reply(&mut reader).await? != 220 {
+        return None;
+    }
+    for command in ["EHLO localhost", "MAIL FROM:<>"] {
+        writer
+            .write_all(format!("{}\r\n", command).as_bytes())
+            .await
+            .ok()?;
+        if smtp_reply(&mut reader).await? != 250 {
+            return None;
+        }
+    }
+    writer
+        .write_all(format!("RCPT TO:<{}>\r\n", address.trim()).as_bytes())
+        .await
+        .ok()?;
+    let code = smtp_reply(&mut reader).await?;
+    let _ = writer.write_all(b"QUIT\r\n").await;
+
+    match code {
+        250 | 251 => Some(true),
+        550 | 551 | 553 => Some(false),
+        _ => None,
+    }
+}
+
+// reads the (multiline) reply of the mail server and returns the status code
+#[cfg(feature = "deliverability")]
+async fn smtp_reply(reader: &mut BufReader<OwnedReadHalf>) -> Option<u16> {
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await.ok()? == 0 {
+            return None;
+        }
+        // continuation lines have a `-` after the code, (e.g.: 250-SIZE)
+        if line.as_bytes().get(3) != Some(&b'-') {
+            return line.get(..3)?.parse().ok();
+        }
+    }
+}
+
+/// The result of checking if mail can be delivered to an EmailAddress
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EmailDeliverability {
+    // Whether mail can be delivered, (the domain has mail servers and the mailbox wasn't rejected)
+    pub deliverable: bool,
+    // The mail servers of the domain, (ordered by preference)
+    pub mx_hosts: Vec<String>,
+    // Whether the mail server accepts the mailbox, (None if it wasn't probed or gave no definite answer)
+    pub mailbox_exists: Option<bool>,
+    // The timestamp when the email address was verified
+    pub verified_dtm: i64,
+}
+
+impl EmailDeliverability {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let result = EmailDeliverability::new(vec!["mx.example.com".to_string()], Some(false));
+    ///
+    /// assert!(!result.deliverable);
+    /// assert!(!EmailDeliverability::new(Vec::new(), None).deliverable);
+    /// assert!(EmailDeliverability::new(vec!["mx.example.com".to_string()], None).deliverable);
+    ///
+    /// // a null MX, (RFC 7505), means the domain doesn't accept mail
+    /// assert!(!EmailDeliverability::new(vec![".".to_string()], None).deliverable);
+    /// ```
+    pub fn new(mx_hosts: Vec<String>, mailbox_exists: Option<bool>) -> Self {
+        let null_mx = mx_hosts
+            .iter()
+            .any(|host| host.trim_end_matches('.').is_empty());
+
+        Self {
+            deliverable: !mx_hosts.is_empty() && !null_mx && mailbox_exists != Some(false),
+            mx_hosts,
+            mailbox_exists,
+            verified_dtm: defaults::now(),
+        }
+    }
+}
+
+/// The JSON body of a request or response that is a Scaffolding entity. Requires the `axum` feature.
+///
+/// As an extractor the entity is deserialized and validated, (see `ScaffoldingValidate`), and the request is rejected with
+/// `415 Unsupported Media Type` when the body is not JSON, `400 Bad Request` or `422 Unprocessable Entity` when the body
+/// can't be deserialized, (see `ScaffoldingError`), and `422 Unprocessable Entity` with the `ValidationReport` when the entity is not valid.
+/// As a response the entity is returned as JSON.
+///
+/// #Example
+///
+/// ```rust
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+/// use axum::{routing::post, Router};
+///
+/// #[scaffolding_struct]
+/// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingValidate)]
+/// struct Customer {
+///     #[validate(length(min = 1))]
+///     name: String,
+/// }
+///
+/// async fn create(Entity(customer): Entity<Customer>) -> Entity<Customer> {
+///     Entity(customer)
+/// }
+///
+/// let app: Router = Router::new().route("/customers", post(create));
+/// ```
+#[cfg(feature = "axum")]
+#[derive(Clone, Debug)]
+pub struct Entity<T>(pub T);
+
+#[cfg(feature = "axum")]
+impl<S, T> FromRequest<S> for Entity<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + ScaffoldingValidate,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let json = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .is_some_and(|c| c.starts_with("application/") && c.contains("json"));
+        if !json {
+            return Err(StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response());
+        }
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|err| err.into_response())?;
+        let entity = match serde_json::from_slice::<T>(&body) {
+            Ok(entity) => entity,
+            Err(err) => {
+                return Err(ScaffoldingError::Deserialize {
+                    source: err,
+                    type_name: std::any::type_name::<T>().to_string(),
+                }
+                .into_response())
+            }
+        };
+        match entity.validate() {
+            Ok(()) => Ok(Entity(entity)),
+            Err(report) => Err(report.into_response()),
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+impl<T> IntoResponse for Entity<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response {
+        Json(self.0).into_response()
+    }
+}
+
+/// A collection of Scaffolding entities of the same type
+#[derive(Clone, Debug)]
+pub struct EntityCollection<T> {
+    // The list of entities
+    pub list: Vec<T>,
+}
+
+impl<T> EntityCollection<T> {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new());
+    ///
+    /// assert_eq!(collection.list.len(), 1);
+    /// ```
+    pub fn new() -> Self {
+        Self { list: Vec::new() }
+    }
+
+    /// Applies the retention policies to every entity in the collection and removes the entities that are expired.
+    /// Returns a report of what was removed.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingNotes, ScaffoldingRetention)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut expired = MyEntity::new();
+    /// expired.expired_dtm = defaults::add_days(defaults::now(), -1);
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new());
+    /// collection.list.push(expired.clone());
+    ///
+    /// let report = collection.apply_retention(&[RetentionPolicy::RemoveOlderThan {
+    ///     attribute: "notes".to_string(),
+    ///     days: 730,
+    /// }]);
+    ///
+    /// assert_eq!(collection.list.len(), 1);
+    /// assert_eq!(report.entities, vec![expired.id]);
+    /// ```
+    pub fn apply_retention(&mut self, policies: &[RetentionPolicy]) -> RetentionReport
+    where
+        T: ScaffoldingRetention,
+    {
+        let mut report = RetentionReport::new();
+
+        self.list.retain_mut(|entity| {
+            let entity_report = entity.apply_retention(policies);
+            let expired = !entity_report.entities.is_empty();
+            report.merge(entity_report);
+            !expired
+        });
+
+        report
+    }
+
+    /// Retrieves all the entities that belong to the specified tenant.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tenant")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTenant)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tenant")]
+    ///     fn new(tenant: &str) -> Self {
+    ///         Self {
+    ///             tenant_id: tenant.to_string(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new("acme"));
+    /// collection.list.push(MyEntity::new("globex"));
+    /// collection.list.push(MyEntity::new("acme"));
+    ///
+    /// assert_eq!(collection.search_by_tenant("acme".to_string()).len(), 2);
+    /// ```
+    pub fn search_by_tenant(&self, tenant_id: String) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingTenant + Clone,
+    {
+        self.list
+            .iter()
+            .filter(|e| !e.is_deleted() && e.belongs_to_tenant(tenant_id.clone()))
+            .cloned()
+            .collect()
+    }
+
+    /// Retrieves all the entities that have metadata with a key that matches the pattern and a value that contains the text,
+    /// (see `ScaffoldingMetadata::search_metadata()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata")]
+    ///     fn new(source: &str) -> Self {
+    ///         let mut entity = Self {};
+    ///         entity.upsert_metadata("source".to_string(), source.to_string());
+    ///         entity
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new("import-2024"));
+    /// collection.list.push(MyEntity::new("import-2025"));
+    /// collection.list.push(MyEntity::new("manual"));
+    ///
+    /// assert_eq!(collection.search_metadata("source".to_string(), "import-2024".to_string()).len(), 1);
+    /// assert_eq!(collection.search_metadata("sour*".to_string(), "import".to_string()).len(), 2);
+    /// ```
+    pub fn search_metadata(&self, key_glob: String, value_contains: String) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingMetadata + Clone,
+    {
+        self.list
+            .iter()
+            .filter(|e| {
+                !e.is_deleted()
+                    && !e
+                        .search_metadata(key_glob.clone(), value_contains.clone())
+                        .is_empty()
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Retrieves all the entities that have the key-value tag key, (with any value or none) or the key with the value.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new(priority: &str) -> Self {
+    ///         let mut entity = Self {};
+    ///         entity.add_tag_value("priority".to_string(), priority.to_string());
+    ///         entity
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new("high"));
+    /// collection.list.push(MyEntity::new("low"));
+    ///
+    /// assert_eq!(collection.search_by_tag_value("priority".to_string(), None).len(), 2);
+    /// assert_eq!(collection.search_by_tag_value("priority".to_string(), Some("high".to_string())).len(), 1);
+    /// ```
+    pub fn search_by_tag_value(&self, key: String, value: Option<String>) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingTags + Clone,
+    {
+        self.list
+            .iter()
+            .filter(|e| !e.is_deleted() && e.has_tag_value(key.clone(), value.clone()))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the distinct tags of the entities that start with the prefix, (e.g.: for autocomplete).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new(tag: &str) -> Self {
+    ///         let mut entity = Self {};
+    ///         entity.add_tag(tag.to_string());
+    ///         entity
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new("region:emea"));
+    /// collection.list.push(MyEntity::new("region:apac"));
+    /// collection.list.push(MyEntity::new("region:emea"));
+    ///
+    /// assert_eq!(collection.search_tags("region:e".to_string()), vec!["region:emea".to_string()]);
+    /// ```
+    pub fn search_tags(&self, prefix: String) -> Vec<String>
+    where
+        T: Scaffolding + ScaffoldingTags,
+    {
+        TagPolicy::default().search(&self.distinct_tags(), &prefix)
+    }
+
+    /// Returns the distinct tags of the entities that resemble the partial tag, the best suggestions first,
+    /// (see `TagPolicy::suggest()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new(tag: &str) -> Self {
+    ///         let mut entity = Self {};
+    ///         entity.add_tag(tag.to_string());
+    ///         entity
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new("region:emea"));
+    /// collection.list.push(MyEntity::new("region:apac"));
+    /// collection.list.push(MyEntity::new("region:emea"));
+    ///
+    /// assert_eq!(collection.suggest_tags("apac".to_string()), vec!["region:apac".to_string()]);
+    /// ```
+    pub fn suggest_tags(&self, partial: String) -> Vec<String>
+    where
+        T: Scaffolding + ScaffoldingTags,
+    {
+        TagPolicy::default().suggest(&self.distinct_tags(), &partial)
+    }
+
+    /// Returns the number of entities that have each tag, (keyed by the normalized tag).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new(tags: &[&str]) -> Self {
+    ///         let mut entity = Self {};
+    ///         for tag in tags {
+    ///             entity.add_tag(tag.to_string());
+    ///         }
+    ///         entity
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new(&["vip", "customer"]));
+    /// collection.list.push(MyEntity::new(&["customer"]));
+    ///
+    /// let counts = collection.tag_counts();
+    ///
+    /// assert_eq!(counts.get("customer"), Some(&2));
+    /// assert_eq!(counts.get("vip"), Some(&1));
+    /// ```
+    pub fn tag_counts(&self) -> BTreeMap<String, usize>
+    where
+        T: Scaffolding + ScaffoldingTags,
+    {
+        let policy = TagPolicy::default();
+        let mut counts = BTreeMap::new();
+        for entity in self.list.iter().filter(|e| !e.is_deleted()) {
+            // an entity is only counted once for each tag
+            let tags: BTreeSet<String> = entity
+                .search_tags(String::new())
+                .iter()
+                .map(|t| policy.normalize(t))
+                .collect();
+            for tag in tags {
+                *counts.entry(tag).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Retrieves all the entities that have the tag.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new(tags: &[&str]) -> Self {
+    ///         let mut entity = Self {};
+    ///         for tag in tags {
+    ///             entity.add_tag(tag.to_string());
+    ///         }
+    ///         entity
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new(&["vip", "customer"]));
+    /// collection.list.push(MyEntity::new(&["customer"]));
+    ///
+    /// assert_eq!(collection.entities_with_tag("customer".to_string()).len(), 2);
+    /// ```
+    pub fn entities_with_tag(&self, tag: String) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingTags + Clone,
+    {
+        self.entities_with_all_tags(&[tag])
+    }
+
+    /// Retrieves all the entities that have every one of the tags.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new(tags: &[&str]) -> Self {
+    ///         let mut entity = Self {};
+    ///         for tag in tags {
+    ///             entity.add_tag(tag.to_string());
+    ///         }
+    ///         entity
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new(&["vip", "customer"]));
+    /// collection.list.push(MyEntity::new(&["customer"]));
+    ///
+    /// assert_eq!(collection.entities_with_all_tags(&["customer".to_string(), "vip".to_string()]).len(), 1);
+    /// ```
+    pub fn entities_with_all_tags(&self, tags: &[String]) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingTags + Clone,
+    {
+        self.list
+            .iter()
+            .filter(|e| !e.is_deleted() && tags.iter().all(|t| e.has_tag(t.clone())))
+            .cloned()
+            .collect()
+    }
+
+    // lists the tags of the entities that are not deleted once, (sorted by their normalized tag)
+    fn distinct_tags(&self) -> Vec<String>
+    where
+        T: Scaffolding + ScaffoldingTags,
+    {
+        let policy = TagPolicy::default();
+        let mut tags = BTreeMap::new();
+        for entity in self.list.iter().filter(|e| !e.is_deleted()) {
+            for tag in entity.search_tags(String::new()) {
+                tags.entry(policy.normalize(&tag)).or_insert(tag);
+            }
+        }
+        tags.into_values().collect()
+    }
+
+    /// Retrieves all the entities that have been soft deleted, (e.g.: for audits).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("soft_delete")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingSoftDelete)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("soft_delete")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut deleted = MyEntity::new();
+    /// deleted.delete();
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new());
+    /// collection.list.push(deleted);
+    ///
+    /// assert_eq!(collection.search_deleted().len(), 1);
+    /// ```
+    pub fn search_deleted(&self) -> Vec<T>
+    where
+        T: Scaffolding + Clone,
+    {
+        self.list
+            .iter()
+            .filter(|e| e.is_deleted())
+            .cloned()
+            .collect()
+    }
+
+    /// Retrieves all the entities that have not been soft deleted.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("soft_delete")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingSoftDelete)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("soft_delete")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut deleted = MyEntity::new();
+    /// deleted.delete();
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(MyEntity::new());
+    /// collection.list.push(deleted);
+    ///
+    /// assert_eq!(collection.search_not_deleted().len(), 1);
+    /// ```
+    pub fn search_not_deleted(&self) -> Vec<T>
+    where
+        T: Scaffolding + Clone,
+    {
+        self.list
+            .iter()
+            .filter(|e| !e.is_deleted())
+            .cloned()
+            .collect()
+    }
+
+    /// Retrieves the entities in the collection that the entity is directly related to by the kind of relationship.
+    /// Soft deleted entities are excluded.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let ceo = MyEntity::new();
+    /// let mut vp = MyEntity::new();
+    /// vp.insert_relationship("parent".to_string(), ceo.id.clone());
+    /// let mut manager = MyEntity::new();
+    /// manager.insert_relationship("parent".to_string(), vp.id.clone());
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(ceo.clone());
+    /// collection.list.push(vp.clone());
+    /// collection.list.push(manager.clone());
+    ///
+    /// let related = collection.related(&manager, "parent".to_string());
+    ///
+    /// assert_eq!(related.len(), 1);
+    /// assert_eq!(related[0].id, vp.id);
+    /// ```
+    pub fn related(&self, entity: &T, kind: String) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingRelationships + Clone,
+    {
+        let targets: Vec<String> = entity
+            .search_relationships_by_kind(kind)
+            .iter()
+            .map(|r| r.target_id.clone())
+            .collect();
+
+        self.list
+            .iter()
+            .filter(|e| !e.is_deleted() && targets.contains(&e.get_id().to_string()))
+            .cloned()
+            .collect()
+    }
+
+    /// Retrieves all the ancestors of the entity by walking the kind of relationship, (e.g.: parent), upwards.
+    /// The nearest ancestor is first. Cycles are walked only once and soft deleted entities are excluded.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let ceo = MyEntity::new();
+    /// let mut vp = MyEntity::new();
+    /// vp.insert_relationship("parent".to_string(), ceo.id.clone());
+    /// let mut manager = MyEntity::new();
+    /// manager.insert_relationship("parent".to_string(), vp.id.clone());
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(ceo.clone());
+    /// collection.list.push(vp.clone());
+    /// collection.list.push(manager.clone());
+    ///
+    /// let ancestors = collection.ancestors(&manager, "parent".to_string());
+    ///
+    /// assert_eq!(ancestors.len(), 2);
+    /// assert_eq!(ancestors[0].id, vp.id);
+    /// assert_eq!(ancestors[1].id, ceo.id);
+    /// ```
+    pub fn ancestors(&self, entity: &T, kind: String) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingRelationships + Clone,
+    {
+        let mut visited: BTreeSet<String> = BTreeSet::from([entity.get_id().to_string()]);
+        let mut ancestors: Vec<T> = Vec::new();
+        let mut current = self.related(entity, kind.clone());
+
+        while !current.is_empty() {
+            let mut next = Vec::new();
+            for ancestor in current.into_iter() {
+                if !visited.insert(ancestor.get_id().to_string()) {
+                    continue;
+                }
+                next.append(&mut self.related(&ancestor, kind.clone()));
+                ancestors.push(ancestor);
+            }
+            current = next;
+        }
+
+        ancestors
+    }
+
+    /// Retrieves all the descendants of the entity by walking the kind of relationship, (e.g.: parent), downwards.
+    /// The nearest descendants are first. Cycles are walked only once and soft deleted entities are excluded.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let ceo = MyEntity::new();
+    /// let mut vp = MyEntity::new();
+    /// vp.insert_relationship("parent".to_string(), ceo.id.clone());
+    /// let mut manager = MyEntity::new();
+    /// manager.insert_relationship("parent".to_string(), vp.id.clone());
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(ceo.clone());
+    /// collection.list.push(vp.clone());
+    /// collection.list.push(manager.clone());
+    ///
+    /// let descendants = collection.descendants(&ceo, "parent".to_string());
+    ///
+    /// assert_eq!(descendants.len(), 2);
+    /// assert_eq!(descendants[0].id, vp.id);
+    /// assert_eq!(descendants[1].id, manager.id);
+    /// ```
+    pub fn descendants(&self, entity: &T, kind: String) -> Vec<T>
+    where
+        T: Scaffolding + ScaffoldingRelationships + Clone,
+    {
+        let mut visited: BTreeSet<String> = BTreeSet::from([entity.get_id().to_string()]);
+        let mut descendants: Vec<T> = Vec::new();
+        let mut current: BTreeSet<String> = BTreeSet::from([entity.get_id().to_string()]);
+
+        while !current.is_empty() {
+            let mut next = BTreeSet::new();
+            for e in self.list.iter() {
+                if e.is_deleted() || visited.contains(&e.get_id().to_string()) {
+                    continue;
+                }
+                let is_child = e
+                    .search_relationships_by_kind(kind.clone())
+                    .iter()
+                    .any(|r| current.contains(&r.target_id));
+                if is_child {
+                    visited.insert(e.get_id().to_string());
+                    next.insert(e.get_id().to_string());
+                    descendants.push(e.clone());
+                }
+            }
+            current = next;
+        }
+
+        descendants
+    }
+
+    /// Determines if the kind of relationship, (e.g.: parent), forms a cycle between the entities in the collection
+    /// that are not soft deleted.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let ceo = MyEntity::new();
+    /// let mut vp = MyEntity::new();
+    /// vp.insert_relationship("parent".to_string(), ceo.id.clone());
+    /// let mut manager = MyEntity::new();
+    /// manager.insert_relationship("parent".to_string(), vp.id.clone());
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// collection.list.push(ceo.clone());
+    /// collection.list.push(vp.clone());
+    /// collection.list.push(manager.clone());
+    ///
+    /// assert!(!collection.has_cycle("parent".to_string()));
+    ///
+    /// collection.list[0].insert_relationship("parent".to_string(), manager.id.clone());
+    /// assert!(collection.has_cycle("parent".to_string()));
+    /// ```
+    pub fn has_cycle(&self, kind: String) -> bool
+    where
+        T: Scaffolding + ScaffoldingRelationships + Clone,
+    {
+        // the ids that each entity is related to, (only the entities that are not deleted)
+        let edges: BTreeMap<String, Vec<String>> = self
+            .list
+            .iter()
+            .filter(|e| !e.is_deleted())
+            .map(|e| {
+                let targets = e
+                    .search_relationships_by_kind_ref(&kind)
+                    .iter()
+                    .map(|r| r.target_id.clone())
+                    .collect();
+                (e.get_id().to_string(), targets)
+            })
+            .collect();
+
+        // a depth first walk finds a cycle when it reaches an entity that is still on its path
+        let mut visited: BTreeSet<&String> = BTreeSet::new();
+        let mut on_path: BTreeSet<&String> = BTreeSet::new();
+        for (start, _) in edges.iter() {
+            if !visited.insert(start) {
+                continue;
+            }
+            on_path.insert(start);
+            // each step is the entity and the index of the next relationship to follow
+            let mut path = vec![(start, 0)];
+            while let Some((id, next)) = path.last_mut() {
+                match edges[*id].get(*next) {
+                    Some(target) => {
+                        *next += 1;
+                        if on_path.contains(target) {
+                            return true;
+                        }
+                        if let Some((target, _)) = edges.get_key_value(target) {
+                            if visited.insert(target) {
+                                on_path.insert(target);
+                                path.push((target, 0));
+                            }
+                        }
+                    }
+                    None => {
+                        on_path.remove(*id);
+                        path.pop();
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Finds the pairs of entities that are likely duplicates based on their normalized contact data.
+    /// Only the pairs with a confidence score of at least the threshold are returned, highest score first.
+    /// Soft deleted entities are excluded.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingDuplicates, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut collection = EntityCollection::new();
+    /// for email in ["myemail@example.com", "other@example.com", "MyEmail@Example.com"] {
+    ///     let mut entity = MyEntity::new();
+    ///     entity.insert_email_address("home".to_string(), email.to_string());
+    ///     collection.list.push(entity);
+    /// }
+    ///
+    /// let duplicates = collection.find_duplicates(0.8);
+    ///
+    /// assert_eq!(duplicates.len(), 1);
+    /// assert_eq!(duplicates[0].left_id, collection.list[0].id);
+    /// assert_eq!(duplicates[0].right_id, collection.list[2].id);
+    /// assert_eq!(duplicates[0].score, 1.0);
+    /// ```
+    pub fn find_duplicates(&self, threshold: f64) -> Vec<DuplicateCandidate>
+    where
+        T: Scaffolding + ScaffoldingDuplicates,
+    {
+        let mut candidates = Vec::new();
+
+        let entities: Vec<&T> = self.list.iter().filter(|e| !e.is_deleted()).collect();
+        for (i, left) in entities.iter().enumerate() {
+            for right in entities.iter().skip(i + 1) {
+                let score = left.duplicate_score(right);
+                if score > 0.0 && score >= threshold {
+                    candidates.push(DuplicateCandidate::new(
+                        left.get_id().to_string(),
+                        right.get_id().to_string(),
+                        score,
+                    ));
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates
+    }
+}
+
+impl<T> Default for EntityCollection<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The registry of the kinds of entities, (type tag and concrete type),
+/// that is used to load AnyEntity objects from their serialized form.
+#[derive(Clone, Debug, Default)]
+pub struct EntityRegistry {
+    // The functions that deserialize the entity of each kind
+    kinds: BTreeMap<String, DeserializeAny>,
+}
+
+// deserializes the entity of the kind, (registered using `EntityRegistry::register()`)
+type DeserializeAny = fn(&str, Value) -> Result<AnyEntity, ScaffoldingError>;
+
+impl EntityRegistry {
+    /// This is the constructor function.
+    pub fn new() -> Self {
+        Self {
+            kinds: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the concrete type of the kind of entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    /// struct Customer {}
+    ///
+    /// let mut registry = EntityRegistry::new();
+    /// registry.register::<Customer>("customer");
+    ///
+    /// assert_eq!(registry.kinds(), vec!["customer".to_string()]);
+    /// ```
+    pub fn register<T>(&mut self, kind: &str)
+    where
+        T: Scaffolding<Id = String> + Serialize + DeserializeOwned + 'static,
+    {
+        self.kinds.insert(
+            kind.to_string(),
+            |kind, value| match serde_json::from_value::<T>(value) {
+                Ok(entity) => Ok(AnyEntity::new(kind, entity)),
+                Err(err) => Err(ScaffoldingError::Deserialize {
+                    source: err,
+                    type_name: std::any::type_name::<T>().to_string(),
+                }),
+            },
+        );
+    }
+
+    /// Returns the registered kinds of entities.
+    pub fn kinds(&self) -> Vec<String> {
+        self.kinds.keys().cloned().collect()
+    }
+
+    /// Instantiates an AnyEntity from a JSON string, (see `AnyEntity` serialization), using the concrete type of its kind.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    /// struct Customer {}
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    /// struct Order {}
+    ///
+    /// impl Order {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut registry = EntityRegistry::new();
+    /// registry.register::<Customer>("customer");
+    /// registry.register::<Order>("order");
+    ///
+    /// let mut entity = AnyEntity::new("order", Order::new());
+    /// let loaded = registry.deserialized(entity.serialize().as_bytes()).unwrap();
+    ///
+    /// assert_eq!(loaded.kind(), "order");
+    /// assert_eq!(loaded.downcast_ref::<Order>().unwrap().id, entity.get_id());
+    /// ```
+    pub fn deserialized(&self, serialized: &[u8]) -> Result<AnyEntity, ScaffoldingError> {
+        let invalid = |source| ScaffoldingError::Deserialize {
+            source,
+            type_name: "AnyEntity".to_string(),
+        };
+        let mut value = match serde_json::from_slice::<Value>(serialized) {
+            Ok(value) => value,
+            Err(err) => return Err(invalid(err)),
+        };
+        let kind = match value.get("kind").and_then(|k| k.as_str()) {
+            Some(kind) => kind.to_string(),
+            None => return Err(invalid(serde::de::Error::missing_field("kind"))),
+        };
+        match self.kinds.get(&kind) {
+            Some(deserialize) => deserialize(&kind, value["entity"].take()),
+            None => Err(invalid(serde::de::Error::custom(format!(
+                "unregistered kind `{}`",
+                kind
+            )))),
+        }
+    }
+}
+
+/// A service that looks up the location, (latitude, longitude) of an Address
+pub trait Geocoder {
+    /// Returns the latitude and longitude of the Address.
+    fn geocode(&self, address: &Address) -> Result<(f64, f64), ScaffoldingError>;
+}
+
+/// A Geocoder that uses the [Nominatim](https://nominatim.org) search API of OpenStreetMap.
+/// Requires the `geocoding` feature.
+#[cfg(feature = "geocoding")]
+pub struct NominatimGeocoder {
+    // The search endpoint, (e.g.: https://nominatim.openstreetmap.org/search)
+    pub url: String,
+    // The User-Agent that identifies the application, (required by the usage policy)
+    pub user_agent: String,
+}
+
+#[cfg(feature = "geocoding")]
+impl NominatimGeocoder {
+    /// This is the constructor function that uses the public OpenStreetMap endpoint.
+    ///
+    /// #Example
+    ///
+    /// ```rust,no_run
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let geocoder = NominatimGeocoder::new("my-app/1.0".to_string());
+    /// let mut address = Address::new(
+    ///     "office".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "10 Downing Street".to_string(),
+    ///     "London, SW1A 2AA".to_string(),
+    ///     "United Kingdom".to_string(),
+    ///     "GBR".to_string(),
+    /// );
+    /// address.geocode(&geocoder).unwrap();
+    /// ```
+    pub fn new(user_agent: String) -> Self {
+        Self {
+            url: "https://nominatim.openstreetmap.org/search".to_string(),
+            user_agent,
+        }
+    }
+}
+
+#[cfg(feature = "geocoding")]
+impl Geocoder for NominatimGeocoder {
+    fn geocode(&self, address: &Address) -> Result<(f64, f64), ScaffoldingError> {
+        let query = [&address.line_2, &address.line_3, &address.line_4]
+            .iter()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ");
+        let response = Client::new()
+            .get(&self.url)
+            .header("User-Agent", &self.user_agent)
+            .query(&[("q", query.as_str()), ("format", "json"), ("limit", "1")])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .map_err(|_| ScaffoldingError::Geocode)?;
+        let places: Value = response.json().map_err(|_| ScaffoldingError::Geocode)?;
+        let coordinate = |key: &str| -> Result<f64, ScaffoldingError> {
+            places[0][key]
+                .as_str()
+                .and_then(|c| c.parse::<f64>().ok())
+                .ok_or(ScaffoldingError::Geocode)
+        };
+
+        Ok((coordinate("lat")?, coordinate("lon")?))
+    }
+}
+
+/// The masking policy that is applied to the personal data of an entity
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaskPolicy {
+    // The masking style for the address lines
+    pub addresses: MaskStyle,
+    // The masking style for the email addresses
+    pub email_addresses: MaskStyle,
+    // The masking style for the content of the notes
+    pub notes: MaskStyle,
+    // The masking style for the phone numbers
+    pub phone_numbers: MaskStyle,
+}
+
+impl MaskPolicy {
+    /// This is the constructor function that applies the same masking style to all the personal data.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut policy = MaskPolicy::new(MaskStyle::Partial);
+    /// policy.notes = MaskStyle::Full;
+    ///
+    /// assert_eq!(policy.email_addresses, MaskStyle::Partial);
+    /// ```
+    pub fn new(style: MaskStyle) -> Self {
+        Self {
+            addresses: style,
+            email_addresses: style,
+            notes: style,
+            phone_numbers: style,
+        }
+    }
+}
+
+/// The supported styles for masking personal data
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MaskStyle {
+    // Every character is replaced with `*`
+    Full,
+    // Only enough characters to recognize the value are kept, (e.g.: m****@example.com, ******3561)
+    Partial,
+    // The value is replaced with its SHA-256 hash (hex encoded)
+    Hash,
+    // The value is left as is
+    Unmasked,
+}
+
+impl MaskStyle {
+    /// This function masks a textual value.
+    /// Partial masking keeps the first character of every word.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert_eq!(MaskStyle::Full.mask("14 Main Street"), "**************");
+    /// assert_eq!(MaskStyle::Partial.mask("14 Main Street"), "1* M*** S*****");
+    /// assert_eq!(MaskStyle::Hash.mask("14 Main Street").len(), 64);
+    /// assert_eq!(MaskStyle::Unmasked.mask("14 Main Street"), "14 Main Street");
+    /// ```
+    pub fn mask(&self, value: &str) -> String {
+        match self {
+            MaskStyle::Full => "*".repeat(value.chars().count()),
+            MaskStyle::Partial => value
+                .split(' ')
+                .map(|word| {
+                    word.chars()
+                        .enumerate()
+                        .map(|(i, c)| if i == 0 { c } else { '*' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>()
+                .join(" "),
+            MaskStyle::Hash => format!("{:x}", Sha256::digest(value.as_bytes())),
+            MaskStyle::Unmasked => value.to_string(),
+        }
+    }
+
+    /// This function masks an email address.
+    /// Partial masking keeps the first character of the mailbox and the domain.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert_eq!(MaskStyle::Partial.mask_email("myemail@example.com"), "m****@example.com");
+    /// assert_eq!(MaskStyle::Full.mask_email("me@example.com"), "**************");
+    /// ```
+    pub fn mask_email(&self, value: &str) -> String {
+        match (self, value.split_once('@')) {
+            (MaskStyle::Partial, Some((mailbox, domain))) => {
+                format!(
+                    "{}****@{}",
+                    mailbox.chars().take(1).collect::<String>(),
+                    domain
+                )
+            }
+            _ => self.mask(value),
+        }
+    }
+
+    /// This function masks a phone number.
+    /// Partial masking keeps the last 4 digits.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert_eq!(MaskStyle::Partial.mask_phone("8482493561"), "******3561");
+    /// assert_eq!(MaskStyle::Full.mask_phone("8482493561"), "**********");
+    /// ```
+    pub fn mask_phone(&self, value: &str) -> String {
+        match self {
+            MaskStyle::Partial => {
+                let keep = value.chars().count().saturating_sub(4);
+                value
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| if i < keep { '*' } else { c })
+                    .collect()
+            }
+            _ => self.mask(value),
+        }
+    }
+}
+
+/// The rules that the metadata of an entity type must follow, keyed by the metadata key
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetadataSchema {
+    // The rules of the metadata keys
+    pub keys: BTreeMap<String, MetadataRule>,
+}
+
+impl MetadataSchema {
+    /// This is the constructor function for an empty schema, (any metadata is valid).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut schema = MetadataSchema::new();
+    /// schema.keys.insert(
+    ///     "seats".to_string(),
+    ///     MetadataRule::new(true, MetadataType::Integer, Vec::new()),
+    /// );
+    ///
+    /// assert_eq!(schema.keys.len(), 1);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            keys: BTreeMap::new(),
+        }
+    }
+
+    /// This function builds a schema from a list of rules written as `key: options`,
+    /// where the options are separated by whitespace and are `required`, a type, (`text`, `integer`, `number` or `boolean`)
+    /// or the allowed values separated by `|`. A key without options can have any text value.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let schema = MetadataSchema::from_rules(vec![
+    ///     "source: required",
+    ///     "seats: integer",
+    ///     "tier: required bronze|silver|gold",
+    /// ]);
+    ///
+    /// assert!(schema.keys.get("source").unwrap().required);
+    /// assert_eq!(schema.keys.get("seats").unwrap().value_type, MetadataType::Integer);
+    /// assert_eq!(schema.keys.get("tier").unwrap().allowed.len(), 3);
+    /// ```
+    pub fn from_rules(rules: Vec<&str>) -> Self {
+        let mut schema = Self::new();
+        for rule in rules.iter() {
+            let (key, options) = rule.split_once(':').unwrap_or((rule, ""));
+            if key.trim().is_empty() {
+                continue;
+            }
+            let rule = schema.keys.entry(key.trim().to_string()).or_default();
+            for option in options.split_whitespace() {
+                match option {
+                    "required" => rule.required = true,
+                    "text" => rule.value_type = MetadataType::Text,
+                    "integer" => rule.value_type = MetadataType::Integer,
+                    "number" => rule.value_type = MetadataType::Number,
+                    "boolean" => rule.value_type = MetadataType::Boolean,
+                    values => {
+                        rule.allowed = values
+                            .split('|')
+                            .filter(|v| !v.is_empty())
+                            .map(|v| v.to_string())
+                            .collect()
+                    }
+                }
+            }
+        }
+        schema
+    }
+
+    /// This function validates the metadata and returns the violations, (empty if the metadata is valid).
+    /// Keys that are not in the schema are not validated.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let schema = MetadataSchema::from_rules(vec!["source: required", "seats: integer"]);
+    /// let mut metadata = BTreeMap::new();
+    /// metadata.insert("seats".to_string(), "five".to_string());
+    ///
+    /// assert_eq!(
+    ///     schema.validate(&metadata),
+    ///     vec![
+    ///         MetadataViolation::InvalidType("seats".to_string(), MetadataType::Integer),
+    ///         MetadataViolation::Missing("source".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn validate(&self, metadata: &BTreeMap<String, String>) -> Vec<MetadataViolation> {
+        let mut violations = Vec::new();
+        for (key, rule) in self.keys.iter() {
+            match metadata.get(key) {
+                None if rule.required => violations.push(MetadataViolation::Missing(key.clone())),
+                None => {}
+                Some(value) if !rule.value_type.accepts(value) => {
+                    violations.push(MetadataViolation::InvalidType(key.clone(), rule.value_type))
+                }
+                Some(value) if !rule.allowed.is_empty() && !rule.allowed.contains(value) => {
+                    violations.push(MetadataViolation::NotAllowed(key.clone(), value.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+        violations
+    }
+}
+
+/// The rule of a metadata key, (see `MetadataSchema`)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetadataRule {
+    // The key must be present
+    pub required: bool,
+    // The type that the value must have
+    pub value_type: MetadataType,
+    // The values that are allowed, (any value if empty)
+    pub allowed: Vec<String>,
+}
+
+impl MetadataRule {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let rule = MetadataRule::new(false, MetadataType::Text, vec!["emea".to_string(), "apac".to_string()]);
+    ///
+    /// assert!(!rule.required);
+    /// ```
+    pub fn new(required: bool, value_type: MetadataType, allowed: Vec<String>) -> Self {
+        Self {
+            required,
+            value_type,
+            allowed,
+        }
+    }
+}
+
+/// The types of metadata values, (the values are stored as text and must be readable as the type)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MetadataType {
+    // Any value
+    #[default]
+    Text,
+    // A whole number, (e.g.: 42)
+    Integer,
+    // A finite number, (e.g.: 4.2)
+    Number,
+    // Either `true` or `false`
+    Boolean,
+}
+
+impl MetadataType {
+    /// This function determines if the value can be read as the type.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert!(MetadataType::Integer.accepts("42"));
+    /// assert!(!MetadataType::Integer.accepts("4.2"));
+    /// assert!(MetadataType::Number.accepts("4.2"));
+    /// assert!(!MetadataType::Boolean.accepts("yes"));
+    /// ```
+    pub fn accepts(&self, value: &str) -> bool {
+        match self {
+            MetadataType::Text => true,
+            MetadataType::Integer => value.parse::<i64>().is_ok(),
+            MetadataType::Number => value.parse::<f64>().is_ok_and(|n| n.is_finite()),
+            MetadataType::Boolean => value == "true" || value == "false",
+        }
+    }
+}
+
+/// A metadata value that doesn't follow the `MetadataSchema`
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetadataViolation {
+    // The required key is missing
+    Missing(String),
+    // The value of the key doesn't have the type
+    InvalidType(String, MetadataType),
+    // The value of the key isn't one of the allowed values
+    NotAllowed(String, String),
+}
+
+impl fmt::Display for MetadataViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetadataViolation::Missing(key) => write!(f, "The metadata {} is required.", key),
+            MetadataViolation::InvalidType(key, value_type) => {
+                write!(f, "The metadata {} must be of type {:?}.", key, value_type)
+            }
+            MetadataViolation::NotAllowed(key, value) => {
+                write!(f, "The metadata {} can't be {}.", key, value)
+            }
+        }
+    }
+}
+
+// The name of the manifest file that `Note::export_to_dir()` writes
+const NOTE_INDEX: &str = "index.json";
+
+/// The attributes that notes can be sorted by, (see `list_notes()`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NoteSort {
+    // The timestamp when the note was created
+    Created,
+    // The timestamp when the note was last modified
+    Modified,
+    // The identifier of the author of the note
+    Author,
+    // The access rule of the note
+    Access,
+}
+
+/// The direction of a sort
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortOrder {
+    // The smallest value first, (e.g.: oldest)
+    Ascending,
+    // The largest value first, (e.g.: newest)
+    Descending,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Note {
+    // The unique identifier of the note
+    pub id: String,
+    // The timestamp when the note was created
+    pub created_dtm: i64,
+    // The timestamp when the note was last modified
+    pub modified_dtm: i64,
+    // The identifier of the author of the note
+    pub author: String,
+    // The identifier of access rule of the note, (e.g.: public, internal, confidential)
+    pub access: String,
+    // The comment of the note
+    pub content: Vec<u8>,
+    // The content type of the note, (e.g.: text/plain, text/markdown, application/pdf)
+    #[serde(
+        default = "defaults::mime_type",
+        skip_serializing_if = "Note::is_default_mime_type"
+    )]
+    pub mime_type: String,
+    // The identifier of the note this note is a reply to, (see `reply_to_note()`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    // Whether the content is encrypted, (see `encrypt()`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub encrypted: bool,
+}
+
+impl Note {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust    
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// ```
+    pub fn new(auth: String, cont: Vec<u8>, acc: Option<String>) -> Self {
+        Self {
+            id: defaults::id(),
+            created_dtm: defaults::now(),
+            modified_dtm: defaults::now(),
+            author: auth,
+            access: match acc {
+                Some(a) => a,
+                None => defaults::access(),
+            },
+            content: cont,
+            mime_type: defaults::mime_type(),
+            parent_id: None,
+            encrypted: false,
+        }
+    }
+
+    // the default content type is not serialized, (so notes serialized before it existed are unchanged)
+    fn is_default_mime_type(mime_type: &str) -> bool {
+        mime_type == defaults::mime_type()
+    }
+
+    /// This is the constructor function for a Note with a content type other than `text/plain`.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let note = Note::new_with_mime_type(
+    ///     "fsmith".to_string(),
+    ///     "# Follow up".as_bytes().to_vec(),
+    ///     None,
+    ///     "text/markdown".to_string(),
+    /// );
+    ///
+    /// assert_eq!(note.mime_type, "text/markdown".to_string());
+    /// ```
+    pub fn new_with_mime_type(
+        auth: String,
+        cont: Vec<u8>,
+        acc: Option<String>,
+        mime_type: String,
+    ) -> Self {
+        let mut note = Self::new(auth, cont, acc);
+        note.mime_type = mime_type;
+        note
+    }
+
+    /// This is the constructor function for a Note whose content is encrypted at rest using AES-256-GCM.
+    /// The access of the note is recorded as `encrypted`.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust    
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let key = [7u8; 32];
+    /// let note = Note::new_encrypted("fsmith".to_string(), "This is confidential".as_bytes().to_vec(), &key);
+    ///
+    /// assert_eq!(note.access, "encrypted".to_string());
+    /// assert_ne!(note.content, "This is confidential".as_bytes().to_vec());
+    /// ```
+    #[cfg(feature = "encryption")]
+    pub fn new_encrypted(auth: String, cont: Vec<u8>, key: &[u8; 32]) -> Self {
+        let mut note = Self::new(auth, cont, Some("encrypted".to_string()));
+        note.encrypt(key);
+        note
+    }
+
+    /// This function encrypts the content of the note at rest using AES-256-GCM, (the access is unchanged).
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let key = [7u8; 32];
+    /// let mut note = Note::new("fsmith".to_string(), "The salary".as_bytes().to_vec(), Some("confidential".to_string()));
+    /// note.encrypt(&key);
+    ///
+    /// assert!(note.encrypted);
+    /// assert_eq!(note.decrypt(&key).unwrap(), "The salary".as_bytes().to_vec());
+    /// ```
+    #[cfg(feature = "encryption")]
+    pub fn encrypt(&mut self, key: &[u8; 32]) {
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        // the nonce is stored in front of the cipher text
+        let mut content = nonce.to_vec();
+        content.append(&mut cipher.encrypt(&nonce, self.content.as_ref()).unwrap());
+
+        self.content = content;
+        self.encrypted = true;
+    }
+
+    /// This function returns the decrypted content of a note that was created using `new_encrypted()`.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust    
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let key = [7u8; 32];
+    /// let note = Note::new_encrypted("fsmith".to_string(), "This is confidential".as_bytes().to_vec(), &key);
+    ///
+    /// assert_eq!(note.decrypt(&key).unwrap(), "This is confidential".as_bytes().to_vec());
+    /// assert!(note.decrypt(&[8u8; 32]).is_err());
+    /// ```
+    #[cfg(feature = "encryption")]
+    pub fn decrypt(&self, key: &[u8; 32]) -> Result<Vec<u8>, ScaffoldingError> {
+        if self.content.len() < 12 {
+            return Err(ScaffoldingError::Decrypt);
+        }
+        let (nonce, cipher_text) = self.content.split_at(12);
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+        cipher
+            .decrypt(Nonce::from_slice(nonce), cipher_text)
+            .map_err(|_| ScaffoldingError::Decrypt)
+    }
+
+    /// This function returns the content of the note as a string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::{defaults, Note};
+    ///
+    /// let note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// assert_eq!(note.content_as_string().unwrap(), "This was updated".to_string());
+    /// ```
+    pub fn content_as_string(&self) -> Result<String, String> {
+        String::from_utf8(self.content.clone())
+            .map_err(|non_utf8| String::from_utf8_lossy(non_utf8.as_bytes()).into_owned())
+    }
+
+    /// This function renders the content of the note as HTML based on its content type.
+    /// Markdown is converted and any other text, (including HTML) is escaped.
+    /// The content is untrusted user input, so the raw HTML in markdown is escaped as text and
+    /// the links and images with a script URL, (e.g.: `javascript:`) are neutralized.
+    /// Returns None for binary content, (e.g.: application/pdf).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let note = Note::new_with_mime_type(
+    ///     "fsmith".to_string(),
+    ///     "Call **today**".as_bytes().to_vec(),
+    ///     None,
+    ///     "text/markdown".to_string(),
+    /// );
+    /// assert_eq!(note.content_as_markdown_html().unwrap(), "<p>Call <strong>today</strong></p>\n".to_string());
+    ///
+    /// let note = Note::new("fsmith".to_string(), "1 < 2".as_bytes().to_vec(), None);
+    /// assert_eq!(note.content_as_markdown_html().unwrap(), "<p>1 &lt; 2</p>\n".to_string());
+    ///
+    /// let note = Note::new_with_mime_type(
+    ///     "fsmith".to_string(),
+    ///     "<script>alert(1)</script>".as_bytes().to_vec(),
+    ///     None,
+    ///     "text/markdown".to_string(),
+    /// );
+    /// assert_eq!(note.content_as_markdown_html().unwrap(), "&lt;script&gt;alert(1)&lt;/script&gt;".to_string());
+    /// ```
+    pub fn content_as_markdown_html(&self) -> Option<String> {
+        let mime_type = self.mime_type.to_lowercase();
+        // ignore the parameters, (e.g.: text/markdown; charset=UTF-8)
+        let mime_type = mime_type.split(';').next().unwrap_or_default().trim();
+        if !mime_type.starts_with("text/") {
+            return None;
+        }
+        let content = String::from_utf8(self.content.clone()).ok()?;
+
+        match mime_type {
+            "text/markdown" | "text/x-markdown" => {
+                let events = Parser::new(&content).map(|event| match event {
+                    // the raw HTML is rendered as text, so it can't inject markup or scripts
+                    Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+                    Event::Start(Tag::Link {
+                        link_type,
+                        dest_url,
+                        title,
+                        id,
+                    }) => Event::Start(Tag::Link {
+                        link_type,
+                        dest_url: Self::safe_url(dest_url),
+                        title,
+                        id,
+                    }),
+                    Event::Start(Tag::Image {
+                        link_type,
+                        dest_url,
+                        title,
+                        id,
+                    }) => Event::Start(Tag::Image {
+                        link_type,
+                        dest_url: Self::safe_url(dest_url),
+                        title,
+                        id,
+                    }),
+                    event => event,
+                });
+                let mut rendered = String::new();
+                html::push_html(&mut rendered, events);
+                Some(rendered)
+            }
+            _ => {
+                let escaped = content
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;")
+                    .replace('"', "&quot;");
+                Some(format!("<p>{}</p>\n", escaped))
+            }
+        }
+    }
+
+    // replaces the URLs that run a script when they are opened, (e.g.: javascript:alert(1))
+    fn safe_url(url: CowStr) -> CowStr {
+        let scheme: String = url
+            .trim_start()
+            .chars()
+            .filter(|c| !c.is_whitespace() && !c.is_control())
+            .take_while(|c| *c != ':')
+            .collect::<String>()
+            .to_lowercase();
+        match url.contains(':') && ["javascript", "vbscript", "data"].contains(&scheme.as_str()) {
+            true => CowStr::Borrowed("#"),
+            false => url,
+        }
+    }
+
+    /// This function turns the Note into a tombstone by replacing the content with the redaction marker.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut note = Note::new("fsmith".to_string(), "Call me at 8482493561".as_bytes().to_vec(), None);
+    /// note.erase();
+    ///
+    /// assert_eq!(note.content_as_string().unwrap(), defaults::redacted());
+    /// ```
+    pub fn erase(&mut self) {
+        self.content = defaults::redacted().into_bytes();
+        self.encrypted = false;
+        self.modified_dtm = defaults::now();
+    }
+
+    /// This function masks the content of the note using the specified masking style.
+    /// Encrypted content can't be masked, so it is replaced with the encrypted marker, (unless the style is `Unmasked`).
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut note = Note::new("fsmith".to_string(), "Call me at 8482493561".as_bytes().to_vec(), None);
+    /// note.mask(&MaskStyle::Full);
+    ///
+    /// assert_eq!(note.content_as_string().unwrap(), "*********************".to_string());
+    /// ```
+    pub fn mask(&mut self, style: &MaskStyle) {
+        if self.encrypted {
+            if *style != MaskStyle::Unmasked {
+                self.content = defaults::encrypted().into_bytes();
+                self.encrypted = false;
+            }
+            return;
+        }
+        let content = String::from_utf8_lossy(&self.content).into_owned();
+        self.content = style.mask(&content).into_bytes();
+    }
+
+    /// This function writes each note to a file in the directory named by its id, (the content as is)
+    /// and the attributes of the notes to the `index.json` manifest. Returns the number of notes written.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let dir = std::env::temp_dir().join(defaults::id());
+    /// let note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    ///
+    /// assert_eq!(Note::export_to_dir(&[&note], &dir.to_string_lossy()).unwrap(), 1);
+    /// assert!(dir.join(&note.id).exists());
+    /// assert!(dir.join("index.json").exists());
+    /// # std::fs::remove_dir_all(dir).unwrap();
+    /// ```
+    pub fn export_to_dir(notes: &[&Note], dir: &str) -> Result<usize, ScaffoldingError> {
+        let storage = |err: std::io::Error| ScaffoldingError::Storage(Box::new(err));
+        let dir = Path::new(dir);
+        fs::create_dir_all(dir).map_err(storage)?;
+
+        let mut index = Vec::new();
+        for note in notes.iter() {
+            let file =
+                Self::file_name(&note.id).ok_or_else(|| Self::invalid_file_name(&note.id))?;
+            fs::write(dir.join(file), &note.content).map_err(storage)?;
+            // the manifest has the attributes, (the content is in the note's own file)
+            let mut entry = serde_json::to_value(note)
+                .map_err(|err| ScaffoldingError::Storage(Box::new(err)))?;
+            if let Some(attrs) = entry.as_object_mut() {
+                attrs.remove("content");
+            }
+            index.push(entry);
+        }
+        let manifest = serde_json::to_string_pretty(&index)
+            .map_err(|err| ScaffoldingError::Storage(Box::new(err)))?;
+        fs::write(dir.join(NOTE_INDEX), manifest).map_err(storage)?;
+
+        Ok(index.len())
+    }
+
+    /// This function reads the notes from a directory that was written using `export_to_dir()`.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let dir = std::env::temp_dir().join(defaults::id());
+    /// let note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = Note::export_to_dir(&[&note], &dir.to_string_lossy());
+    ///
+    /// let notes = Note::import_from_dir(&dir.to_string_lossy()).unwrap();
+    ///
+    /// assert_eq!(notes[0].id, note.id);
+    /// assert_eq!(notes[0].content, "This was updated".as_bytes().to_vec());
+    /// # std::fs::remove_dir_all(dir).unwrap();
+    /// ```
+    pub fn import_from_dir(dir: &str) -> Result<Vec<Note>, ScaffoldingError> {
+        let storage = |err: std::io::Error| ScaffoldingError::Storage(Box::new(err));
+        let invalid = |source| ScaffoldingError::Deserialize {
+            source,
+            type_name: "Note".to_string(),
+        };
+        let dir = Path::new(dir);
+        let manifest = fs::read(dir.join(NOTE_INDEX)).map_err(storage)?;
+        let index: Vec<Value> = serde_json::from_slice(&manifest).map_err(invalid)?;
+
+        let mut notes = Vec::new();
+        for mut entry in index.into_iter() {
+            let id = entry["id"]
+                .as_str()
+                .ok_or_else(|| invalid(serde::de::Error::missing_field("id")))?;
+            let file = Self::file_name(id).ok_or_else(|| Self::invalid_file_name(id))?;
+            let content = fs::read(dir.join(file)).map_err(storage)?;
+            if let Some(attrs) = entry.as_object_mut() {
+                attrs.insert("content".to_string(), json!(content));
+            }
+            notes.push(serde_json::from_value(entry).map_err(invalid)?);
+        }
+
+        Ok(notes)
+    }
+
+    fn invalid_file_name(id: &str) -> ScaffoldingError {
+        ScaffoldingError::Storage(format!("The note id {} can't be used as a file name", id).into())
+    }
+
+    // the id is used as the file name, so it can't point outside the directory or replace the manifest
+    fn file_name(id: &str) -> Option<&str> {
+        match id.is_empty() || id == NOTE_INDEX || id.starts_with('.') || id.contains(['/', '\\']) {
+            true => None,
+            false => Some(id),
+        }
+    }
+
+    /// This function instantiates an ActivityItem from a JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let serialized = r#"{
+    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
+    ///   "created_dtm":1711833619,
+    ///   "modified_dtm":1711833619,
+    ///   "author":"fsmith",
+    ///   "access":"public",
+    ///   "content":[84,104,105,115,32,119,97,115,32,117,112,100,97,116,101,100]
+    /// }"#;
+    /// let mut note = Note::deserialized(&serialized.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(note.created_dtm, 1711833619);
+    /// assert_eq!(note.modified_dtm, 1711833619);
+    /// assert_eq!(note.author, "fsmith".to_string());
+    /// assert_eq!(note.access, "public".to_string());
+    /// assert_eq!(note.content, "This was updated".as_bytes().to_vec());
+    /// ```
+    pub fn deserialized(serialized: &[u8]) -> Result<Note, ScaffoldingError> {
+        match serde_json::from_slice(serialized) {
+            Ok(item) => Ok(item),
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "Note".to_string(),
+            }),
+        }
+    }
+
+    /// This function converts the ActivityItem to a serialize JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let note = Note::new("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// println!("{}", note.serialize());
+    /// ```
+    pub fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+
+    /// This function updates the note and sets the modified_dtm.
+    /// The modified_dtm will not be changed if the attributes are written to directly.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let serialized = r#"{
+    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
+    ///   "created_dtm":1711833619,
+    ///   "modified_dtm":1711833619,
+    ///   "author":"fsmith",
+    ///   "access":"public",
+    ///   "content":[84,104,105,115,32,119,97,115,32,117,112,100,97,116,101,100]
+    /// }"#;
+    /// let mut note = Note::deserialized(&serialized.as_bytes()).unwrap();
+    /// let first_modified = note.modified_dtm.clone();
+    ///
+    /// note.update("fsmith".to_string(), "This was updated again".as_bytes().to_vec(), Some("private".to_string()));
+    ///
+    /// assert_eq!(note.author, "fsmith".to_string());
+    /// assert_eq!(note.access, "private".to_string());
+    /// assert_eq!(note.content, "This was updated again".as_bytes().to_vec());
+    /// assert!(note.modified_dtm > first_modified);
+    /// ```
+    pub fn update(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>) {
+        self.author = auth;
+        self.content = cont;
+        self.encrypted = false;
+        self.access = match acc {
+            Some(a) => a,
+            None => self.access.clone(),
+        };
+        self.modified_dtm = defaults::now();
+    }
+}
+
+/// The permission a principal has been granted on a shared object
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Permission {
+    // The principal can read the object
+    Read,
+    // The principal can read and modify the object
+    Write,
+}
+
+/// The ways a phone number can be reached
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PhoneCapability {
+    // The phone number accepts voice calls
+    Voice,
+    // The phone number is a mobile phone
+    Mobile,
+    // The phone number receives text messages
+    Sms,
+    // The phone number receives faxes
+    Fax,
+}
+
+// The extension at the end of a phone number, (compiled once by `PhoneNumber::parse()`)
+static PHONE_EXTENSION: OnceLock<Regex> = OnceLock::new();
+// The candidates of phone numbers in free-form text, (compiled once by `PhoneNumber::extract_all()`)
+static PHONE_CANDIDATE: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PhoneNumber {
+    // The unique identifier of the note
+    pub id: String,
+    // The timestamp when the note was created
+    pub created_dtm: i64,
+    // The timestamp when the note was last modified
+    pub modified_dtm: i64,
+    // The type of phone number, (e.g.: Home, Mobile, Work, Office, etc.)
+    pub category: Category,
+    // The phone number
+    pub number: String,
+    // The country code of the phone number (Use Alpha 3 codes)
+    pub country_code: String,
+    // Whether this is the primary phone number of its category, (see `set_primary_phone_number()`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub primary: bool,
+    // The extension that is dialed after the phone number, (e.g.: 204)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+    // The ways the phone number can be reached, (e.g.: sms)
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub capabilities: BTreeSet<PhoneCapability>,
+}
+
+impl PhoneNumber {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///   let phone = PhoneNumber::new(
+    ///       "home".to_string(),
+    ///       "8482493561".to_string(),
+    ///       "USA".to_string(),
+    ///   );
+    ///   
+    ///   // scaffolding attributes
+    ///   println!("{}", phone.id);
+    ///   println!("{}", phone.created_dtm);
+    ///   println!("{}", phone.modified_dtm,);
+    /// }
+    /// ```
+    pub fn new(category: impl Into<Category>, number: String, country_code: String) -> Self {
+        Self {
+            id: defaults::id(),
+            created_dtm: defaults::now(),
+            modified_dtm: defaults::now(),
+            category: category.into(),
+            number,
+            country_code,
+            primary: false,
+            extension: None,
+            capabilities: BTreeSet::new(),
+        }
+    }
+
+    /// This function adds the capability to the phone number.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut phone = PhoneNumber::new(
+    ///     "personal".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// phone.add_capability(PhoneCapability::Sms);
+    ///
+    /// assert!(phone.has_capability(PhoneCapability::Sms));
+    /// assert!(!phone.has_capability(PhoneCapability::Fax));
+    /// ```
+    pub fn add_capability(&mut self, capability: PhoneCapability) {
+        if self.capabilities.insert(capability) {
+            self.modified_dtm = defaults::now();
+        }
+    }
+
+    /// This function determines if the phone number has the capability.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let phone = PhoneNumber::new(
+    ///     "personal".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert!(!phone.has_capability(PhoneCapability::Sms));
+    /// ```
+    pub fn has_capability(&self, capability: PhoneCapability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// This function removes the capability from the phone number.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut phone = PhoneNumber::new(
+    ///     "personal".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// phone.add_capability(PhoneCapability::Fax);
+    /// phone.remove_capability(PhoneCapability::Fax);
+    ///
+    /// assert!(!phone.has_capability(PhoneCapability::Fax));
+    /// ```
+    pub fn remove_capability(&mut self, capability: PhoneCapability) {
+        if self.capabilities.remove(&capability) {
+            self.modified_dtm = defaults::now();
+        }
+    }
+
+    /// This function determines if the phone number contains the digits of the fragment, (e.g.: caller-ID digits).
+    /// Spaces, dashes and other formatting are ignored, as well as a leading country calling code or trunk prefix.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let phone = PhoneNumber::new(
+    ///     "work".to_string(),
+    ///     "2079460958".to_string(),
+    ///     "GBR".to_string(),
+    /// );
+    ///
+    /// assert!(phone.matches_number("+44 20 7946 0958", &countries));
+    /// assert!(phone.matches_number("020 7946 0958", &countries));
+    /// assert!(phone.matches_number("7946-0958", &countries));
+    /// assert!(!phone.matches_number("7946-0959", &countries));
+    /// ```
+    pub fn matches_number(&self, fragment: &str, countries: &Countries) -> bool {
+        let digits: String = fragment.chars().filter(|c| c.is_ascii_digit()).collect();
+        let number: String = self.number.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        if digits.is_empty() || number.is_empty() {
+            return false;
+        }
+        if number.contains(&digits) {
+            return true;
+        }
+
+        // the fragment may start with the international prefix and calling code, (e.g.: +44 or 0044)
+        let mut candidates = vec![digits.trim_start_matches('0').to_string()];
+        if let Some(country) = countries.get_country_by_iso_3_code(self.country_code.clone()) {
+            let international = match digits.strip_prefix("00") {
+                Some(v) => v,
+                None => digits.as_str(),
+            };
+            for prefix in country.dialing_prefixes() {
+                if let Some(national) = international.strip_prefix(prefix.as_str()) {
+                    candidates.push(national.to_string());
+                }
+            }
+            let code = country.calling_code();
+            if let Some(national) = international.strip_prefix(code.as_str()) {
+                candidates.push(Self::without_trunk_prefix(country, national));
+            }
+        }
+
+        candidates
+            .iter()
+            .any(|c| !c.is_empty() && number.contains(c.as_str()))
+    }
+
+    /// This is the constructor function for a phone number with an extension.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let phone = PhoneNumber::new_with_extension(
+    ///     "work".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    ///     "204".to_string(),
+    /// );
+    ///
+    /// assert_eq!(phone.number, "8482493561".to_string());
+    /// assert_eq!(phone.extension, Some("204".to_string()));
+    /// ```
+    pub fn new_with_extension(
+        category: impl Into<Category>,
+        number: String,
+        country_code: String,
+        extension: String,
+    ) -> Self {
+        let mut phone = Self::new(category, number, country_code);
+        phone.extension = Some(extension);
+        phone
+    }
+
+    /// This function instantiates a PhoneNumber from a JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let serialized = r#"{
+    ///   "id":"2d624160-16b1-49ce-9b90-09a82127d6ac",
+    ///   "created_dtm":1711833619,
+    ///   "modified_dtm":1711833619,
+    ///   "category":"home",
+    ///   "number":"8482493561",
+    ///   "country_code": "USA"
+    /// }"#;
+    /// let mut phone = PhoneNumber::deserialized(&serialized.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(phone.created_dtm, 1711833619);
+    /// assert_eq!(phone.modified_dtm, 1711833619);
+    /// assert_eq!(phone.category, "home".to_string());
+    /// ```
+    pub fn deserialized(serialized: &[u8]) -> Result<PhoneNumber, ScaffoldingError> {
+        match serde_json::from_slice(serialized) {
+            Ok(item) => Ok(item),
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "PhoneNumber".to_string(),
+            }),
+        }
+    }
+
+    /// This function turns the PhoneNumber into a tombstone by replacing the number with the redaction marker.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut phone = PhoneNumber::new(
+    ///       "home".to_string(),
+    ///       "8482493561".to_string(),
+    ///       "USA".to_string(),
+    /// );
+    /// phone.erase();
+    ///
+    /// assert_eq!(phone.number, defaults::redacted());
+    /// ```
+    pub fn erase(&mut self) {
+        self.number = defaults::redacted();
+        self.extension = None;
+        self.modified_dtm = defaults::now();
+    }
+
+    /// This function masks the phone number and its extension using the specified masking style.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut phone = PhoneNumber::new(
+    ///       "home".to_string(),
+    ///       "8482493561".to_string(),
+    ///       "USA".to_string(),
+    /// );
+    /// phone.mask(&MaskStyle::Partial);
+    ///
+    /// assert_eq!(phone.number, "******3561".to_string());
+    /// ```
+    pub fn mask(&mut self, style: &MaskStyle) {
+        self.number = style.mask_phone(&self.number);
+        self.extension = self.extension.as_ref().map(|ext| style.mask(ext));
+    }
+
+    /// This function returns a normalized form of the phone number that is used for fuzzy comparisons,
+    /// (only the digits are kept).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let phone = PhoneNumber::new(
+    ///       "home".to_string(),
+    ///       "(848) 249-3561".to_string(),
+    ///       "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(phone.normalized(), "8482493561".to_string());
+    /// ```
+    pub fn normalized(&self) -> String {
+        self.number.chars().filter(|c| c.is_ascii_digit()).collect()
+    }
+
+    /// This function parses a phone number in national or international format, (e.g.: "+1 (848) 249-3561",
+    /// "0048 848 249 356" or "(848) 249-3561") and determines its country using the bundled phone codes.
+    /// National numbers belong to the default country, (Alpha 3 code).
+    /// The number of the PhoneNumber is the national number without the trunk prefix and the category is empty.
+    /// A trailing extension, (e.g.: "ext. 204", "x204" or "#204") is kept in the `extension`.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let phone = PhoneNumber::parse("+44 (0)20 7946 0958", "USA").unwrap();
+    ///
+    /// assert_eq!(phone.number, "2079460958".to_string());
+    /// assert_eq!(phone.country_code, "GBR".to_string());
+    /// assert!(PhoneNumber::parse("call me", "USA").is_none());
+    /// ```
+    pub fn parse(raw: &str, default_country: &str) -> Option<PhoneNumber> {
+        let countries = Countries::global();
+        let re = PHONE_EXTENSION.get_or_init(|| {
+            Regex::new(r"(?i)\s*(?:extension|ext\.?|x|#|,|;)\s*(\d{1,7})\s*$").unwrap()
+        });
+        let extension = re.captures(raw).map(|c| c[1].to_string());
+        let raw = re.replace(raw, "");
+        let raw = raw.trim();
+        let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        let (country, national) = match (raw.starts_with('+'), digits.strip_prefix("00")) {
+            (true, _) => Self::international(countries, &digits, default_country)?,
+            (false, Some(digits)) if raw.starts_with("00") => {
+                Self::international(countries, digits, default_country)?
+            }
+            _ => {
+                let country = countries.get_country_by_iso_3_code(default_country.to_string())?;
+                (country, Self::national(country, &digits))
+            }
+        };
+
+        // E.164 numbers have at most 15 digits
+        match national.len() >= 4 && country.calling_code().len() + national.len() <= 15 {
+            true => {
+                let mut phone =
+                    PhoneNumber::new(String::new(), national, country.iso_3_code.clone());
+                phone.extension = extension;
+                Some(phone)
+            }
+            false => None,
+        }
+    }
+
+    /// This function finds the phone numbers in free-form text, (e.g.: an email signature) and returns them as
+    /// PhoneNumber candidates with an empty category, (see `parse()`). Only numbers that are valid for their
+    /// country are returned, so dates and other numbers are ignored.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let signature = "Frank Smith | Acme Inc.\nTel: (848) 249-3561 ext. 204\nLondon office: +44 20 7946 0958";
+    /// let phones = PhoneNumber::extract_all(signature, "USA");
+    ///
+    /// assert_eq!(phones.len(), 2);
+    /// assert_eq!(phones[0].number, "8482493561".to_string());
+    /// assert_eq!(phones[0].extension, Some("204".to_string()));
+    /// assert_eq!(phones[1].country_code, "GBR".to_string());
+    /// ```
+    pub fn extract_all(text: &str, default_country: &str) -> Vec<PhoneNumber> {
+        let countries = Countries::global();
+        let re = PHONE_CANDIDATE.get_or_init(|| {
+            Regex::new(
+                r"(?:\+|\()?\d[\d \t().\-/]{5,}\d(?:[ \t]*(?i:extension|ext\.?|x|#)[ \t]*\d{1,7})?",
+            )
+            .unwrap()
+        });
+        let mut found: Vec<PhoneNumber> = Vec::new();
+
+        for candidate in re.find_iter(text) {
+            if let Some(phone) = Self::parse(candidate.as_str(), default_country) {
+                let duplicate = found
+                    .iter()
+                    .any(|p| p.number == phone.number && p.country_code == phone.country_code);
+                if !duplicate && phone.is_valid(countries) {
+                    found.push(phone);
+                }
+            }
+        }
+
+        found
+    }
+
+    // finds the country of the international number, (the longest matching prefix, then the default country)
+    // and returns the national number
+    fn international<'a>(
+        countries: &'a Countries,
+        digits: &str,
+        default_country: &str,
+    ) -> Option<(&'a Country, String)> {
+        let mut found: Option<(&Country, usize)> = None;
+        for country in countries.list.iter() {
+            for prefix in country.dialing_prefixes() {
+                let better = match found {
+                    None => true,
+                    Some((best, len)) => {
+                        prefix.len() > len
+                            || (prefix.len() == len && best.iso_3_code != default_country)
+                    }
+                };
+                if !prefix.is_empty() && digits.starts_with(&prefix) && better {
+                    found = Some((country, prefix.len()));
+                }
+            }
+        }
+        let (country, _) = found?;
+        let national = &digits[country.calling_code().len()..];
+
+        Some((country, Self::without_trunk_prefix(country, national)))
+    }
+
+    // removes the calling code and trunk prefix, (e.g.: 18482493561 or 0301234567) from a national number
+    fn national(country: &Country, digits: &str) -> String {
+        let code = country.calling_code();
+        let digits = match digits.strip_prefix(code.as_str()) {
+            // national numbers rarely have more than 10 digits
+            Some(national) if digits.len() > 10 => national,
+            _ => digits,
+        };
+        Self::without_trunk_prefix(country, digits)
+    }
+
+    // removes the 0 that is dialed before national numbers, (e.g.: 030 1234567)
+    fn without_trunk_prefix(country: &Country, digits: &str) -> String {
+        match country.iso_3_code.as_str() {
+            // the leading 0 is part of the number
+            "ITA" | "SMR" | "VAT" => digits.to_string(),
+            _ => digits.trim_start_matches('0').to_string(),
+        }
+    }
+
+    /// This function performs a quick check to see if the phone number is properly formatted for its country,
+    /// (see `validate()` for the reason it is not).
+    /// NOTE: This is not a validation that the phone number is in service.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    /// let phone = PhoneNumber::new(
+    ///     "home".to_string(),
+    ///     "(848) 249-3561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(phone.is_valid(&countries), true);
+    /// ```
+    pub fn is_valid(&self, countries: &Countries) -> bool {
+        self.validate(countries).is_ok()
+    }
+
+    /// This function checks the characters, calling code and length of the phone number
+    /// against the country identified by the `country_code`.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    /// use scaffolding_core::errors::PhoneNumberError;
+    ///
+    /// let countries = Countries::new();
+    /// let phone = PhoneNumber::new(
+    ///     "home".to_string(),
+    ///     "249-3561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(phone.validate(&countries), Err(PhoneNumberError::TooShort));
+    /// ```
+    pub fn validate(&self, countries: &Countries) -> Result<(), PhoneNumberError> {
+        let country = countries
+            .get_country_by_iso_3_code(self.country_code.clone())
+            .ok_or(PhoneNumberError::UnknownCountry)?;
+        let number = self.number.trim();
+
+        if !number
+            .chars()
+            .enumerate()
+            .all(|(i, c)| c.is_ascii_digit() || " ()-./".contains(c) || (i == 0 && c == '+'))
+        {
+            return Err(PhoneNumberError::InvalidCharacters);
+        }
+
+        let national = match number.starts_with('+') {
+            true => match Self::international(countries, &self.normalized(), &self.country_code) {
+                Some((c, national)) if c.calling_code() == country.calling_code() => national,
+                _ => return Err(PhoneNumberError::WrongCountryCode),
+            },
+            false => Self::national(country, &self.normalized()),
+        };
+        // E.164 numbers have at most 15 digits
+        let (min, max) = country
+            .phone_number_length
+            .unwrap_or((4, 15 - country.calling_code().len()));
+
+        match national.len() {
+            n if n < min => Err(PhoneNumberError::TooShort),
+            n if n > max => Err(PhoneNumberError::TooLong),
+            _ => Ok(()),
+        }
+    }
+
+    /// This function returns the phone number in the international E.164 format, (e.g.: +18482493561).
+    /// Returns None if the country of the PhoneNumber is unknown.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let countries = Countries::new();
+    ///
+    /// for number in ["+1 (848) 249-3561", "8482493561", "18482493561"] {
+    ///     let phone = PhoneNumber::new("home".to_string(), number.to_string(), "USA".to_string());
+    ///     assert_eq!(phone.to_e164(&countries), Some("+18482493561".to_string()));
+    /// }
+    /// ```
+    pub fn to_e164(&self, countries: &Countries) -> Option<String> {
+        let (country, national) = match self.number.trim().starts_with('+') {
+            true => Self::international(countries, &self.normalized(), &self.country_code)?,
+            false => {
+                let country = countries.get_country_by_iso_3_code(self.country_code.clone())?;
+                (country, Self::national(country, &self.normalized()))
+            }
+        };
+
+        Some(format!("+{}{}", country.calling_code(), national))
+    }
+
+    /// This function updates the phone number and sets the modified_dtm.
+    /// The modified_dtm will not be changed if the attributes are written to directly.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut phone = PhoneNumber::new(
+    ///     "work".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// phone.update(
+    ///     "work".to_string(),
+    ///     "8482490000".to_string(),
+    ///     "USA".to_string(),
+    ///     Some("204".to_string()),
+    /// );
+    ///
+    /// assert_eq!(phone.number, "8482490000".to_string());
+    /// assert_eq!(phone.extension, Some("204".to_string()));
+    /// ```
+    pub fn update(
+        &mut self,
+        category: impl Into<Category>,
+        number: String,
+        country_code: String,
+        extension: Option<String>,
+    ) {
+        let category = category.into();
+        // the primary designation is per category
+        if self.category != category {
+            self.primary = false;
+        }
+        self.category = category;
+        self.number = number;
+        self.country_code = country_code;
+        self.extension = extension;
+        self.modified_dtm = defaults::now();
+    }
+
+    /// This function converts the PhoneNumber to a serialize JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust    
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let phone = PhoneNumber::new(
+    ///       "home".to_string(),
+    ///       "8482493561".to_string(),
+    ///       "USA".to_string(),
+    /// );
+    /// println!("{}", phone.serialize());
+    /// ```
+    pub fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Relationship {
+    // The unique identifier of the relationship
+    pub id: String,
+    // The timestamp when the relationship was created
+    pub created_dtm: i64,
+    // The timestamp when the relationship was last modified
+    pub modified_dtm: i64,
+    // The kind of relationship, (e.g.: parent, employer, duplicate_of, etc.)
+    pub kind: String,
+    // The unique identifier of the related entity
+    pub target_id: String,
+}
+
+impl Relationship {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// fn main() {
+    ///   let relationship = Relationship::new(
+    ///       "parent".to_string(),
+    ///       "2d624160-16b1-49ce-9b90-09a82127d6ac".to_string(),
+    ///   );
+    ///   
+    ///   // scaffolding attributes
+    ///   println!("{}", relationship.id);
+    ///   println!("{}", relationship.created_dtm);
+    ///   println!("{}", relationship.modified_dtm,);
+    /// }
+    /// ```
+    pub fn new(kind: String, target_id: String) -> Self {
+        Self {
+            id: defaults::id(),
+            created_dtm: defaults::now(),
+            modified_dtm: defaults::now(),
+            kind,
+            target_id,
+        }
+    }
+
+    /// This function instantiates a Relationship from a JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let serialized = r#"{
+    ///   "id":"7e2a5d1c-3f51-4d4b-9a39-5a0f8c1e7b20",
+    ///   "created_dtm":1711833619,
+    ///   "modified_dtm":1711833619,
+    ///   "kind":"parent",
+    ///   "target_id":"2d624160-16b1-49ce-9b90-09a82127d6ac"
+    /// }"#;
+    /// let relationship = Relationship::deserialized(&serialized.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(relationship.created_dtm, 1711833619);
+    /// assert_eq!(relationship.kind, "parent".to_string());
+    /// ```
+    pub fn deserialized(serialized: &[u8]) -> Result<Relationship, ScaffoldingError> {
+        match serde_json::from_slice(serialized) {
+            Ok(item) => Ok(item),
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: "Relationship".to_string(),
+            }),
+        }
+    }
+
+    /// This function converts the Relationship to a serialize JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust     
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let relationship = Relationship::new(
+    ///     "parent".to_string(),
+    ///     "2d624160-16b1-49ce-9b90-09a82127d6ac".to_string(),
+    /// );
+    /// println!("{}", relationship.serialize());
+    /// ```
+    pub fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+/// A retention rule that is applied to a Scaffolding entity
+#[derive(Clone, Debug, PartialEq)]
+pub enum RetentionPolicy {
+    // Removes the related records of the attribute, (e.g.: "activity", "addresses", "email_addresses", "notes", "phone_numbers"),
+    // that were created more than x days ago, (a tamper-evident activity log is re-chained and the pruning is logged)
+    RemoveOlderThan { attribute: String, days: i64 },
+    // Expires the entity x years after it becomes inactive, (the change is logged as the `expiration_changed` activity)
+    ExpireAfterInactivity { years: u32 },
+}
+
+/// The report of what was removed when applying retention policies
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct RetentionReport {
+    // The ids of the entities that are expired, (removed from a collection)
+    pub entities: Vec<String>,
+    // The ids of the related records that were removed grouped by attribute, (e.g.: "notes")
+    pub records: BTreeMap<String, Vec<String>>,
+    // The number of ActivityItems that were removed
+    pub activity: usize,
+    // The attributes of the policies that the entities don't have, (e.g.: a typo such as "note")
+    pub unknown_attributes: Vec<String>,
+}
+
+impl RetentionReport {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let report = RetentionReport::new();
+    ///
+    /// assert!(report.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            entities: Vec::new(),
+            records: BTreeMap::new(),
+            activity: 0,
+            unknown_attributes: Vec::new(),
+        }
+    }
+
+    /// This function determines if nothing was removed.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut report = RetentionReport::new();
+    /// report.activity = 2;
+    ///
+    /// assert!(!report.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+            && self.records.values().all(|r| r.is_empty())
+            && self.activity == 0
+    }
+
+    /// This function adds the results of another report to this report.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut report = RetentionReport::new();
+    /// let mut other = RetentionReport::new();
+    /// other.records.insert("notes".to_string(), vec!["2d624160-16b1-49ce-9b90-09a82127d6ac".to_string()]);
+    /// other.activity = 1;
+    ///
+    /// report.merge(other);
+    ///
+    /// assert_eq!(report.records.get("notes").unwrap().len(), 1);
+    /// assert_eq!(report.activity, 1);
+    /// ```
+    pub fn merge(&mut self, other: RetentionReport) {
+        self.entities.extend(other.entities);
+        for (attr, ids) in other.records {
+            self.records.entry(attr).or_default().extend(ids);
+        }
+        self.activity += other.activity;
+        for attr in other.unknown_attributes {
+            if !self.unknown_attributes.contains(&attr) {
+                self.unknown_attributes.push(attr);
+            }
+        }
+    }
+}
+
+/// The declarative transition table of a status state machine
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusTransitions {
+    // The statuses that can be transitioned to, grouped by the status they are transitioned from
+    pub transitions: BTreeMap<String, Vec<String>>,
+}
+
+impl StatusTransitions {
+    /// This is the constructor function for an empty transition table.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut transitions = StatusTransitions::new();
+    /// transitions.allow("open".to_string(), "closed".to_string());
+    ///
+    /// assert!(transitions.is_allowed("open", "closed"));
+    /// assert!(!transitions.is_allowed("closed", "open"));
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            transitions: BTreeMap::new(),
+        }
+    }
+
+    /// This function builds a transition table from a list of rules written as `from -> to`.
+    /// Rules that are not written as `from -> to` are ignored.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let transitions = StatusTransitions::from_rules(vec!["open -> closed", "closed -> open"]);
+    ///
+    /// assert!(transitions.is_allowed("closed", "open"));
+    /// ```
+    pub fn from_rules(rules: Vec<&str>) -> Self {
+        let mut table = Self::new();
+        for rule in rules.iter() {
+            if let Some((from, to)) = rule.split_once("->") {
+                table.allow(from.trim().to_string(), to.trim().to_string());
+            }
+        }
+        table
+    }
+
+    /// This function adds an allowed transition to the table.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut transitions = StatusTransitions::default();
+    /// transitions.allow("closed".to_string(), "active".to_string());
+    ///
+    /// assert!(transitions.is_allowed("closed", "active"));
+    /// ```
+    pub fn allow(&mut self, from: String, to: String) {
+        let allowed = self.transitions.entry(from).or_default();
+        if !allowed.contains(&to) {
+            allowed.push(to);
+        }
+    }
+
+    /// This function determines if the transition is allowed.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let transitions = StatusTransitions::default();
+    ///
+    /// assert!(transitions.is_allowed("draft", "active"));
+    /// assert!(!transitions.is_allowed("draft", "closed"));
+    /// ```
+    pub fn is_allowed(&self, from: &str, to: &str) -> bool {
+        match self.transitions.get(from) {
+            Some(allowed) => allowed.iter().any(|a| a == to),
+            None => false,
+        }
+    }
+}
+
+impl Default for StatusTransitions {
+    /// The default workflow is draft -> active -> suspended -> closed,
+    /// where a suspended object can be reactivated and an active object can be closed
+    fn default() -> Self {
+        Self::from_rules(vec![
+            "draft -> active",
+            "active -> suspended",
+            "suspended -> active",
+            "active -> closed",
+            "suspended -> closed",
+        ])
+    }
+}
+
+/// A first-level subdivision of a country, (e.g.: a state or province)
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Subdivision {
+    // The ISO 3166-2 code, (e.g.: US-NY)
+    pub code: String,
+    // The name of the subdivision
+    pub name: String,
+}
+
+impl Subdivision {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let subdivision = Subdivision::new("CA-QC".to_string(), "Quebec".to_string());
+    ///
+    /// assert_eq!(subdivision.name, "Quebec");
+    /// ```
+    pub fn new(code: String, name: String) -> Self {
+        Self { code, name }
+    }
+
+    /// Returns the code without the country, (e.g.: NY for US-NY).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let subdivision = Subdivision::new("US-NY".to_string(), "New York".to_string());
+    ///
+    /// assert_eq!(subdivision.abbreviation(), "NY");
+    /// ```
+    pub fn abbreviation(&self) -> &str {
+        match self.code.split_once('-') {
+            Some((_, abbreviation)) => abbreviation,
+            None => &self.code,
+        }
+    }
+}
+
+// The separator of the levels of a hierarchical tag, (e.g.: region:emea:uk)
+const TAG_SEPARATOR: char = ':';
+// The separator of the key and value of a key-value tag, (e.g.: priority=high)
+const TAG_VALUE_SEPARATOR: char = '=';
+
+/// The normalization that is applied to tags before they are added or compared,
+/// so that `VIP`, `vip` and ` vip ` are the same tag
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TagPolicy {
+    // Compare and store the tags in lowercase
+    pub case_folding: bool,
+    // Remove the leading and trailing whitespace
+    pub trim: bool,
+    // Replace the runs of whitespace with a single space
+    pub collapse_whitespace: bool,
+}
+
+impl TagPolicy {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let policy = TagPolicy::new(false, true, true);
+    ///
+    /// assert_eq!(policy.normalize("  Key   Account "), "Key Account".to_string());
+    /// ```
+    pub fn new(case_folding: bool, trim: bool, collapse_whitespace: bool) -> Self {
+        Self {
+            case_folding,
+            trim,
+            collapse_whitespace,
+        }
+    }
+
+    /// This function returns the normalized tag.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert_eq!(TagPolicy::default().normalize(" Key \t Account "), "key account".to_string());
+    /// ```
+    pub fn normalize(&self, tag: &str) -> String {
+        let mut normalized = match self.trim {
+            true => tag.trim().to_string(),
+            false => tag.to_string(),
+        };
+        if self.collapse_whitespace {
+            let mut collapsed = String::with_capacity(normalized.len());
+            let mut whitespace = false;
+            for c in normalized.chars() {
+                match c.is_whitespace() {
+                    true if whitespace => {}
+                    true => collapsed.push(' '),
+                    false => collapsed.push(c),
+                }
+                whitespace = c.is_whitespace();
+            }
+            normalized = collapsed;
+        }
+        match self.case_folding {
+            true => normalized.to_lowercase(),
+            false => normalized,
+        }
+    }
+
+    /// This function determines if the tag is the prefix or is nested under it, (e.g.: `region:emea:uk` is under `region:emea`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let policy = TagPolicy::default();
+    ///
+    /// assert!(policy.has_prefix("region:emea:uk", "Region:EMEA"));
+    /// assert!(policy.has_prefix("region:emea", "region:emea"));
+    /// assert!(!policy.has_prefix("region:emeaX", "region:emea"));
+    /// ```
+    pub fn has_prefix(&self, tag: &str, prefix: &str) -> bool {
+        let tag = self.normalize(tag);
+        let prefix = self.normalize(prefix);
+        match tag.strip_prefix(&prefix) {
+            Some(rest) => rest.is_empty() || rest.starts_with(TAG_SEPARATOR),
+            None => false,
+        }
+    }
+
+    /// This function determines if the tag matches the pattern, where `*` matches a single level
+    /// and `**` matches any number of levels, (e.g.: `region:*:uk` or `region:**`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let policy = TagPolicy::default();
+    ///
+    /// assert!(policy.matches("region:emea:uk", "region:*:uk"));
+    /// assert!(policy.matches("region:emea:uk", "region:**"));
+    /// assert!(!policy.matches("region:emea:uk", "region:*"));
+    /// ```
+    pub fn matches(&self, tag: &str, pattern: &str) -> bool {
+        let tag = self.normalize(tag);
+        let pattern = self.normalize(pattern);
+        let tag: Vec<&str> = tag.split(TAG_SEPARATOR).collect();
+        let pattern: Vec<&str> = pattern.split(TAG_SEPARATOR).collect();
+
+        Self::matches_levels(&tag, &pattern)
+    }
+
+    /// This function returns the normalized key and value of a key-value tag, (e.g.: `priority=high`).
+    /// A tag without a value is a key without a value.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let policy = TagPolicy::default();
+    ///
+    /// assert_eq!(policy.key_value("Priority = High"), ("priority".to_string(), Some("high".to_string())));
+    /// assert_eq!(policy.key_value("vip"), ("vip".to_string(), None));
+    /// ```
+    pub fn key_value(&self, tag: &str) -> (String, Option<String>) {
+        match tag.split_once(TAG_VALUE_SEPARATOR) {
+            Some((key, value)) => (self.normalize(key), Some(self.normalize(value))),
+            None => (self.normalize(tag), None),
+        }
+    }
+
+    /// This function returns the key-value tag, (e.g.: `priority=high`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// assert_eq!(TagPolicy::default().tag_value("Priority", "High"), "priority=high".to_string());
+    /// ```
+    pub fn tag_value(&self, key: &str, value: &str) -> String {
+        format!(
+            "{}{}{}",
+            self.normalize(key),
+            TAG_VALUE_SEPARATOR,
+            self.normalize(value)
+        )
+    }
+
+    /// This function returns the tags that start with the prefix, (e.g.: for autocomplete).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let tags = vec!["region:emea".to_string(), "regional".to_string(), "vip".to_string()];
+    ///
+    /// assert_eq!(TagPolicy::default().search(&tags, "REG").len(), 2);
+    /// ```
+    pub fn search(&self, tags: &[String], prefix: &str) -> Vec<String> {
+        let prefix = self.normalize(prefix);
+        tags.iter()
+            .filter(|t| self.normalize(t).starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+
+    /// This function returns the tags that resemble the partial tag, the best suggestions first:
+    /// the tags that start with it, then the tags with a level or word that starts with it,
+    /// then the tags that contain it and last the tags that contain its characters in order, (e.g.: `emr` for `emea:ireland`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let tags = vec!["customer".to_string(), "key account".to_string(), "account".to_string()];
+    ///
+    /// assert_eq!(
+    ///     TagPolicy::default().suggest(&tags, "acc"),
+    ///     vec!["account".to_string(), "key account".to_string()]
+    /// );
+    /// ```
+    pub fn suggest(&self, tags: &[String], partial: &str) -> Vec<String> {
+        let partial = self.normalize(partial);
+        let mut ranked: Vec<(u8, String, &String)> = tags
+            .iter()
+            .filter_map(|t| {
+                let tag = self.normalize(t);
+                let rank = Self::suggestion_rank(&tag, &partial)?;
+                Some((rank, tag, t))
+            })
+            .collect();
+        ranked.sort();
+
+        ranked.into_iter().map(|(_, _, t)| t.clone()).collect()
+    }
+
+    // ranks how well the tag matches the partial tag, (None when it doesn't)
+    fn suggestion_rank(tag: &str, partial: &str) -> Option<u8> {
+        if tag.starts_with(partial) {
+            return Some(0);
+        }
+        let boundary =
+            |c: char| c == TAG_SEPARATOR || c == TAG_VALUE_SEPARATOR || !c.is_alphanumeric();
+        if tag.split(boundary).any(|w| w.starts_with(partial)) {
+            return Some(1);
+        }
+        if tag.contains(partial) {
+            return Some(2);
+        }
+        let mut chars = tag.chars();
+        match partial.chars().all(|p| chars.any(|c| c == p)) {
+            true => Some(3),
+            false => None,
+        }
+    }
+
+    // compares the levels of the tag with the levels of the pattern
+    fn matches_levels(tag: &[&str], pattern: &[&str]) -> bool {
+        match (pattern.first(), tag.first()) {
+            (None, None) => true,
+            (Some(&"**"), _) => {
+                Self::matches_levels(tag, &pattern[1..])
+                    || (!tag.is_empty() && Self::matches_levels(&tag[1..], pattern))
+            }
+            (Some(p), Some(t)) if *p == "*" || p == t => {
+                Self::matches_levels(&tag[1..], &pattern[1..])
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Default for TagPolicy {
+    /// By default the tags are case folded, trimmed and the whitespace is collapsed
+    fn default() -> Self {
+        Self::new(true, true, true)
+    }
+}
+
+/// The attributes that can be checked using `#[validate(length(...))]`, (see `ScaffoldingValidate`)
+pub trait ValidateLength {
+    /// Returns the number of characters or items.
+    fn validate_length(&self) -> usize;
+}
+
+impl ValidateLength for String {
+    fn validate_length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl<T> ValidateLength for Vec<T> {
+    fn validate_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> ValidateLength for BTreeMap<K, V> {
+    fn validate_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ValidateLength for BTreeSet<T> {
+    fn validate_length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// The attributes that can be checked using `#[validate(range(...))]`, (see `ScaffoldingValidate`)
+pub trait ValidateRange {
+    /// Returns the value as a decimal number.
+    fn validate_range(&self) -> f64;
+}
+
+macro_rules! impl_validate_range {
+    ($($t:ty),*) => {
+        $(impl ValidateRange for $t {
+            fn validate_range(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+impl_validate_range!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/// The violations found when validating an object, (see `ScaffoldingValidate`).
+/// All the violations are collected, (rather than stopping at the first), so they can be returned together.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ValidationReport {
+    // The violations in the order they were found
+    pub violations: Vec<ValidationViolation>,
+}
+
+impl ValidationReport {
+    /// This is the constructor function.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let report = ValidationReport::new();
+    ///
+    /// assert!(report.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    /// This function adds a violation of the attribute to the report.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut report = ValidationReport::new();
+    /// report.add("name", "length", "must have a length of at least 1");
+    ///
+    /// assert_eq!(report.violations[0].field, "name");
+    /// assert_eq!(report.violations[0].code, "length");
+    /// ```
+    pub fn add(&mut self, field: &str, code: &str, message: &str) {
+        self.violations.push(ValidationViolation::new(
+            field.to_string(),
+            code.to_string(),
+            message.to_string(),
+        ));
+    }
+
+    /// This function determines if no violations were found.
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns the number of violations.
+    pub fn len(&self) -> usize {
+        self.violations.len()
+    }
+
+    /// Retrieves the violations of the attribute, (including the attributes nested in it).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut report = ValidationReport::new();
+    /// report.add("name", "length", "must have a length of at least 1");
+    /// report.add("owner.name", "required", "is required");
+    /// report.add("owner_id", "required", "is required");
+    ///
+    /// assert_eq!(report.get_violations("owner").len(), 1);
+    /// assert_eq!(report.get_violations("name").len(), 1);
+    /// ```
+    pub fn get_violations(&self, field: &str) -> Vec<&ValidationViolation> {
+        let nested = format!("{}.", field);
+        self.violations
+            .iter()
+            .filter(|v| v.field == field || v.field.starts_with(&nested))
+            .collect()
+    }
+
+    /// This function adds the violations of another report to this report.
+    /// The prefix is prepended to the attributes of the other report, (e.g.: the attribute the validated object is nested in).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut report = ValidationReport::new();
+    /// let mut other = ValidationReport::new();
+    /// other.add("name", "required", "is required");
+    ///
+    /// report.merge(Some("owner"), other.clone());
+    /// report.merge(None, other);
+    ///
+    /// assert_eq!(report.violations[0].field, "owner.name");
+    /// assert_eq!(report.violations[1].field, "name");
+    /// ```
+    pub fn merge(&mut self, prefix: Option<&str>, other: ValidationReport) {
+        self.violations
+            .extend(other.violations.into_iter().map(|mut v| {
+                if let Some(prefix) = prefix {
+                    v.field = format!("{}.{}", prefix, v.field);
+                }
+                v
+            }));
+    }
+
+    /// This function turns the report into the result of a validation, (Ok if no violations were found).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut report = ValidationReport::new();
+    /// assert!(report.clone().into_result().is_ok());
+    ///
+    /// report.add("name", "required", "is required");
+    /// assert_eq!(report.into_result().unwrap_err().len(), 1);
+    /// ```
+    pub fn into_result(self) -> Result<(), ValidationReport> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self),
+        }
+    }
+
+    /// This function converts the ValidationReport to a serialize JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// let mut report = ValidationReport::new();
+    /// report.add("name", "required", "is required");
+    ///
+    /// assert_eq!(
+    ///     report.serialize(),
+    ///     r#"{"violations":[{"field":"name","code":"required","message":"is required"}]}"#
+    /// );
+    /// ```
+    pub fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    /// Lists the violations, (one per line)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines: Vec<String> = self.violations.iter().map(|v| v.to_string()).collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+#[cfg(feature = "axum")]
+impl IntoResponse for ValidationReport {
+    /// Responds with `422 Unprocessable Entity` and the violations as JSON, (see `serialize()`)
+    fn into_response(self) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, Json(self)).into_response()
+    }
+}
+
+/// A violation of a validation rule by an attribute
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ValidationViolation {
+    // The path of the attribute that violates the rule, (e.g.: "name" or "addresses.<id>.postal_code")
+    pub field: String,
+    // The rule that is violated, (e.g.: "length", "range", "email", "required", "postal_code", "region" or "phone_number")
+    pub code: String,
+    // The description of the violation
+    pub message: String,
+}
+
+impl ValidationViolation {
+    /// This is the constructor function.
+    pub fn new(field: String, code: String, message: String) -> Self {
+        Self {
+            field,
+            code,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ValidationViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.field, self.message)
+    }
+}
+
+/// The type of the unique identifier of a Scaffolding object, (see `#[scaffolding_struct(id_type = "...")]`)
+///
+/// #Example
+///
+/// ```rust
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+/// use std::fmt;
+///
+/// #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+/// struct EntityId(u64);
+///
+/// impl fmt::Display for EntityId {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "E{}", self.0)
+///     }
+/// }
+///
+/// impl ScaffoldingId for EntityId {
+///     fn generate() -> Self {
+///         EntityId(u64::generate())
+///     }
+/// }
+///
+/// #[scaffolding_struct(id_type = "EntityId")]
+/// #[derive(Clone, Debug, Scaffolding)]
+/// struct MyEntity {}
+///
+/// impl MyEntity {
+///     #[scaffolding_fn]
+///     fn new() -> Self {
+///         Self {}
+///     }
+/// }
+///
+/// let entity = MyEntity::new();
+///
+/// assert_eq!(entity.get_id(), entity.id);
+/// ```
+pub trait ScaffoldingId: Clone + std::fmt::Display {
+    /// This function generates a new unique identifier.
+    fn generate() -> Self;
+}
+
+impl ScaffoldingId for String {
+    fn generate() -> Self {
+        defaults::id()
+    }
+}
+
+impl ScaffoldingId for u64 {
+    fn generate() -> Self {
+        uuid::Uuid::new_v4().as_u64_pair().0
+    }
+}
+
+impl ScaffoldingId for u128 {
+    fn generate() -> Self {
+        uuid::Uuid::new_v4().as_u128()
+    }
+}
+
+/// The core behavior of a Scaffolding object
+///
+/// The trait is object safe, so different types of Scaffolding objects can be held together,
+/// (the functions that need the concrete type, e.g.: `serialize()`, are only available on the concrete type).
+///
+/// #Example
+///
+/// ```rust
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// #[scaffolding_struct]
+/// #[derive(Clone, Debug, Scaffolding)]
+/// struct Customer {}
+///
+/// #[scaffolding_struct("tags")]
+/// #[derive(Clone, Debug, Scaffolding)]
+/// struct Order {}
+///
+/// impl Customer {
+///     #[scaffolding_fn]
+///     fn new() -> Self {
+///         Self {}
+///     }
+/// }
+///
+/// impl Order {
+///     #[scaffolding_fn("tags")]
+///     fn new() -> Self {
+///         Self {}
+///     }
+/// }
+///
+/// let mut entities: Vec<Box<dyn Scaffolding<Id = String>>> = vec![
+///     Box::new(Customer::new()),
+///     Box::new(Order::new()),
+/// ];
+///
+/// for entity in entities.iter_mut() {
+///     entity.deactivate();
+/// }
+///
+/// assert_eq!(entities[1].get_activity("deactivated".to_string()).len(), 1);
+/// ```
+///
+/// Enums are supported when every variant has named fields.
+///
+/// ```compile_fail
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// // error: scaffolding_struct only supports enum variants with named fields
+/// #[scaffolding_struct]
+/// #[derive(Clone, Debug, Scaffolding)]
+/// enum Account {
+///     Person { name: String },
+///     Company(String),
+/// }
+/// ```
+///
+/// The `default` option only supports structs.
+///
+/// ```compile_fail
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// // error: the "default" option only supports structs
+/// #[scaffolding_struct("default")]
+/// #[derive(Clone, Debug, Scaffolding)]
+/// enum Account {
+///     Person { name: String },
+///     Company { name: String },
+/// }
+/// ```
+pub trait Scaffolding {
+    /// The type of the unique identifier, (`String` unless `id_type` is set)
+    type Id: ScaffoldingId;
+
+    /// This function adds a ActivityItem to the activity log
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
+    /// assert_eq!(entity.activity.len(), 1);
+    /// ```
+    fn log_activity(&mut self, name: String, descr: String);
+
+    /// This function adds a ActivityItem with a structured payload to the activity log
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.log_activity_with_data(
+    ///     "updated".to_string(),
+    ///     "The status was changed".to_string(),
+    ///     json!({"field": "status", "old": "open", "new": "closed"}),
+    /// );
+    /// assert_eq!(entity.activity[0].data.as_ref().unwrap()["old"], "open");
+    /// ```
+    fn log_activity_with_data(&mut self, name: String, descr: String, data: Value);
+
+    /// This function redacts the description and payload of all the ActivityItems that match the predicate
+    /// and returns the number of ActivityItems that were redacted.
+    /// The action and timestamp are preserved so the activity log keeps its record count.
+    /// A tamper-evident activity log is re-chained, (see `ActivityItem::rechain()`) and the redaction is logged as a `redacted` ActivityItem.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.log_activity("ordered".to_string(), "The customer has place the order".to_string());
+    /// entity.log_activity("contacted".to_string(), "Called the customer at 8482493561".to_string());
+    ///
+    /// let count = entity.redact_activity(&|a| a.description.contains("8482493561"));
+    ///
+    /// assert_eq!(count, 1);
+    /// assert_eq!(entity.activity.len(), 2);
+    /// assert_eq!(entity.get_activity("contacted".to_string())[0].description, defaults::redacted());
+    /// ```
+    fn redact_activity(&mut self, predicate: &dyn Fn(&ActivityItem) -> bool) -> usize;
+
+    /// This function turns the activity log into a tamper-evident (hash-chained) activity log.
+    /// A `chained` ActivityItem is logged, the existing ActivityItems that are not hashed yet are chained
+    /// and every ActivityItem logged afterwards is chained to the previous one.
+    /// It does nothing if the activity log is already tamper-evident, so earlier tampering is not hidden.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.log_activity("ordered".to_string(), "The customer has place the order".to_string());
+    /// entity.enable_activity_chain();
+    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
+    ///
+    /// assert_eq!(entity.activity.len(), 3);
+    /// assert!(entity.activity.iter().all(|a| a.hash.is_some()));
+    /// ```
+    fn enable_activity_chain(&mut self);
+
+    /// This function verifies that the tamper-evident activity log has not been modified.
+    /// Returns false if the activity log is excluded, is not tamper-evident or any ActivityItem was changed, removed or reordered.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.enable_activity_chain();
+    /// entity.log_activity("ordered".to_string(), "The customer has place the order".to_string());
+    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
+    /// assert!(entity.verify_activity_chain());
+    ///
+    /// entity.activity[0].description = "The customer never ordered".to_string();
+    /// assert!(!entity.verify_activity_chain());
+    /// ```
+    fn verify_activity_chain(&self) -> bool;
+
+    /// This function retrieves all the ActivityItems that have the specified action (name)
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.log_activity("ordered".to_string(), "The customer has place the order".to_string());
+    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
+    /// assert_eq!(entity.get_activity("cancelled".to_string()).len(), 1);
+    /// ```
+    fn get_activity(&self, name: String) -> Vec<ActivityItem>;
+
+    /// Same as `get_activity()` but borrows the ActivityItems instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.log_activity("ordered".to_string(), "The customer has place the order".to_string());
+    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
+    /// assert_eq!(entity.get_activity_ref("cancelled").len(), 1);
+    /// ```
+    fn get_activity_ref(&self, name: &str) -> Vec<&ActivityItem>;
+
+    /// This function exports the activity log as CSV or JSON Lines.
+    /// Only the ActivityItems created within the optional start and end timestamps (inclusive) are exported.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// entity.log_activity("ordered".to_string(), "The customer has place the order".to_string());
+    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
+    ///
+    /// let csv = entity.export_activity(ExportFormat::Csv, None, None);
+    /// assert_eq!(csv.lines().count(), 3);
+    ///
+    /// let jsonl = entity.export_activity(ExportFormat::JsonLines, Some(defaults::now()), None);
+    /// assert_eq!(jsonl.lines().count(), 2);
+    /// ```
+    fn export_activity(&self, format: ExportFormat, start: Option<i64>, end: Option<i64>)
+        -> String;
+
+    /// This function deactivates the entity by setting the `inactive_dtm` to now,
+    /// logs a `deactivated` activity and updates the `modified_dtm`.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.deactivate();
+    ///
+    /// assert_eq!(entity.inactive_dtm, defaults::now());
+    /// assert_eq!(entity.get_activity("deactivated".to_string()).len(), 1);
+    /// ```
+    fn deactivate(&mut self);
+
+    /// This function expires the entity by setting the `expired_dtm` to now,
+    /// logs an `expired` activity and updates the `modified_dtm`.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.expire_now();
+    ///
+    /// assert_eq!(entity.expired_dtm, defaults::now());
+    /// assert_eq!(entity.get_activity("expired".to_string()).len(), 1);
+    /// ```
+    fn expire_now(&mut self);
+
+    /// This function returns the unique identifier of the entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let entity = MyEntity::new();
+    ///
+    /// assert_eq!(entity.get_id(), entity.id);
+    /// ```
+    fn get_id(&self) -> Self::Id;
+
+    /// This function determines if the entity has been soft deleted, (see `ScaffoldingSoftDelete`).
+    /// Entities without the `deleted_dtm` attribute are never deleted.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("soft_delete")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingSoftDelete)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("soft_delete")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// assert!(!entity.is_deleted());
+    ///
+    /// entity.delete();
+    /// assert!(entity.is_deleted());
+    /// ```
+    fn is_deleted(&self) -> bool {
+        false
+    }
+
+    /// This function reactivates the entity by pushing the `inactive_dtm` and `expired_dtm` back into the future,
+    /// logs a `reactivated` activity and updates the `modified_dtm`.
+    /// An entity that has already expired is beyond recovery and returns a `ScaffoldingError::Recovery`.
+    ///
+    /// #Arguments
+    /// * inactive_days: i64 - The number of days from now until the entity becomes inactive.
+    /// * expire_days: i64 - The number of days from now until the entity expires.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.inactive_dtm = defaults::add_days(defaults::now(), -1);
+    ///
+    /// assert!(entity.reactivate(30, 90).is_ok());
+    /// assert_eq!(entity.inactive_dtm, defaults::add_days(defaults::now(), 30));
+    /// assert_eq!(entity.expired_dtm, defaults::add_days(defaults::now(), 90));
+    ///
+    /// entity.expired_dtm = defaults::add_days(defaults::now(), -1);
+    /// assert!(entity.reactivate(30, 90).is_err());
+    /// ```
+    fn reactivate(&mut self, inactive_days: i64, expire_days: i64) -> Result<(), ScaffoldingError>;
+
+    /// This function converts the entity to a serialize JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Serialize, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let entity = MyEntity::new();
+    /// let json_string = entity.serialize();
+    ///
+    /// println!("{}", json_string);
+    /// ```
+    fn serialize(&self) -> String
+    where
+        Self: Serialize + Sized,
+    {
+        serde_json::to_string(&self).unwrap()
+    }
+
+    /// This function returns a stable SHA-256 hash (hex encoded) of the serialized entity that can be used as an ETag.
+    /// The top level attributes listed in `exclude` (e.g.: volatile attributes like `modified_dtm` or `activity`) are not part of the hash.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Serialize, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let etag = entity.etag(&[]);
+    /// let without_activity = entity.etag(&["activity"]);
+    ///
+    /// entity.log_activity("viewed".to_string(), "The customer viewed their profile".to_string());
+    ///
+    /// assert_eq!(etag.len(), 64);
+    /// assert_ne!(entity.etag(&[]), etag);
+    /// assert_eq!(entity.etag(&["activity"]), without_activity);
+    /// ```
+    fn etag(&self, exclude: &[&str]) -> String
+    where
+        Self: Serialize + Sized,
+    {
+        let mut value = serde_json::to_value(self).unwrap();
+        if let Some(map) = value.as_object_mut() {
+            for attr in exclude.iter() {
+                map.remove(*attr);
+            }
+        }
+        format!("{:x}", Sha256::digest(canonical_json(&value).as_bytes()))
+    }
+
+    /// This function produces a detached ed25519 signature (hex encoded) over the canonical
+    /// serialization of the entity, (JSON with the keys sorted).
+    /// Requires the `signing` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Serialize, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let entity = MyEntity::new();
+    /// let key = SigningKey::from_bytes(&[7u8; 32]);
+    /// let signature = entity.sign(&key);
+    ///
+    /// assert_eq!(signature.len(), 128);
+    /// ```
+    #[cfg(feature = "signing")]
+    fn sign(&self, key: &SigningKey) -> String
+    where
+        Self: Serialize + Sized,
+    {
+        let signature = key.sign(canonical_json(self).as_bytes());
+        hex::encode(signature.to_bytes())
+    }
+
+    /// This function validates a detached ed25519 signature (hex encoded) against the canonical
+    /// serialization of the entity, (JSON with the keys sorted).
+    /// Requires the `signing` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let key = SigningKey::from_bytes(&[7u8; 32]);
+    /// let signature = entity.sign(&key);
+    ///
+    /// // the receiving service
+    /// let received = MyEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+    /// assert!(received.verify(&key.verifying_key(), &signature));
+    /// ```
+    #[cfg(feature = "signing")]
+    fn verify(&self, public_key: &VerifyingKey, signature: &str) -> bool
+    where
+        Self: Serialize + Sized,
+    {
+        let bytes = match hex::decode(signature) {
+            Ok(b) => b,
+            Err(_) => return false,
+        };
+        match Signature::from_slice(&bytes) {
+            Ok(sig) => public_key
+                .verify(canonical_json(self).as_bytes(), &sig)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+}
+
+// converts the entity to JSON with the keys sorted so that the output is stable
+fn canonical_json<T: Serialize + ?Sized>(entity: &T) -> String {
+    serde_json::to_value(entity).unwrap().to_string()
+}
+
+/// The addresses behavior of a Scaffolding object
+pub trait ScaffoldingAddresses {
+    /// Retrieves a related Address to the Entity based on the specified id.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.get_address(id).unwrap().category, "shipping".to_string());
+    /// ```    
+    fn get_address(&self, id: String) -> Option<&Address>;
+
+    /// Retrieves the Address of the category that was in effect at the timestamp.
+    /// If several are in effect, the primary one is preferred, then the one that took effect last.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let old = entity.insert_address(
+    ///     "home".to_string(),
+    ///     "Peter Petty".to_string(),
+    ///     "23 Corner Lane".to_string(),
+    ///     "Tiny Town, VT 04456".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// let new = entity.insert_address(
+    ///     "home".to_string(),
+    ///     "Peter Petty".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// // moved in the year 2020
+    /// entity.addresses.get_mut(&old).unwrap().valid_to = Some(1577836800);
+    /// entity.addresses.get_mut(&new).unwrap().valid_from = Some(1577836800);
+    ///
+    /// assert_eq!(entity.address_as_of(1500000000, "home".to_string()).unwrap().id, old);
+    /// assert_eq!(entity.address_as_of(1600000000, "home".to_string()).unwrap().id, new);
+    /// ```
+    fn address_as_of(&self, dtm: i64, category: String) -> Option<&Address>;
+
+    /// Retrieves the Address of the category that is currently in effect, (see `address_as_of()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let old = entity.insert_address(
+    ///     "home".to_string(),
+    ///     "Peter Petty".to_string(),
+    ///     "23 Corner Lane".to_string(),
+    ///     "Tiny Town, VT 04456".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// let new = entity.insert_address(
+    ///     "home".to_string(),
+    ///     "Peter Petty".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// // moved in the year 2020
+    /// entity.addresses.get_mut(&old).unwrap().valid_to = Some(1577836800);
+    /// entity.addresses.get_mut(&new).unwrap().valid_from = Some(1577836800);
+    ///
+    /// assert_eq!(entity.current_address("home".to_string()).unwrap().id, new);
+    /// ```
+    fn current_address(&self, category: String) -> Option<&Address>;
+
+    /// Retrieves the primary Address of the category, (see `set_primary_address()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let home = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// let office = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "16 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// assert!(entity.get_primary_address("shipping".to_string()).is_none());
+    ///
+    /// entity.set_primary_address(office.clone());
+    /// assert_eq!(entity.get_primary_address("shipping".to_string()).unwrap().id, office);
+    /// ```
+    fn get_primary_address(&self, category: String) -> Option<&Address>;
+
+    /// Makes the Address the primary one of its category, (any other Address of the category is no longer primary).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let home = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// let office = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "16 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// entity.set_primary_address(home.clone());
+    /// entity.set_primary_address(office.clone());
+    ///
+    /// assert_eq!(entity.get_address(home).unwrap().primary, false);
+    /// assert_eq!(entity.get_address(office).unwrap().primary, true);
+    /// ```
+    fn set_primary_address(&mut self, id: String);
+
+    /// Sets the latitude and longitude of all the Addresses using the geocoder
+    /// and returns the ids of the Addresses that could not be geocoded.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    /// use scaffolding_core::errors::ScaffoldingError;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// struct FixedGeocoder;
+    ///
+    /// impl Geocoder for FixedGeocoder {
+    ///     fn geocode(&self, _address: &Address) -> Result<(f64, f64), ScaffoldingError> {
+    ///         Ok((40.7128, -74.006))
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.geocode_addresses(&FixedGeocoder).len(), 0);
+    /// assert_eq!(entity.get_address(id).unwrap().latitude, Some(40.7128));
+    /// ```
+    fn geocode_addresses(&mut self, geocoder: &dyn Geocoder) -> Vec<String>;
+
+    /// Insert or updates a related Address to the Entity and returns the id of the Address.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.addresses.len(), 1);
+    /// ```
+    fn insert_address(
+        &mut self,
+        category: String,
+        line_1: String,
+        line_2: String,
+        line_3: String,
+        line_4: String,
+        country_code: String,
+    ) -> String;
+
+    /// Insert or updates a related Address to the Entity and returns the id of the Address for reference.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// entity.modify_address(
+    ///     id.clone(),
+    ///     "billing".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),);
+    ///
+    /// assert_eq!(entity.get_address(id).unwrap().category, "billing".to_string());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    fn modify_address(
+        &mut self,
+        id: String,
+        category: String,
+        line_1: String,
+        line_2: String,
+        line_3: String,
+        line_4: String,
+        country_code: String,
+    );
+
+    /// Retrieves all the Addresses with the specified category.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let address = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_addresses_by_category("shipping".to_string()).len(), 1);
+    /// ```
+    fn search_addresses_by_category(&self, category: String) -> Vec<Address>;
+
+    /// Same as `search_addresses_by_category()` but borrows the Addresses instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let address = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_addresses_by_category_ref("shipping").len(), 1);
+    /// ```
+    fn search_addresses_by_category_ref(&self, category: &str) -> Vec<&Address>;
+
+    /// Retrieves all the Addresses in the country, (Alpha 3 code, ignoring case).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "10 Downing Street".to_string(),
+    ///     "London, SW1A 2AA".to_string(),
+    ///     "United Kingdom".to_string(),
+    ///     "GBR".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_addresses_by_country("gbr".to_string()).len(), 1);
+    /// assert_eq!(entity.search_addresses_by_country("USA".to_string()).len(), 0);
+    /// ```
+    fn search_addresses_by_country(&self, iso_3_code: String) -> Vec<Address>;
+
+    /// Retrieves all the Addresses in the postal code, (ignoring case and spaces).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "10 Downing Street".to_string(),
+    ///     "London, SW1A 2AA".to_string(),
+    ///     "United Kingdom".to_string(),
+    ///     "GBR".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_addresses_by_postal_code("sw1a2aa".to_string()).len(), 1);
+    /// ```
+    fn search_addresses_by_postal_code(&self, postal_code: String) -> Vec<Address>;
+
+    /// Retrieves all the Addresses in the region, (province, state, or county), ignoring case.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_addresses_by_region("ny".to_string()).len(), 1);
+    /// ```
+    fn search_addresses_by_region(&self, region: String) -> Vec<Address>;
+
+    /// Retrieves the Addresses within the radius (km) of the center, (latitude, longitude),
+    /// ordered by distance with the nearest first. Addresses without coordinates are ignored.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_address(
+    ///     "warehouse".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "10 Downing Street".to_string(),
+    ///     "London, SW1A 2AA".to_string(),
+    ///     "United Kingdom".to_string(),
+    ///     "GBR".to_string(),
+    /// );
+    /// let address = entity.addresses.get_mut(&id).unwrap();
+    /// address.latitude = Some(51.5034);
+    /// address.longitude = Some(-0.1276);
+    ///
+    /// // Paris
+    /// assert_eq!(entity.search_addresses_within((48.8566, 2.3522), 500.0).len(), 1);
+    /// assert_eq!(entity.search_addresses_within((48.8566, 2.3522), 100.0).len(), 0);
+    /// ```
+    fn search_addresses_within(&self, center: (f64, f64), radius_km: f64) -> Vec<Address>;
+
+    /// Verifies the Address with the provider, stores the result in the `verification` of the Address
+    /// and logs the activity. Returns `ScaffoldingError::NotFound` if there is no Address with the id.
+    /// Requires the `verification` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    /// use scaffolding_core::errors::ScaffoldingError;
+    /// use std::future::Future;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// struct AlwaysDeliverable;
+    ///
+    /// impl AddressVerifier for AlwaysDeliverable {
+    ///     async fn verify(&self, _address: &Address) -> Result<AddressVerification, ScaffoldingError> {
+    ///         Ok(AddressVerification::new(true, None, 1.0))
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 03884".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// // use the executor of the application, (e.g.: tokio)
+    /// let mut future = std::pin::pin!(entity.verify_address(id.clone(), &AlwaysDeliverable));
+    /// let verification = match future.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+    ///     Poll::Ready(result) => result.unwrap(),
+    ///     Poll::Pending => panic!("the verifier is not ready"),
+    /// };
+    ///
+    /// assert!(verification.deliverable);
+    /// ```
+    #[cfg(feature = "verification")]
+    fn verify_address<V>(
+        &mut self,
+        id: String,
+        verifier: &V,
+    ) -> impl std::future::Future<Output = Result<AddressVerification, ScaffoldingError>> + Send
+    where
+        Self: Send + Sized,
+        V: AddressVerifier + Sync;
+
+    /// Removes a related Address to the Entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_address(
+    ///     "shipping".to_string(),
+    ///     "acmes company".to_string(),
+    ///     "14 Main Street".to_string(),
+    ///     "Big City, NY 038845".to_string(),
+    ///     "USA".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// assert_eq!(entity.addresses.len(), 1);
+    ///
+    /// entity.remove_address(id);
+    /// assert_eq!(entity.addresses.len(), 0);
+    /// ```
+    fn remove_address(&mut self, id: String);
+}
+
+/// The attachments behavior of a Scaffolding object, (binary files such as documents and images)
+///
+/// The struct must have the `attachments` attribute, otherwise the derive reports which option is missing.
+///
+/// ```compile_fail
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// // error: #[derive(ScaffoldingAttachments)] requires the `attachments` option
+/// #[scaffolding_struct]
+/// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+/// struct MyEntity {}
+/// ```
+pub trait ScaffoldingAttachments {
+    /// Retrieves a related Attachment based on the specific id.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("attachments")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("attachments")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_attachment(
+    ///     "invoice.pdf".to_string(),
+    ///     "application/pdf".to_string(),
+    ///     vec![37, 80, 68, 70],
+    /// );
+    ///
+    /// assert_eq!(entity.get_attachment(id).unwrap().filename, "invoice.pdf".to_string());
+    /// ```
+    fn get_attachment(&self, id: String) -> Option<&Attachment>;
+
+    /// Inserts a related Attachment whose content is stored with the object.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("attachments")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("attachments")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_attachment(
+    ///     "invoice.pdf".to_string(),
+    ///     "application/pdf".to_string(),
+    ///     vec![37, 80, 68, 70],
+    /// );
+    ///
+    /// assert_eq!(entity.attachments.len(), 1);
+    /// ```
+    fn insert_attachment(&mut self, filename: String, mime_type: String, bytes: Vec<u8>) -> String;
+
+    /// Inserts a related Attachment whose content is stored elsewhere, (e.g.: in a document store).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("attachments")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("attachments")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_attachment_locator(
+    ///     "photo.png".to_string(),
+    ///     "image/png".to_string(),
+    ///     "s3://documents/photo.png".to_string(),
+    /// );
+    ///
+    /// assert!(entity.get_attachment(id).unwrap().bytes.is_none());
+    /// ```
+    fn insert_attachment_locator(
+        &mut self,
+        filename: String,
+        mime_type: String,
+        locator: String,
+    ) -> String;
+
+    /// Retrieves all the Attachment whose filename contains the search text, (case insensitive).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("attachments")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("attachments")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_attachment(
+    ///     "Invoice-2024.pdf".to_string(),
+    ///     "application/pdf".to_string(),
+    ///     Vec::new(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_attachments_by_filename("invoice".to_string()).len(), 1);
+    /// ```
+    fn search_attachments_by_filename(&self, search: String) -> Vec<Attachment>;
+
+    /// Retrieves all the Attachment with the content type, (e.g.: application/pdf or image/*).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("attachments")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("attachments")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_attachment(
+    ///     "photo.png".to_string(),
+    ///     "image/png".to_string(),
+    ///     Vec::new(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_attachments_by_mime_type("image/*".to_string()).len(), 1);
+    /// assert_eq!(entity.search_attachments_by_mime_type("application/pdf".to_string()).len(), 0);
+    /// ```
+    fn search_attachments_by_mime_type(&self, mime_type: String) -> Vec<Attachment>;
+
+    /// Same as `search_attachments_by_mime_type()` but borrows the Attachments instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("attachments")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("attachments")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_attachment(
+    ///     "photo.png".to_string(),
+    ///     "image/png".to_string(),
+    ///     Vec::new(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_attachments_by_mime_type_ref("image/*").len(), 1);
+    /// assert_eq!(entity.search_attachments_by_mime_type_ref("application/pdf").len(), 0);
+    /// ```
+    fn search_attachments_by_mime_type_ref(&self, mime_type: &str) -> Vec<&Attachment>;
+
+    /// Removes a related Attachment from the Entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("attachments")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAttachments)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("attachments")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_attachment(
+    ///     "photo.png".to_string(),
+    ///     "image/png".to_string(),
+    ///     Vec::new(),
+    /// );
+    /// entity.remove_attachment(id);
+    ///
+    /// assert_eq!(entity.attachments.len(), 0);
+    /// ```
+    fn remove_attachment(&mut self, id: String);
+}
+
+/// The conversion behavior of a Scaffolding object
+///
+/// The scaffolding attributes, (e.g.: id, timestamps, activity, tags) can be carried over from any other
+/// Scaffolding object with the same type of id, so its identity and history are preserved.
+pub trait ScaffoldingConvert: Scaffolding {
+    /// This function returns a copy of the scaffolding attributes of the object.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingConvert)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let entity = MyEntity::new();
+    /// let core = entity.core_attributes();
+    ///
+    /// assert_eq!(core.id, entity.id);
+    /// assert_eq!(core.tags, Some(Vec::new()));
+    /// assert!(core.notes.is_none());
+    /// ```
+    fn core_attributes(&self) -> CoreAttributes<Self::Id>;
+
+    /// This function replaces the scaffolding attributes of the object.
+    /// Only the attributes that both the object and the `CoreAttributes` have are replaced.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingConvert)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let mut core = entity.core_attributes();
+    /// core.id = "b4d6c6db-7468-400a-8536-a5e83b1f2bdc".to_string();
+    /// entity.set_core_attributes(core);
+    ///
+    /// assert_eq!(entity.id, "b4d6c6db-7468-400a-8536-a5e83b1f2bdc".to_string());
+    /// ```
+    fn set_core_attributes(&mut self, core: CoreAttributes<Self::Id>);
+
+    /// This function copies the scaffolding attributes from the other object,
+    /// (e.g.: when promoting a Prospect into a Customer).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingConvert, ScaffoldingTags)]
+    /// struct Prospect {}
+    ///
+    /// impl Prospect {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingConvert)]
+    /// struct Customer {
+    ///     account: String,
+    /// }
+    ///
+    /// impl Customer {
+    ///     #[scaffolding_fn("tags")]
+    ///     fn new(account: &str) -> Self {
+    ///         Self {
+    ///             account: account.to_string(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut prospect = Prospect::new();
+    /// prospect.add_tag("vip".to_string());
+    ///
+    /// let mut customer = Customer::new("ACC-100");
+    /// customer.adopt_core(&prospect);
+    ///
+    /// assert_eq!(customer.id, prospect.id);
+    /// assert_eq!(customer.tags, vec!["vip".to_string()]);
+    /// assert_eq!(customer.account, "ACC-100".to_string());
+    /// ```
+    fn adopt_core<O>(&mut self, other: &O)
+    where
+        O: ScaffoldingConvert<Id = Self::Id>,
+    {
+        self.set_core_attributes(other.core_attributes());
+    }
+
+    /// This function creates a new object, (using `Default` for the other attributes),
+    /// with the scaffolding attributes of the other object.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingConvert)]
+    /// struct Prospect {}
+    ///
+    /// impl Prospect {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// #[scaffolding_struct("default")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingConvert)]
+    /// struct Customer {
+    ///     account: String,
+    /// }
+    ///
+    /// let prospect = Prospect::new();
+    /// let customer = Customer::from_scaffolding(&prospect);
+    ///
+    /// assert_eq!(customer.id, prospect.id);
+    /// assert_eq!(customer.created_dtm, prospect.created_dtm);
+    /// ```
+    fn from_scaffolding<O>(other: &O) -> Self
+    where
+        Self: Default + Sized,
+        O: ScaffoldingConvert<Id = Self::Id>,
+    {
+        let mut object = Self::default();
+        object.adopt_core(other);
+        object
+    }
+}
+
+/// The deserialization behavior of a Scaffolding object
+///
+/// It is kept out of the `Scaffolding` trait so that `Scaffolding` can be used as a trait object,
+/// and it is implemented for every Scaffolding object that implements `Deserialize`.
+pub trait ScaffoldingDeserialize: Scaffolding + DeserializeOwned {
+    /// This function instantiates an entity from a JSON string.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct]
+    /// #[derive(Clone, Debug, Deserialize, Scaffolding)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let json = r#"{
+    ///     "id":"b4d6c6db-7468-400a-8536-a5e83b1f2bdc",
+    ///     "created_dtm":1711802687,
+    ///     "modified_dtm":1711802687,
+    ///     "inactive_dtm":1719578687,
+    ///     "expired_dtm":1806410687,
+    ///     "activity":[
+    ///         {
+    ///             "created_dtm":1711802687,
+    ///             "action":"updated",
+    ///             "description":"The object has been updated"
+    ///         },
+    ///         {
+    ///             "created_dtm":1711802687,
+    ///             "action":"updated",
+    ///             "description":"The object has been updated"
+    ///         },
+    ///         {
+    ///             "created_dtm":1711802687,
+    ///             "action":"cancelled",
+    ///             "description":"The object has been cancelled"
+    ///         }
+    ///         ]
+    ///     }"#;
+    /// let deserialized = MyEntity::deserialized(json.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(deserialized.id, "b4d6c6db-7468-400a-8536-a5e83b1f2bdc");
+    /// assert_eq!(deserialized.activity.len(), 3);  
+    ///
+    /// ```
+    fn deserialized(serialized: &[u8]) -> Result<Self, ScaffoldingError> {
+        match serde_json::from_slice::<Self>(serialized) {
+            Ok(item) => Ok(item),
+            Err(err) => Err(ScaffoldingError::Deserialize {
+                source: err,
+                type_name: std::any::type_name::<Self>().to_string(),
+            }),
+        }
+    }
+}
+
+impl<T> ScaffoldingDeserialize for T where T: Scaffolding + DeserializeOwned {}
+
+/// The email address behavior of a Scaffolding object
+pub trait ScaffoldingEmailAddresses {
+    /// Retrieves a related EmailAddress based on the specific id.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_email_address(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.get_email_address(id).unwrap().address, "myemail@example.com".to_string());
+    /// ```
+    fn get_email_address(&self, id: String) -> Option<&EmailAddress>;
+
+    /// Retrieves the primary EmailAddress of the category, (see `set_primary_email_address()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let home = entity.insert_email_address(
+    ///     "work".to_string(),
+    ///     "me@example.com".to_string(),
+    /// );
+    /// let office = entity.insert_email_address(
+    ///     "work".to_string(),
+    ///     "sales@example.com".to_string(),
+    /// );
+    /// assert!(entity.get_primary_email_address("work".to_string()).is_none());
+    ///
+    /// entity.set_primary_email_address(office.clone());
+    /// assert_eq!(entity.get_primary_email_address("work".to_string()).unwrap().id, office);
+    /// ```
+    fn get_primary_email_address(&self, category: String) -> Option<&EmailAddress>;
+
+    /// Makes the EmailAddress the primary one of its category, (any other EmailAddress of the category is no longer primary).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let home = entity.insert_email_address(
+    ///     "work".to_string(),
+    ///     "me@example.com".to_string(),
+    /// );
+    /// let office = entity.insert_email_address(
+    ///     "work".to_string(),
+    ///     "sales@example.com".to_string(),
+    /// );
+    /// entity.set_primary_email_address(home.clone());
+    /// entity.set_primary_email_address(office.clone());
+    ///
+    /// assert_eq!(entity.get_email_address(home).unwrap().primary, false);
+    /// assert_eq!(entity.get_email_address(office).unwrap().primary, true);
+    /// ```
+    fn set_primary_email_address(&mut self, id: String);
+
+    /// Adds a related PhoneNumber to the Entity and returns the id for reference.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_email_address(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.email_addresses.len(), 1);
+    /// ```
+    fn insert_email_address(&mut self, category: String, address: String) -> String;
+
+    /// Adds a related EmailAddress to the Entity unless the Entity already has it, (comparing the canonical form,
+    /// see `EmailAddress::canonical()`)
+    /// and returns the id of the EmailAddress for reference.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_email_address_unique(
+    ///     "home".to_string(),
+    ///     "foo@example.com".to_string(),
+    /// );
+    /// let again = entity.insert_email_address_unique(
+    ///     "work".to_string(),
+    ///     " Foo@Example.com".to_string(),
+    /// );
+    /// let alias = entity.insert_email_address_unique(
+    ///     "home".to_string(),
+    ///     "john.doe+news@gmail.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(again, id);
+    /// assert_eq!(entity.insert_email_address_unique("work".to_string(), "johndoe@gmail.com".to_string()), alias);
+    /// assert_eq!(entity.email_addresses.len(), 2);
+    /// ```
+    fn insert_email_address_unique(&mut self, category: String, address: String) -> String;
+
+    /// Checks if mail can be delivered to the EmailAddress, (see `EmailAddress::verify_deliverability()`),
+    /// stores the result in the `deliverability` of the EmailAddress and logs the `verified` activity with the result.
+    /// Returns `ScaffoldingError::NotFound` if there is no EmailAddress with the id.
+    /// Requires the `deliverability` feature and a tokio runtime.
+    ///
+    /// #Example
+    ///
+    /// ```rust,no_run
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// async fn sign_up() {
+    ///     let mut entity = MyEntity::new();
+    ///     let id = entity.insert_email_address(
+    ///         "login".to_string(),
+    ///         "myemail@example.com".to_string(),
+    ///     );
+    ///     let result = entity.verify_email_address(id, true).await.unwrap();
+    ///
+    ///     assert_eq!(entity.activity.len(), 1);
+    /// }
+    /// ```
+    #[cfg(feature = "deliverability")]
+    fn verify_email_address(
+        &mut self,
+        id: String,
+        probe: bool,
+    ) -> impl std::future::Future<Output = Result<EmailDeliverability, ScaffoldingError>> + Send
+    where
+        Self: Send + Sized;
+
+    /// Retrieves all the EmailAddress with the specified category.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_email_address(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_email_addresses_by_category("home".to_string()).len(), 1);
+    /// ```
+    fn search_email_addresses_by_category(&self, category: String) -> Vec<EmailAddress>;
+
+    /// Same as `search_email_addresses_by_category()` but borrows the EmailAddresses instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_email_address(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_email_addresses_by_category_ref("home").len(), 1);
+    /// ```
+    fn search_email_addresses_by_category_ref(&self, category: &str) -> Vec<&EmailAddress>;
+
+    /// Retrieves all the EmailAddress at the domain, (ignoring case).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_email_address(
+    ///     "work".to_string(),
+    ///     "me@Example.com".to_string(),
+    /// );
+    /// let _ = entity.insert_email_address(
+    ///     "home".to_string(),
+    ///     "me@gmail.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_email_addresses_by_domain("example.com".to_string()).len(), 1);
+    /// ```
+    fn search_email_addresses_by_domain(&self, domain: String) -> Vec<EmailAddress>;
+
+    /// Removes a related EmailAddress to the Entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_email_address(
+    ///     "home".to_string(),
+    ///     "myemail@example.com".to_string(),
+    /// );
+    /// assert_eq!(entity.email_addresses.len(), 1);
+    ///
+    /// entity.remove_email_address(id);
+    /// assert_eq!(entity.email_addresses.len(), 0);
+    /// ```
+    fn remove_email_address(&mut self, id: String);
+}
+
+/// The duplicate detection behavior of a Scaffolding object
+///
+/// The email addresses, phone numbers, addresses and name metadata, (metadata keys containing `name`),
+/// of the entity are normalized and compared. See `EntityCollection::find_duplicates()`.
+/// The personal data that has been erased, (see `ScaffoldingErasure`) is left out.
+pub trait ScaffoldingDuplicates {
+    /// This function returns the normalized values of the entity grouped by the compared attribute,
+    /// (e.g.: "email_addresses", "phone_numbers", "addresses", "name").
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses", "metadata", "phone_numbers")]
+    /// #[derive(
+    ///     Clone,
+    ///     Debug,
+    ///     Scaffolding,
+    ///     ScaffoldingDuplicates,
+    ///     ScaffoldingEmailAddresses,
+    ///     ScaffoldingPhoneNumbers,
+    /// )]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses", "metadata", "phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// fn get_entity(name: &str, phone: &str) -> MyEntity {
+    ///     let mut entity = MyEntity::new();
+    ///     entity.metadata.insert("name".to_string(), name.to_string());
+    ///     entity.insert_phone_number("home".to_string(), phone.to_string(), "USA".to_string());
+    ///     entity
+    /// }
+    ///
+    /// let entity = get_entity("Frank Smith", "(848) 249-3561");
+    /// let keys = entity.duplicate_keys();
+    ///
+    /// assert_eq!(keys.get("name").unwrap(), &vec!["frank smith".to_string()]);
+    /// assert_eq!(keys.get("phone_numbers").unwrap(), &vec!["8482493561".to_string()]);
+    /// ```
+    fn duplicate_keys(&self) -> BTreeMap<String, Vec<String>>;
+
+    /// This function calculates the confidence, (0.0 - 1.0), that the other entity is a duplicate.
+    /// The score is the average similarity of the attributes that both entities have values for.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses", "metadata", "phone_numbers")]
+    /// #[derive(
+    ///     Clone,
+    ///     Debug,
+    ///     Scaffolding,
+    ///     ScaffoldingDuplicates,
+    ///     ScaffoldingEmailAddresses,
+    ///     ScaffoldingPhoneNumbers,
+    /// )]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses", "metadata", "phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// fn get_entity(name: &str, phone: &str) -> MyEntity {
+    ///     let mut entity = MyEntity::new();
+    ///     entity.metadata.insert("name".to_string(), name.to_string());
+    ///     entity.insert_phone_number("home".to_string(), phone.to_string(), "USA".to_string());
+    ///     entity
+    /// }
+    ///
+    /// let entity = get_entity("Frank Smith", "(848) 249-3561");
+    ///
+    /// assert_eq!(entity.duplicate_score(&get_entity("frank  SMITH", "848.249.3561")), 1.0);
+    /// assert_eq!(entity.duplicate_score(&get_entity("Frank Smith", "555-555-5555")), 0.5);
+    /// ```
+    fn duplicate_score(&self, other: &Self) -> f64
+    where
+        Self: Sized,
+    {
+        let left = self.duplicate_keys();
+        let right = other.duplicate_keys();
+        let scores: Vec<f64> = left
+            .iter()
+            .filter_map(|(attr, values)| match right.get(attr) {
+                Some(others) => DuplicateCandidate::similarity(values, others),
+                None => None,
+            })
+            .collect();
+
+        match scores.is_empty() {
+            true => 0.0,
+            false => scores.iter().sum::<f64>() / scores.len() as f64,
+        }
+    }
+}
+
+/// The personal data erasure (right to be forgotten) behavior of a Scaffolding object
+pub trait ScaffoldingErasure {
+    /// Erases the personal data of the Entity by turning the addresses, email addresses, phone numbers and notes
+    /// into tombstones, (the records are kept but their content is replaced with the redaction marker),
+    /// and replacing the values of the specified identifying metadata.
+    /// The descriptions and payloads of the existing activity are redacted, (see `redact_activity()`),
+    /// so the old values they carry are erased as well.
+    /// An `erased` activity is logged, while the id and lifecycle timestamps are kept for referential integrity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses", "metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses, ScaffoldingErasure)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses", "metadata")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let email = entity.insert_email_address("home".to_string(), "myemail@example.com".to_string());
+    /// entity.metadata.insert("ssn".to_string(), "123-45-6789".to_string());
+    /// entity.metadata.insert("segment".to_string(), "retail".to_string());
+    ///
+    /// entity.erase_personal_data(vec!["ssn".to_string()]);
+    ///
+    /// assert_eq!(entity.get_email_address(email).unwrap().address, defaults::redacted());
+    /// assert_eq!(entity.metadata.get("ssn").unwrap(), &defaults::redacted());
+    /// assert_eq!(entity.metadata.get("segment").unwrap(), "retail");
+    /// assert_eq!(entity.get_activity("erased".to_string()).len(), 1);
+    /// ```
+    fn erase_personal_data(&mut self, metadata_keys: Vec<String>);
+}
+
+/// The masking behavior of a Scaffolding object
+pub trait ScaffoldingMasking {
+    /// Returns a copy of the Entity with the addresses, email addresses, phone numbers and note contents
+    /// masked according to the masking policy. Only the personal data the Entity has are masked.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("email_addresses", "phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses, ScaffoldingMasking, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("email_addresses", "phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let email = entity.insert_email_address("home".to_string(), "myemail@example.com".to_string());
+    /// let phone = entity.insert_phone_number("home".to_string(), "8482493561".to_string(), "USA".to_string());
+    ///
+    /// let masked = entity.mask(&MaskPolicy::new(MaskStyle::Partial));
+    ///
+    /// assert_eq!(masked.get_email_address(email.clone()).unwrap().address, "m****@example.com".to_string());
+    /// assert_eq!(masked.get_phone_number(phone).unwrap().number, "******3561".to_string());
+    /// // the original is untouched
+    /// assert_eq!(entity.get_email_address(email).unwrap().address, "myemail@example.com".to_string());
+    /// ```
+    fn mask(&self, policy: &MaskPolicy) -> Self;
+}
+
+/// The metadata behavior of a Scaffolding object
+///
+/// The changes made using the trait update the `modified_dtm` and are logged as `metadata_changed` activity,
+/// (with the key, but not the values, since metadata can hold personal data).
+/// The struct must have the `metadata` attribute, otherwise the derive reports which option is missing.
+/// The schema of the metadata can be declared using the `scaffolding_metadata` attribute,
+/// (e.g.: `#[scaffolding_metadata("source: required", "seats: integer")]`, see `MetadataSchema::from_rules()`).
+///
+/// ```compile_fail
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// // error: #[derive(ScaffoldingMetadata)] requires the `metadata` option
+/// #[scaffolding_struct]
+/// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+/// struct MyEntity {}
+/// ```
+pub trait ScaffoldingMetadata {
+    /// Retrieves the value of the metadata key.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+    ///
+    /// assert_eq!(entity.get_metadata("source".to_string()), Some(&"import-2024".to_string()));
+    /// assert_eq!(entity.get_metadata("missing".to_string()), None);
+    /// ```
+    fn get_metadata(&self, key: String) -> Option<&String>;
+
+    /// Inserts the metadata key or updates its value, (nothing changes if the key already has the value).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+    ///
+    /// assert_eq!(entity.metadata.len(), 1);
+    /// assert_eq!(entity.activity[0].action, "metadata_changed".to_string());
+    /// ```
+    fn upsert_metadata(&mut self, key: String, value: String);
+
+    /// Removes the metadata key and returns its value, (None if the object doesn't have the key).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+    ///
+    /// assert_eq!(entity.remove_metadata("source".to_string()), Some("import-2024".to_string()));
+    /// assert_eq!(entity.remove_metadata("source".to_string()), None);
+    /// ```
+    fn remove_metadata(&mut self, key: String) -> Option<String>;
+
+    /// Returns the schema of the metadata, (empty if the struct doesn't declare one).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    /// #[scaffolding_metadata("source: required", "tier: bronze|silver|gold")]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let entity = MyEntity::new();
+    ///
+    /// assert!(entity.metadata_schema().keys.get("source").unwrap().required);
+    /// ```
+    fn metadata_schema(&self) -> MetadataSchema;
+
+    /// Validates the metadata against the schema and returns the violations, (empty if the metadata is valid).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    /// #[scaffolding_metadata("source: required", "tier: bronze|silver|gold")]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata("tier".to_string(), "platinum".to_string());
+    ///
+    /// assert_eq!(
+    ///     entity.validate_metadata(),
+    ///     vec![
+    ///         MetadataViolation::Missing("source".to_string()),
+    ///         MetadataViolation::NotAllowed("tier".to_string(), "platinum".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    fn validate_metadata(&self) -> Vec<MetadataViolation>;
+
+    /// Retrieves the metadata with a key that matches the pattern and a value that contains the text, (case insensitive).
+    /// In the pattern `*` matches any characters and `?` matches a single character, (e.g.: `source_*`)
+    /// and an empty text matches any value.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+    /// entity.upsert_metadata("source_system".to_string(), "CRM".to_string());
+    /// entity.upsert_metadata("region".to_string(), "emea".to_string());
+    ///
+    /// assert_eq!(entity.search_metadata("source*".to_string(), String::new()).len(), 2);
+    /// assert_eq!(entity.search_metadata("*".to_string(), "crm".to_string()).len(), 1);
+    /// assert_eq!(entity.search_metadata("regio?".to_string(), "apac".to_string()).len(), 0);
+    /// ```
+    fn search_metadata(&self, key_glob: String, value_contains: String)
+        -> BTreeMap<String, String>;
+}
+
+/// The JSON metadata behavior of a Scaffolding object, (nested values that don't need to be encoded as strings)
+///
+/// The changes made using the trait update the `modified_dtm` and are logged as `metadata_changed` activity,
+/// (with the key, but not the values, since metadata can hold personal data).
+/// The struct must have the `metadata_json` attribute, otherwise the derive reports which option is missing.
+///
+/// ```compile_fail
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// // error: #[derive(ScaffoldingMetadataJson)] requires the `metadata_json` option
+/// #[scaffolding_struct("metadata")]
+/// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadataJson)]
+/// struct MyEntity {}
+/// ```
+pub trait ScaffoldingMetadataJson {
+    /// Retrieves the JSON value of the metadata key.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata_json")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadataJson)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata_json")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata_json("billing".to_string(), json!({"plan": "pro"}));
+    ///
+    /// assert_eq!(entity.get_metadata_json("billing".to_string()), Some(&json!({"plan": "pro"})));
+    /// assert_eq!(entity.get_metadata_json("missing".to_string()), None);
+    /// ```
+    fn get_metadata_json(&self, key: String) -> Option<&Value>;
+
+    /// Retrieves the nested JSON value using a path of keys separated by `.`, (the first key is the metadata key
+    /// and array elements are addressed by their index, e.g.: `billing.invoices.0`).
+    /// Returns None if any part of the path doesn't exist.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata_json")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadataJson)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata_json")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata_json(
+    ///     "billing".to_string(),
+    ///     json!({"plan": {"tier": "gold"}, "invoices": ["inv-1", "inv-2"]}),
+    /// );
+    ///
+    /// assert_eq!(entity.get_metadata_path("billing.plan.tier".to_string()), Some(&json!("gold")));
+    /// assert_eq!(entity.get_metadata_path("billing.invoices.1".to_string()), Some(&json!("inv-2")));
+    /// assert_eq!(entity.get_metadata_path("billing.plan.seats".to_string()), None);
+    /// ```
+    fn get_metadata_path(&self, path: String) -> Option<&Value>;
+
+    /// Inserts the metadata key or updates its JSON value, (nothing changes if the key already has the value).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata_json")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadataJson)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata_json")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata_json("billing".to_string(), json!({"plan": "pro"}));
+    ///
+    /// assert_eq!(entity.metadata_json.len(), 1);
+    /// assert_eq!(entity.activity[0].action, "metadata_changed".to_string());
+    /// ```
+    fn upsert_metadata_json(&mut self, key: String, value: Value);
+
+    /// Removes the metadata key and returns its JSON value, (None if the object doesn't have the key).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("metadata_json")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadataJson)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("metadata_json")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.upsert_metadata_json("billing".to_string(), json!({"plan": "pro"}));
+    ///
+    /// assert_eq!(entity.remove_metadata_json("billing".to_string()), Some(json!({"plan": "pro"})));
+    /// assert_eq!(entity.remove_metadata_json("billing".to_string()), None);
+    /// ```
+    fn remove_metadata_json(&mut self, key: String) -> Option<Value>;
+}
+
+/// The ownership and sharing behavior of a Scaffolding object
+pub trait ScaffoldingOwnership {
+    /// This function determines if the principal can read the object.
+    /// The owner and any principal the object is shared with can read it, (an empty principal never can).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("ownership")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingOwnership)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("ownership")]
+    ///     fn new() -> Self {
+    ///         Self {
+    ///             owner: "fsmith".to_string(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.share("jdoe".to_string(), Permission::Read);
+    ///
+    /// assert!(entity.can_read("fsmith".to_string()));
+    /// assert!(entity.can_read("jdoe".to_string()));
+    /// assert!(!entity.can_read("bjones".to_string()));
+    /// ```
+    fn can_read(&self, principal: String) -> bool;
+
+    /// This function determines if the principal can modify the object.
+    /// The owner and any principal the object is shared with using `Permission::Write` can modify it,
+    /// (an empty principal never can).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("ownership")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingOwnership)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("ownership")]
+    ///     fn new() -> Self {
+    ///         Self {
+    ///             owner: "fsmith".to_string(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.share("jdoe".to_string(), Permission::Read);
+    /// entity.share("bjones".to_string(), Permission::Write);
+    ///
+    /// assert!(entity.can_write("fsmith".to_string()));
+    /// assert!(!entity.can_write("jdoe".to_string()));
+    /// assert!(entity.can_write("bjones".to_string()));
+    /// ```
+    fn can_write(&self, principal: String) -> bool;
+
+    /// This function shares the object with the principal and logs the `shared` activity.
+    /// If the object is already shared with the principal, the permission is replaced.
+    /// An empty principal is ignored.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("ownership")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingOwnership)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("ownership")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.share("jdoe".to_string(), Permission::Read);
+    /// entity.share("jdoe".to_string(), Permission::Write);
+    ///
+    /// assert_eq!(entity.shared_with, vec![("jdoe".to_string(), Permission::Write)]);
+    /// ```
+    fn share(&mut self, principal: String, permission: Permission);
+
+    /// This function stops sharing the object with the principal and logs the `unshared` activity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("ownership")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingOwnership)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("ownership")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// entity.share("jdoe".to_string(), Permission::Read);
+    /// entity.unshare("jdoe".to_string());
+    ///
+    /// assert!(!entity.can_read("jdoe".to_string()));
+    /// ```
+    fn unshare(&mut self, principal: String);
+}
+
+/// The notes behavior of a Scaffolding object
+///
+/// The struct must have the `notes` attribute, otherwise the derive reports which option is missing.
+///
+/// ```compile_fail
+/// extern crate scaffolding_core;
+///
+/// use scaffolding_core::*;
+///
+/// // error: #[derive(ScaffoldingNotes)] requires the `notes` option
+/// #[scaffolding_struct]
+/// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+/// struct MyEntity {}
+/// ```
+pub trait ScaffoldingNotes {
+    /// Retrieves a related Note based on the specific id.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "This was updated".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(entity.get_note(id).unwrap().content_as_string().unwrap(), "This was updated".to_string());
+    /// ```
+    fn get_note(&self, id: String) -> Option<&Note>;
+
+    /// Retrieves all the related Notes that the clearance can access, using the default access levels,
+    /// (public, internal, confidential). For example, confidential notes are excluded unless the clearance is confidential.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = entity.insert_note("fsmith".to_string(), "This is a secret".as_bytes().to_vec(), Some("confidential".to_string()));
+    ///
+    /// assert_eq!(entity.get_notes_for_access("internal".to_string()).len(), 1);
+    /// assert_eq!(entity.get_notes_for_access("confidential".to_string()).len(), 2);
+    /// ```
+    fn get_notes_for_access(&self, clearance: String) -> Vec<Note>;
+
+    /// Retrieves all the related Notes that the clearance can access according to the access policy.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = entity.insert_note("fsmith".to_string(), "This is for staff".as_bytes().to_vec(), Some("staff".to_string()));
+    ///
+    /// let policy = AccessLevels::new(vec!["public".to_string(), "staff".to_string()]);
+    ///
+    /// assert_eq!(entity.get_notes_for_access_with_policy("staff".to_string(), &policy).len(), 2);
+    /// ```
+    fn get_notes_for_access_with_policy(
+        &self,
+        clearance: String,
+        policy: &dyn AccessPolicy,
+    ) -> Vec<Note>;
+
+    /// Inserts a related Note.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "This was updated".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(entity.notes.len(), 1);
+    /// ```
+    fn insert_note(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>) -> String;
+
+    /// Inserts a related Note with a content type other than `text/plain`, (e.g.: text/markdown).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_note_with_mime_type(
+    ///     "fsmith".to_string(),
+    ///     "# Follow up".as_bytes().to_vec(),
+    ///     None,
+    ///     "text/markdown".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.get_note(id).unwrap().mime_type, "text/markdown".to_string());
+    /// ```
+    fn insert_note_with_mime_type(
+        &mut self,
+        auth: String,
+        cont: Vec<u8>,
+        acc: Option<String>,
+        mime_type: String,
+    ) -> String;
+
+    /// Updates a related Note based on the specified id.
+    /// The content is stored as given, (use `modify_note_with()` to keep an encrypted note encrypted).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "This was updated".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    ///
+    /// entity.modify_note(
+    ///     id.clone(),
+    ///     "fsmith".to_string(),
+    ///     "This was updated again".as_bytes().to_vec(),
+    ///     Some("private".to_string()),
+    /// );
+    /// ```
+    fn modify_note(&mut self, id: String, auth: String, cont: Vec<u8>, acc: Option<String>);
+
+    /// Inserts a related Note as a reply to the note, (with the same access as the note).
+    /// Returns None when the note doesn't exist.
+    /// The content is stored as given, (use `reply_to_note_with()` to encrypt replies in an encrypted thread).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Can we ship on Monday?".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let reply = entity.reply_to_note(id.clone(), "jdoe".to_string(), "Yes".as_bytes().to_vec()).unwrap();
+    ///
+    /// assert_eq!(entity.get_note(reply).unwrap().parent_id, Some(id));
+    /// assert!(entity.reply_to_note("unknown".to_string(), "jdoe".to_string(), Vec::new()).is_none());
+    /// ```
+    fn reply_to_note(&mut self, id: String, auth: String, cont: Vec<u8>) -> Option<String>;
+
+    /// Retrieves the conversation that the Note is part of, starting with the original note
+    /// followed by the replies in the order of the conversation, (each reply is followed by its own replies).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Can we ship on Monday?".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let reply = entity.reply_to_note(id.clone(), "jdoe".to_string(), "Yes".as_bytes().to_vec()).unwrap();
+    /// let thread = entity.get_note_thread(reply);
+    ///
+    /// assert_eq!(thread.len(), 2);
+    /// assert_eq!(thread[0].id, id);
+    /// ```
+    fn get_note_thread(&self, id: String) -> Vec<Note>;
+
+    /// Inserts a related Note whose content is encrypted at rest using AES-256-GCM and returns the id of the Note.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let key = [7u8; 32];
+    /// let id = entity.insert_note_encrypted(
+    ///     "fsmith".to_string(),
+    ///     "This is confidential".as_bytes().to_vec(),
+    ///     &key,
+    /// );
+    ///
+    /// assert_eq!(entity.get_note(id).unwrap().access, "encrypted".to_string());
+    /// ```
+    #[cfg(feature = "encryption")]
+    fn insert_note_encrypted(&mut self, auth: String, cont: Vec<u8>, key: &[u8; 32]) -> String;
+
+    /// Decrypts the content of a related Note based on the specified id.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let key = [7u8; 32];
+    /// let id = entity.insert_note_encrypted(
+    ///     "fsmith".to_string(),
+    ///     "This is confidential".as_bytes().to_vec(),
+    ///     &key,
+    /// );
+    ///
+    /// assert_eq!(entity.decrypt_note(id, &key).unwrap(), "This is confidential".as_bytes().to_vec());
+    /// ```
+    #[cfg(feature = "encryption")]
+    fn decrypt_note(&self, id: String, key: &[u8; 32]) -> Result<Vec<u8>, ScaffoldingError>;
+
+    /// Inserts a related Note whose content is encrypted when a key is bound to its access level.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let keys = AccessKeys::new().with_key("confidential".to_string(), [7u8; 32]);
+    /// let id = entity.insert_note_with_keys(
+    ///     "fsmith".to_string(),
+    ///     "The salary".as_bytes().to_vec(),
+    ///     Some("confidential".to_string()),
+    ///     &keys,
+    /// );
+    ///
+    /// assert!(entity.get_note(id).unwrap().encrypted);
+    /// ```
+    #[cfg(feature = "encryption")]
+    fn insert_note_with_keys(
+        &mut self,
+        auth: String,
+        cont: Vec<u8>,
+        acc: Option<String>,
+        keys: &dyn KeyProvider,
+    ) -> String;
+
+    /// Reads the content of a related Note, (encrypted content is decrypted using the key bound to its access level).
+    /// Returns `ScaffoldingError::NotFound` when the note doesn't exist and `ScaffoldingError::Decrypt`
+    /// when the key for an encrypted note isn't supplied.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let keys = AccessKeys::new().with_key("confidential".to_string(), [7u8; 32]);
+    /// let id = entity.insert_note_with_keys(
+    ///     "fsmith".to_string(),
+    ///     "The salary".as_bytes().to_vec(),
+    ///     Some("confidential".to_string()),
+    ///     &keys,
+    /// );
+    ///
+    /// assert_eq!(entity.read_note(id.clone(), &keys).unwrap(), "The salary".as_bytes().to_vec());
+    /// assert!(entity.read_note(id, &AccessKeys::new()).is_err());
+    /// ```
+    #[cfg(feature = "encryption")]
+    fn read_note(&self, id: String, keys: &dyn KeyProvider) -> Result<Vec<u8>, ScaffoldingError>;
+
+    /// Updates a related Note, (the same as `modify_note()`), and encrypts the new content when a key is bound to its access level.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let keys = AccessKeys::new().with_key("confidential".to_string(), [7u8; 32]);
+    /// let id = entity.insert_note_with_keys(
+    ///     "fsmith".to_string(),
+    ///     "The salary".as_bytes().to_vec(),
+    ///     Some("confidential".to_string()),
+    ///     &keys,
+    /// );
+    /// entity.modify_note_with(
+    ///     id.clone(),
+    ///     "fsmith".to_string(),
+    ///     "The new salary".as_bytes().to_vec(),
+    ///     None,
+    ///     &keys,
+    /// );
+    ///
+    /// assert!(entity.get_note(id.clone()).unwrap().encrypted);
+    /// assert_eq!(entity.read_note(id, &keys).unwrap(), "The new salary".as_bytes().to_vec());
+    /// ```
+    #[cfg(feature = "encryption")]
+    fn modify_note_with(
+        &mut self,
+        id: String,
+        auth: String,
+        cont: Vec<u8>,
+        acc: Option<String>,
+        keys: &dyn KeyProvider,
+    );
+
+    /// Inserts a related Note as a reply to the note, (the same as `reply_to_note()`),
+    /// and encrypts its content when a key is bound to the access level of the thread.
+    /// Returns None when the note doesn't exist.
+    /// Requires the `encryption` feature.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let keys = AccessKeys::new().with_key("confidential".to_string(), [7u8; 32]);
+    /// let id = entity.insert_note_with_keys(
+    ///     "fsmith".to_string(),
+    ///     "Can we raise the salary?".as_bytes().to_vec(),
+    ///     Some("confidential".to_string()),
+    ///     &keys,
+    /// );
+    /// let reply = entity.reply_to_note_with(id, "jdoe".to_string(), "Yes".as_bytes().to_vec(), &keys).unwrap();
+    ///
+    /// assert!(entity.get_note(reply.clone()).unwrap().encrypted);
+    /// assert_eq!(entity.read_note(reply, &keys).unwrap(), "Yes".as_bytes().to_vec());
+    /// ```
+    #[cfg(feature = "encryption")]
+    fn reply_to_note_with(
+        &mut self,
+        id: String,
+        auth: String,
+        cont: Vec<u8>,
+        keys: &dyn KeyProvider,
+    ) -> Option<String>;
+
+    /// Searches the notes for specific string and returns all the notes that were found.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "This was updated".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Something to find here".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Nonething to find here".as_bytes().to_vec(),
+    ///     Some("private".to_string()),
+    /// );
+    ///  
+    /// let search_results = entity.search_notes("thing".to_string());
+    ///
+    /// assert_eq!(search_results.len(), 2);
+    /// ```
+    fn search_notes(&mut self, search: String) -> Vec<Note>;
+
+    /// Same as `search_notes()` but borrows the Notes instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "This was updated".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Something to find here".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Nonething to find here".as_bytes().to_vec(),
+    ///     Some("private".to_string()),
+    /// );
+    ///  
+    /// let search_results = entity.search_notes_ref("thing");
+    ///
+    /// assert_eq!(search_results.len(), 2);
+    /// ```
+    fn search_notes_ref(&self, search: &str) -> Vec<&Note>;
+
+    /// Retrieves all the Note written by the author.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = entity.insert_note("jdoe".to_string(), "This was reviewed".as_bytes().to_vec(), None);
+    ///
+    /// assert_eq!(entity.search_notes_by_author("fsmith".to_string()).len(), 1);
+    /// ```
+    fn search_notes_by_author(&self, author: String) -> Vec<Note>;
+
+    /// Same as `search_notes_by_author()` but borrows the Notes instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = entity.insert_note("jdoe".to_string(), "This was reviewed".as_bytes().to_vec(), None);
+    ///
+    /// assert_eq!(entity.search_notes_by_author_ref("fsmith").len(), 1);
+    /// ```
+    fn search_notes_by_author_ref(&self, author: &str) -> Vec<&Note>;
+
+    /// Retrieves a page of the Note sorted by the attribute, (e.g.: the newest 20 notes).
+    /// Only the notes on the page are cloned.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = entity.insert_note("jdoe".to_string(), "This was reviewed".as_bytes().to_vec(), None);
+    /// let _ = entity.insert_note("mjones".to_string(), "This was approved".as_bytes().to_vec(), None);
+    ///
+    /// let page = entity.list_notes(NoteSort::Author, SortOrder::Descending, 0, 2);
+    ///
+    /// assert_eq!(page.len(), 2);
+    /// assert_eq!(page[0].author, "mjones".to_string());
+    /// assert_eq!(entity.count_notes(), 3);
+    /// ```
+    fn list_notes(
+        &self,
+        sort_by: NoteSort,
+        order: SortOrder,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<Note>;
+
+    /// Returns the number of related Note, (e.g.: to show the number of pages).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    ///
+    /// assert_eq!(entity.count_notes(), 1);
+    /// ```
+    fn count_notes(&self) -> usize;
+
+    /// Writes the related notes to the directory, (a file per note named by its id and an `index.json` manifest
+    /// with the author, access and timestamps). Returns the number of notes written.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let dir = std::env::temp_dir().join(defaults::id());
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    ///
+    /// assert_eq!(entity.export_notes(dir.to_string_lossy().to_string()).unwrap(), 1);
+    /// # std::fs::remove_dir_all(dir).unwrap();
+    /// ```
+    fn export_notes(&self, dir: String) -> Result<usize, ScaffoldingError>;
+
+    /// Reads the notes from a directory that was written using `export_notes()` and adds them to the related notes,
+    /// (notes with the same id are replaced). Returns the number of notes read.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let dir = std::env::temp_dir().join(defaults::id());
+    /// let id = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = entity.export_notes(dir.to_string_lossy().to_string());
+    ///
+    /// let mut copy = MyEntity::new();
+    ///
+    /// assert_eq!(copy.import_notes(dir.to_string_lossy().to_string()).unwrap(), 1);
+    /// assert_eq!(copy.get_note(id).unwrap().author, "fsmith".to_string());
+    /// # std::fs::remove_dir_all(dir).unwrap();
+    /// ```
+    fn import_notes(&mut self, dir: String) -> Result<usize, ScaffoldingError>;
+
+    /// Retrieves all the Note with the access level, (e.g.: public, internal, confidential).
+    /// Unlike `get_notes_for_access()`, only the notes with exactly that access level are returned.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_note("fsmith".to_string(), "This was updated".as_bytes().to_vec(), None);
+    /// let _ = entity.insert_note("fsmith".to_string(), "The salary".as_bytes().to_vec(), Some("confidential".to_string()));
+    ///
+    /// assert_eq!(entity.search_notes_by_access("confidential".to_string()).len(), 1);
+    /// assert_eq!(entity.search_notes_by_access("public".to_string()).len(), 1);
+    /// ```
+    fn search_notes_by_access(&self, level: String) -> Vec<Note>;
+
+    /// Removes a note for specific id.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    ///
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "This was updated".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let id = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Something to find here".as_bytes().to_vec(),
+    ///     None,
+    /// );
+    /// let _ = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "Nonething to find here".as_bytes().to_vec(),
+    ///     Some("private".to_string()),
+    /// );
+    ///  
+    /// entity.remove_note(id);
+    ///
+    /// assert_eq!(entity.notes.len(), 2);
+    /// ```
+    fn remove_note(&mut self, id: String);
+}
+
+/// The phone number behavior of a Scaffolding object
+pub trait ScaffoldingPhoneNumbers {
+    /// Retrieves a related PhoneNumber based on the specific id.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_phone_number(
+    ///     "home".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.get_phone_number(id).unwrap().number, "8482493561".to_string());
+    /// ```
+    fn get_phone_number(&self, id: String) -> Option<&PhoneNumber>;
+
+    /// Retrieves the primary PhoneNumber of the category, (see `set_primary_phone_number()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let home = entity.insert_phone_number(
+    ///     "work".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// let office = entity.insert_phone_number(
+    ///     "work".to_string(),
+    ///     "8482493562".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// assert!(entity.get_primary_phone_number("work".to_string()).is_none());
+    ///
+    /// entity.set_primary_phone_number(office.clone());
+    /// assert_eq!(entity.get_primary_phone_number("work".to_string()).unwrap().id, office);
+    /// ```
+    fn get_primary_phone_number(&self, category: String) -> Option<&PhoneNumber>;
+
+    /// Makes the PhoneNumber the primary one of its category, (any other PhoneNumber of the category is no longer primary).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let home = entity.insert_phone_number(
+    ///     "work".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// let office = entity.insert_phone_number(
+    ///     "work".to_string(),
+    ///     "8482493562".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// entity.set_primary_phone_number(home.clone());
+    /// entity.set_primary_phone_number(office.clone());
+    ///
+    /// assert_eq!(entity.get_phone_number(home).unwrap().primary, false);
+    /// assert_eq!(entity.get_phone_number(office).unwrap().primary, true);
+    /// ```
+    fn set_primary_phone_number(&mut self, id: String);
+
+    /// Adds a related PhoneNumber to the Entity and returns the id for reference.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_phone_number(
+    ///     "home".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.phone_numbers.len(), 1);
+    /// ```
+    fn insert_phone_number(
+        &mut self,
+        category: String,
+        number: String,
+        country_code: String,
+    ) -> String;
+
+    /// Retrieves all the PhoneNumber with the specified category.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_phone_number(
+    ///     "home".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_phone_numbers_by_category("home".to_string()).len(), 1);
+    /// ```
+    fn search_phone_numbers_by_category(&self, category: String) -> Vec<PhoneNumber>;
+
+    /// Same as `search_phone_numbers_by_category()` but borrows the PhoneNumbers instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_phone_number(
+    ///     "home".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_phone_numbers_by_category_ref("home").len(), 1);
+    /// ```
+    fn search_phone_numbers_by_category_ref(&self, category: &str) -> Vec<&PhoneNumber>;
+
+    /// Retrieves all the PhoneNumber with the capability, (e.g.: to send text messages).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_phone_number(
+    ///     "personal".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// entity.phone_numbers.get_mut(&id).unwrap().add_capability(PhoneCapability::Sms);
+    ///
+    /// assert_eq!(entity.search_phone_numbers_by_capability(PhoneCapability::Sms).len(), 1);
+    /// assert_eq!(entity.search_phone_numbers_by_capability(PhoneCapability::Fax).len(), 0);
+    /// ```
+    fn search_phone_numbers_by_capability(&self, capability: PhoneCapability) -> Vec<PhoneNumber>;
+
+    /// Retrieves all the PhoneNumber that contain the digits of the fragment, (e.g.: caller-ID digits).
+    /// Spaces, dashes and a leading country calling code are ignored, (see `PhoneNumber::matches_number()`).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_phone_number(
+    ///     "home".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    ///
+    /// assert_eq!(entity.search_phone_numbers_by_number("+1 (848) 249-3561".to_string()).len(), 1);
+    /// assert_eq!(entity.search_phone_numbers_by_number("249-3561".to_string()).len(), 1);
+    /// assert_eq!(entity.search_phone_numbers_by_number("555-0100".to_string()).len(), 0);
+    /// ```
+    fn search_phone_numbers_by_number(&self, fragment: String) -> Vec<PhoneNumber>;
+
+    /// Removes a related PhoneNumber to the Entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("phone_numbers")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("phone_numbers")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_phone_number(
+    ///     "home".to_string(),
+    ///     "8482493561".to_string(),
+    ///     "USA".to_string(),
+    /// );
+    /// assert_eq!(entity.phone_numbers.len(), 1);
+    ///
+    /// entity.remove_phone_number(id);
+    /// assert_eq!(entity.phone_numbers.len(), 0);
+    /// ```
+    fn remove_phone_number(&mut self, id: String);
+}
+
+/// The relationships behavior of a Scaffolding object
+pub trait ScaffoldingRelationships {
+    /// Retrieves a related Relationship based on the identifier.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_relationship("parent".to_string(), "ORG-100".to_string());
+    ///
+    /// assert_eq!(entity.get_relationship(id).unwrap().target_id, "ORG-100".to_string());
+    /// ```
+    fn get_relationship(&self, id: String) -> Option<&Relationship>;
+
+    /// Insert or updates a relationship to another entity and returns the identifier.
+    ///
+    /// #Arguments
+    /// * kind: String - The kind of relationship, (e.g.: parent, employer, duplicate_of, etc.).
+    /// * target_id: String - The unique identifier of the related entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_relationship("employer".to_string(), "ORG-100".to_string());
+    ///
+    /// assert_eq!(entity.relationships.len(), 1);
+    /// ```
+    fn insert_relationship(&mut self, kind: String, target_id: String) -> String;
+
+    /// Retrieves all the relationships of a specific kind.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_relationship("parent".to_string(), "ORG-100".to_string());
+    /// let _ = entity.insert_relationship("employer".to_string(), "ORG-200".to_string());
+    ///
+    /// assert_eq!(entity.search_relationships_by_kind("parent".to_string()).len(), 1);
+    /// ```
+    fn search_relationships_by_kind(&self, kind: String) -> Vec<Relationship>;
+
+    /// Same as `search_relationships_by_kind()` but borrows the Relationships instead of cloning them, (e.g.: for read-heavy workloads).
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_relationship("parent".to_string(), "ORG-100".to_string());
+    /// let _ = entity.insert_relationship("employer".to_string(), "ORG-200".to_string());
+    ///
+    /// assert_eq!(entity.search_relationships_by_kind_ref("parent").len(), 1);
+    /// ```
+    fn search_relationships_by_kind_ref(&self, kind: &str) -> Vec<&Relationship>;
+
+    /// Retrieves all the relationships to a specific entity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let _ = entity.insert_relationship("parent".to_string(), "ORG-100".to_string());
+    /// let _ = entity.insert_relationship("employer".to_string(), "ORG-100".to_string());
+    ///
+    /// assert_eq!(entity.search_relationships_by_target("ORG-100".to_string()).len(), 2);
+    /// ```
+    fn search_relationships_by_target(&self, target_id: String) -> Vec<Relationship>;
+
+    /// Removes a relationship for specific id.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("relationships")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingRelationships)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("relationships")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_relationship("parent".to_string(), "ORG-100".to_string());
+    /// entity.remove_relationship(id);
+    ///
+    /// assert_eq!(entity.relationships.len(), 0);
+    /// ```
+    fn remove_relationship(&mut self, id: String);
+}
+
+/// The retention behavior of a Scaffolding object
+pub trait ScaffoldingRetention {
+    /// Applies the retention policies to the Entity and returns a report of what was removed.
+    /// If the Entity is expired, (the `expired_dtm` has passed), its id is listed in the report's entities.
+    /// The attributes that the Entity doesn't have, (e.g.: a typo) are listed in the report's unknown attributes
+    /// and the removed related records are logged as the `pruned` activity.
+    ///
+    /// #Example
+    ///
+    /// ```rust
+    /// extern crate scaffolding_core;
+    ///  
+    /// use scaffolding_core::*;
+    ///
+    /// #[scaffolding_struct("notes")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes, ScaffoldingRetention)]
+    /// struct MyEntity {}
+    ///
+    /// impl MyEntity {
+    ///     #[scaffolding_fn("notes")]
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// let mut entity = MyEntity::new();
+    /// let id = entity.insert_note(
+    ///     "fsmith".to_string(),
+    ///     "This was updated".as_bytes().to_vec(),
+    ///     None,
     /// );
-    /// println!("{}", phone.serialize());
+    /// entity.notes.get_mut(&id).unwrap().created_dtm = defaults::add_days(defaults::now(), -1000);
+    ///
+    /// let report = entity.apply_retention(&[
+    ///     RetentionPolicy::RemoveOlderThan { attribute: "notes".to_string(), days: 730 },
+    ///     RetentionPolicy::ExpireAfterInactivity { years: 7 },
+    /// ]);
+    ///
+    /// assert_eq!(entity.notes.len(), 0);
+    /// assert_eq!(report.records.get("notes").unwrap(), &vec![id]);
+    /// assert_eq!(entity.expired_dtm, defaults::add_years(entity.inactive_dtm, 7));
     /// ```
-    pub fn serialize(&mut self) -> String {
-        serde_json::to_string(&self).unwrap()
-    }
+    fn apply_retention(&mut self, policies: &[RetentionPolicy]) -> RetentionReport;
 }
 
-/// The core behavior of a Scaffolding object
-pub trait Scaffolding {
-    /// This function adds a ActivityItem to the activity log
+/// The soft delete behavior of a Scaffolding object
+///
+/// Soft deleted entities are kept, (e.g.: for audits), but are excluded from the EntityCollection searches.
+/// Use `Scaffolding::is_deleted()` to determine if an entity has been soft deleted.
+pub trait ScaffoldingSoftDelete {
+    /// This function soft deletes the object by setting the `deleted_dtm`,
+    /// logs a `deleted` activity and updates the `modified_dtm`.
+    /// Unlike expiration, a soft deleted object is hidden immediately.
     ///
     /// #Example
     ///
@@ -929,25 +11512,28 @@ pub trait Scaffolding {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct]
-    /// #[derive(Clone, Debug, Scaffolding)]
+    /// #[scaffolding_struct("soft_delete")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingSoftDelete)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn]
+    ///     #[scaffolding_fn("soft_delete")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
+    /// entity.delete();
     ///
-    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
-    /// assert_eq!(entity.activity.len(), 1);
+    /// assert_eq!(entity.deleted_dtm, Some(defaults::now()));
+    /// assert_eq!(entity.get_activity("deleted".to_string()).len(), 1);
     /// ```
-    fn log_activity(&mut self, name: String, descr: String);
+    fn delete(&mut self);
 
-    /// This function retrieves all the ActivityItems that have the specified action (name)
+    /// This function restores a soft deleted object by clearing the `deleted_dtm`,
+    /// logs a `restored` activity and updates the `modified_dtm`.
+    /// An object that has already expired is beyond recovery and returns a `ScaffoldingError::Recovery`.
     ///
     /// #Example
     ///
@@ -956,26 +11542,33 @@ pub trait Scaffolding {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct]
-    /// #[derive(Clone, Debug, Scaffolding)]
+    /// #[scaffolding_struct("soft_delete")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingSoftDelete)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn]
+    ///     #[scaffolding_fn("soft_delete")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
+    /// entity.delete();
     ///
-    /// entity.log_activity("ordered".to_string(), "The customer has place the order".to_string());
-    /// entity.log_activity("cancelled".to_string(), "The customer has cancelled their service".to_string());
-    /// assert_eq!(entity.get_activity("cancelled".to_string()).len(), 1);
+    /// assert!(entity.restore().is_ok());
+    /// assert!(!entity.is_deleted());
+    /// assert_eq!(entity.get_activity("restored".to_string()).len(), 1);
     /// ```
-    fn get_activity(&self, name: String) -> Vec<ActivityItem>;
+    fn restore(&mut self) -> Result<(), ScaffoldingError>;
+}
 
-    /// This function instantiates an entity from a JSON string.
+/// The status (state machine) behavior of a Scaffolding object
+///
+/// The transition table defaults to `StatusTransitions::default()` and can be declared on the struct
+/// using the `scaffolding_status` attribute, (e.g.: `#[scaffolding_status("open -> closed", "closed -> open")]`).
+pub trait ScaffoldingStatus {
+    /// This function returns the transition table of the object.
     ///
     /// #Example
     ///
@@ -984,61 +11577,27 @@ pub trait Scaffolding {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct]
-    /// #[derive(Clone, Debug, Deserialize, Scaffolding)]
+    /// #[scaffolding_struct("status")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingStatus)]
+    /// #[scaffolding_status("open -> closed", "closed -> open")]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn]
+    ///     #[scaffolding_fn("status")]
     ///     fn new() -> Self {
-    ///         Self {}
+    ///         Self {
+    ///             status: "open".to_string(),
+    ///         }
     ///     }
     /// }
     ///
-    /// let json = r#"{
-    ///     "id":"b4d6c6db-7468-400a-8536-a5e83b1f2bdc",
-    ///     "created_dtm":1711802687,
-    ///     "modified_dtm":1711802687,
-    ///     "inactive_dtm":1719578687,
-    ///     "expired_dtm":1806410687,
-    ///     "activity":[
-    ///         {
-    ///             "created_dtm":1711802687,
-    ///             "action":"updated",
-    ///             "description":"The object has been updated"
-    ///         },
-    ///         {
-    ///             "created_dtm":1711802687,
-    ///             "action":"updated",
-    ///             "description":"The object has been updated"
-    ///         },
-    ///         {
-    ///             "created_dtm":1711802687,
-    ///             "action":"cancelled",
-    ///             "description":"The object has been cancelled"
-    ///         }
-    ///         ]
-    ///     }"#;
-    /// let deserialized = MyEntity::deserialized(json.as_bytes()).unwrap();
-    ///
-    /// assert_eq!(deserialized.id, "b4d6c6db-7468-400a-8536-a5e83b1f2bdc");
-    /// assert_eq!(deserialized.activity.len(), 3);  
+    /// let entity = MyEntity::new();
     ///
+    /// assert!(entity.status_transitions().is_allowed("open", "closed"));
     /// ```
-    fn deserialized(serialized: &[u8]) -> Result<Self, DeserializeError>
-    where
-        Self: DeserializeOwned,
-    {
-        match serde_json::from_slice::<Self>(&serialized) {
-            Ok(item) => Ok(item),
-            Err(err) => {
-                println!("{}", err);
-                Err(DeserializeError)
-            }
-        }
-    }
+    fn status_transitions(&self) -> StatusTransitions;
 
-    /// This function converts the entity to a serialize JSON string.
+    /// This function determines if the object can transition from its current status to the specified status.
     ///
     /// #Example
     ///
@@ -1047,33 +11606,27 @@ pub trait Scaffolding {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct]
-    /// #[derive(Clone, Debug, Serialize, Scaffolding)]
+    /// #[scaffolding_struct("status")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingStatus)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn]
+    ///     #[scaffolding_fn("status")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
-    /// let mut entity = MyEntity::new();
-    /// let json_string = entity.serialize();
+    /// let entity = MyEntity::new();
     ///
-    /// println!("{}", json_string);
+    /// assert_eq!(entity.status, "draft".to_string());
+    /// assert!(entity.can_transition_to("active".to_string()));
+    /// assert!(!entity.can_transition_to("closed".to_string()));
     /// ```
-    fn serialize(&mut self) -> String
-    where
-        Self: Serialize,
-    {
-        serde_json::to_string(&self).unwrap()
-    }
-}
+    fn can_transition_to(&self, status: String) -> bool;
 
-/// The addresses behavior of a Scaffolding object
-pub trait ScaffoldingAddresses {
-    /// Retrieves a related Address to the Entity based on the specified id.
+    /// This function transitions the object to the specified status if the transition is allowed,
+    /// logs a `status_changed` activity and updates the `modified_dtm`.
     ///
     /// #Example
     ///
@@ -1082,32 +11635,30 @@ pub trait ScaffoldingAddresses {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// #[scaffolding_struct("status")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingStatus)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("addresses")]
+    ///     #[scaffolding_fn("status")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_address(
-    ///     "shipping".to_string(),
-    ///     "acmes company".to_string(),
-    ///     "14 Main Street".to_string(),
-    ///     "Big City, NY 038845".to_string(),
-    ///     "USA".to_string(),
-    ///     "USA".to_string(),
-    /// );
     ///
-    /// assert_eq!(entity.get_address(id).unwrap().category, "shipping".to_string());
-    /// ```    
-    fn get_address(&self, id: String) -> Option<&Address>;
+    /// assert!(entity.transition_to("active".to_string()).is_ok());
+    /// assert!(entity.transition_to("draft".to_string()).is_err());
+    /// assert_eq!(entity.status, "active".to_string());
+    /// assert_eq!(entity.get_activity("status_changed".to_string()).len(), 1);
+    /// ```
+    fn transition_to(&mut self, status: String) -> Result<(), ScaffoldingError>;
+}
 
-    /// Insert or updates a related Address to the Entity and returns the id of the Address.
+/// The multi-tenancy behavior of a Scaffolding object
+pub trait ScaffoldingTenant {
+    /// This function determines if the object belongs to the specified tenant
     ///
     /// #Example
     ///
@@ -1116,40 +11667,29 @@ pub trait ScaffoldingAddresses {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// #[scaffolding_struct("tenant")]
+    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTenant)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("addresses")]
+    ///     #[scaffolding_fn("tenant")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let _ = entity.insert_address(
-    ///     "shipping".to_string(),
-    ///     "acmes company".to_string(),
-    ///     "14 Main Street".to_string(),
-    ///     "Big City, NY 038845".to_string(),
-    ///     "USA".to_string(),
-    ///     "USA".to_string(),
-    /// );
+    /// entity.tenant_id = "acme".to_string();
     ///
-    /// assert_eq!(entity.addresses.len(), 1);
+    /// assert!(entity.belongs_to_tenant("acme".to_string()));
+    /// assert!(!entity.belongs_to_tenant("globex".to_string()));
     /// ```
-    fn insert_address(
-        &mut self,
-        category: String,
-        line_1: String,
-        line_2: String,
-        line_3: String,
-        line_4: String,
-        country_code: String,
-    ) -> String;
+    fn belongs_to_tenant(&self, tenant_id: String) -> bool;
+}
 
-    /// Insert or updates a related Address to the Entity and returns the id of the Address for reference.
+/// The tagging behavior of a Scaffolding object
+pub trait ScaffoldingTags {
+    /// This function adds a tag to the object, (normalized using the default `TagPolicy`)
     ///
     /// #Example
     ///
@@ -1158,153 +11698,121 @@ pub trait ScaffoldingAddresses {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("addresses")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_address(
-    ///     "shipping".to_string(),
-    ///     "acmes company".to_string(),
-    ///     "14 Main Street".to_string(),
-    ///     "Big City, NY 038845".to_string(),
-    ///     "USA".to_string(),
-    ///     "USA".to_string(),
-    /// );
     ///
-    /// entity.modify_address(
-    ///     id.clone(),
-    ///     "billing".to_string(),
-    ///     "acmes company".to_string(),
-    ///     "14 Main Street".to_string(),
-    ///     "Big City, NY 038845".to_string(),
-    ///     "USA".to_string(),
-    ///     "USA".to_string(),);
+    /// entity.add_tag("tag_1".to_string());
+    /// // ignore any duplicates
+    /// entity.add_tag("tag_1".to_string());
+    /// entity.add_tag("tag_2".to_string());
+    /// entity.add_tag("tag_3".to_string());
     ///
-    /// assert_eq!(entity.get_address(id).unwrap().category, "billing".to_string());
+    /// assert_eq!(entity.tags.len(), 3);
     /// ```
-    fn modify_address(
-        &mut self,
-        id: String,
-        category: String,
-        line_1: String,
-        line_2: String,
-        line_3: String,
-        line_4: String,
-        country_code: String,
-    );
+    fn add_tag(&mut self, tag: String);
 
-    /// Retrieves all the Addresses with the specified category.
+    /// This function adds a tag to the object, (normalized using the policy)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("addresses")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let address = entity.insert_address(
-    ///     "shipping".to_string(),
-    ///     "acmes company".to_string(),
-    ///     "14 Main Street".to_string(),
-    ///     "Big City, NY 038845".to_string(),
-    ///     "USA".to_string(),
-    ///     "USA".to_string(),
-    /// );
+    /// let policy = TagPolicy::new(false, true, true);
     ///
-    /// assert_eq!(entity.search_addresses_by_category("shipping".to_string()).len(), 1);
+    /// entity.add_tag_with_policy(" Key  Account".to_string(), &policy);
+    /// entity.add_tag_with_policy("Key Account ".to_string(), &policy);
+    ///
+    /// assert_eq!(entity.tags, vec!["Key Account".to_string()]);
     /// ```
-    fn search_addresses_by_category(&self, category: String) -> Vec<Address>;
+    fn add_tag_with_policy(&mut self, tag: String, policy: &TagPolicy);
 
-    /// Removes a related Address to the Entity.
+    /// This function adds a tag to the object, (normalized using the default `TagPolicy`)
+    /// and returns false instead of printing a message when the tag already exists.
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("addresses")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_address(
-    ///     "shipping".to_string(),
-    ///     "acmes company".to_string(),
-    ///     "14 Main Street".to_string(),
-    ///     "Big City, NY 038845".to_string(),
-    ///     "USA".to_string(),
-    ///     "USA".to_string(),
-    /// );
-    /// assert_eq!(entity.addresses.len(), 1);
     ///
-    /// entity.remove_address(id);
-    /// assert_eq!(entity.addresses.len(), 0);
+    /// assert!(entity.try_add_tag("tag_1".to_string()));
+    /// assert!(!entity.try_add_tag("tag_1".to_string()));
+    ///
+    /// assert_eq!(entity.tags.len(), 1);
     /// ```
-    fn remove_address(&mut self, id: String);
-}
+    fn try_add_tag(&mut self, tag: String) -> bool;
 
-/// The email address behavior of a Scaffolding object
-pub trait ScaffoldingEmailAddresses {
-    /// Retrieves a related EmailAddress based on the specific id.
+    /// This function adds a tag to the object, (normalized using the policy)
+    /// and returns false instead of printing a message when the tag already exists.
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("email_addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("email_addresses")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_email_address(
-    ///     "home".to_string(),
-    ///     "myemail@example.com".to_string(),
-    /// );
+    /// let policy = TagPolicy::new(false, true, true);
     ///
-    /// assert_eq!(entity.get_email_address(id).unwrap().address, "myemail@example.com".to_string());
+    /// assert!(entity.try_add_tag_with_policy(" Key  Account".to_string(), &policy));
+    /// assert!(!entity.try_add_tag_with_policy("Key Account ".to_string(), &policy));
+    ///
+    /// assert_eq!(entity.tags, vec!["Key Account".to_string()]);
     /// ```
-    fn get_email_address(&self, id: String) -> Option<&EmailAddress>;
+    fn try_add_tag_with_policy(&mut self, tag: String, policy: &TagPolicy) -> bool;
 
-    /// Adds a related PhoneNumber to the Entity and returns the id for reference.
+    /// This function determines if the object has a specific tag, (compared using the default `TagPolicy`)
     ///
     /// #Example
     ///
@@ -1313,58 +11821,58 @@ pub trait ScaffoldingEmailAddresses {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("email_addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("email_addresses")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let _ = entity.insert_email_address(
-    ///     "home".to_string(),
-    ///     "myemail@example.com".to_string(),
-    /// );
     ///
-    /// assert_eq!(entity.email_addresses.len(), 1);
+    /// entity.add_tag("tag_1".to_string());
+    ///
+    /// assert!(entity.has_tag("tag_1".to_string()));
+    /// assert!(entity.has_tag(" TAG_1".to_string()));
     /// ```
-    fn insert_email_address(&mut self, category: String, address: String) -> String;
+    fn has_tag(&self, tag: String) -> bool;
 
-    /// Retrieves all the EmailAddress with the specified category.
+    /// This function determines if the object has a specific tag, (compared using the policy)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("email_addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("email_addresses")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let _ = entity.insert_email_address(
-    ///     "home".to_string(),
-    ///     "myemail@example.com".to_string(),
-    /// );
+    /// let exact = TagPolicy::new(false, false, false);
     ///
-    /// assert_eq!(entity.search_email_addresses_by_category("home".to_string()).len(), 1);
+    /// entity.add_tag_with_policy("VIP".to_string(), &exact);
+    ///
+    /// assert!(entity.has_tag_with_policy("VIP".to_string(), &exact));
+    /// assert!(!entity.has_tag_with_policy("vip".to_string(), &exact));
     /// ```
-    fn search_email_addresses_by_category(&self, category: String) -> Vec<EmailAddress>;
+    fn has_tag_with_policy(&self, tag: String, policy: &TagPolicy) -> bool;
 
-    /// Removes a related EmailAddress to the Entity.
+    /// This function removes a specific tag from the object, (compared using the default `TagPolicy`).
+    /// Removing a tag that the object doesn't have is ignored.
     ///
     /// #Example
     ///
@@ -1373,362 +11881,292 @@ pub trait ScaffoldingEmailAddresses {
     ///  
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("email_addresses")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("email_addresses")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_email_address(
-    ///     "home".to_string(),
-    ///     "myemail@example.com".to_string(),
-    /// );
-    /// assert_eq!(entity.email_addresses.len(), 1);
     ///
-    /// entity.remove_email_address(id);
-    /// assert_eq!(entity.email_addresses.len(), 0);
+    /// entity.add_tag("tag_1".to_string());
+    /// assert_eq!(entity.tags.len(), 1);
+    /// entity.remove_tag("tag_1".to_string());
+    /// assert_eq!(entity.tags.len(), 0);
     /// ```
-    fn remove_email_address(&mut self, id: String);
-}
+    fn remove_tag(&mut self, tag: String);
 
-/// The notes behavior of a Scaffolding object
-pub trait ScaffoldingNotes {
-    /// Retrieves a related Note based on the specific id.
+    /// This function removes a specific tag from the object, (compared using the policy)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("notes")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("notes")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "This was updated".as_bytes().to_vec(),
-    ///     None,
-    /// );
+    /// let policy = TagPolicy::new(false, true, true);
     ///
-    /// assert_eq!(entity.get_note(id).unwrap().content_as_string().unwrap(), "This was updated".to_string());
+    /// entity.add_tag_with_policy("VIP".to_string(), &policy);
+    /// entity.remove_tag_with_policy(" VIP ".to_string(), &policy);
+    ///
+    /// assert_eq!(entity.tags.len(), 0);
     /// ```
-    fn get_note(&self, id: String) -> Option<&Note>;
+    fn remove_tag_with_policy(&mut self, tag: String, policy: &TagPolicy);
 
-    /// Inserts a related Note.
+    /// This function renames a tag in place and returns false when the object doesn't have the tag.
+    /// If the object already has the new tag, the old tag is removed so there are no duplicates.
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("notes")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("notes")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "This was updated".as_bytes().to_vec(),
-    ///     None,
-    /// );
+    /// entity.add_tag("customer".to_string());
     ///
-    /// assert_eq!(entity.notes.len(), 1);
+    /// assert!(entity.rename_tag("customer".to_string(), "client".to_string()));
+    /// assert!(!entity.rename_tag("prospect".to_string(), "lead".to_string()));
+    /// assert_eq!(entity.tags, vec!["client".to_string()]);
     /// ```
-    fn insert_note(&mut self, auth: String, cont: Vec<u8>, acc: Option<String>) -> String;
+    fn rename_tag(&mut self, old: String, new: String) -> bool;
 
-    /// Updates a related Note based on the specified id.
+    /// This function replaces a tag with another tag, (the new tag is added even if the object doesn't have the old tag)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("notes")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("notes")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "This was updated".as_bytes().to_vec(),
-    ///     None,
-    /// );
+    /// entity.replace_tag("status:trial".to_string(), "status:paid".to_string());
     ///
-    /// entity.modify_note(
-    ///     id.clone(),
-    ///     "fsmith".to_string(),
-    ///     "This was updated again".as_bytes().to_vec(),
-    ///     Some("private".to_string()),
-    /// );
+    /// assert_eq!(entity.tags, vec!["status:paid".to_string()]);
     /// ```
-    fn modify_note(&mut self, id: String, auth: String, cont: Vec<u8>, acc: Option<String>);
+    fn replace_tag(&mut self, old: String, new: String);
 
-    /// Searches the notes for specific string and returns all the notes that were found.
+    /// This function determines if the object has a tag that is the prefix or is nested under it,
+    /// (e.g.: `region:emea:uk` has the prefix `region:emea`)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("notes")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("notes")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
+    /// entity.add_tag("region:emea:uk".to_string());
     ///
-    /// let _ = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "This was updated".as_bytes().to_vec(),
-    ///     None,
-    /// );
-    /// let _ = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "Something to find here".as_bytes().to_vec(),
-    ///     None,
-    /// );
-    /// let _ = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "Nonething to find here".as_bytes().to_vec(),
-    ///     Some("private".to_string()),
-    /// );
-    ///  
-    /// let search_results = entity.search_notes("thing".to_string());
-    ///
-    /// assert_eq!(search_results.len(), 2);
+    /// assert!(entity.has_tag_prefix("region:emea".to_string()));
+    /// assert!(!entity.has_tag_prefix("region:apac".to_string()));
     /// ```
-    fn search_notes(&mut self, search: String) -> Vec<Note>;
+    fn has_tag_prefix(&self, prefix: String) -> bool;
 
-    /// Removes a note for specific id.
+    /// This function returns the tags that are nested under the namespace, (e.g.: `region:emea:uk` is in `region`)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("notes")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("notes")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
+    /// entity.add_tag("region:emea:uk".to_string());
+    /// entity.add_tag("segment:enterprise".to_string());
     ///
-    /// let _ = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "This was updated".as_bytes().to_vec(),
-    ///     None,
-    /// );
-    /// let id = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "Something to find here".as_bytes().to_vec(),
-    ///     None,
-    /// );
-    /// let _ = entity.insert_note(
-    ///     "fsmith".to_string(),
-    ///     "Nonething to find here".as_bytes().to_vec(),
-    ///     Some("private".to_string()),
-    /// );
-    ///  
-    /// entity.remove_note(id);
-    ///
-    /// assert_eq!(entity.notes.len(), 2);
+    /// assert_eq!(entity.tags_in_namespace("region".to_string()), vec!["region:emea:uk".to_string()]);
     /// ```
-    fn remove_note(&mut self, id: String);
-}
+    fn tags_in_namespace(&self, namespace: String) -> Vec<String>;
 
-/// The phone number behavior of a Scaffolding object
-pub trait ScaffoldingPhoneNumbers {
-    /// Retrieves a related PhoneNumber based on the specific id.
+    /// This function returns the tags that match the pattern, where `*` matches a single level
+    /// and `**` matches any number of levels, (see `TagPolicy::matches()`)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("phone_numbers")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("phone_numbers")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_phone_number(
-    ///     "home".to_string(),
-    ///     "8482493561".to_string(),
-    ///     "USA".to_string(),
-    /// );
+    /// entity.add_tag("region:emea:uk".to_string());
+    /// entity.add_tag("region:apac:jp".to_string());
     ///
-    /// assert_eq!(entity.get_phone_number(id).unwrap().number, "8482493561".to_string());
+    /// assert_eq!(entity.search_tags_matching("region:*:uk".to_string()).len(), 1);
+    /// assert_eq!(entity.search_tags_matching("region:**".to_string()).len(), 2);
     /// ```
-    fn get_phone_number(&self, id: String) -> Option<&PhoneNumber>;
+    fn search_tags_matching(&self, pattern: String) -> Vec<String>;
 
-    /// Adds a related PhoneNumber to the Entity and returns the id for reference.
+    /// This function returns the tags that start with the prefix, (e.g.: for autocomplete)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("phone_numbers")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("phone_numbers")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let _ = entity.insert_phone_number(
-    ///     "home".to_string(),
-    ///     "8482493561".to_string(),
-    ///     "USA".to_string(),
-    /// );
+    /// entity.add_tag("region:emea".to_string());
+    /// entity.add_tag("vip".to_string());
     ///
-    /// assert_eq!(entity.phone_numbers.len(), 1);
+    /// assert_eq!(entity.search_tags("reg".to_string()), vec!["region:emea".to_string()]);
     /// ```
-    fn insert_phone_number(
-        &mut self,
-        category: String,
-        number: String,
-        country_code: String,
-    ) -> String;
+    fn search_tags(&self, prefix: String) -> Vec<String>;
 
-    /// Retrieves all the PhoneNumber with the specified category.
+    /// This function returns the tags that resemble the partial tag, the best suggestions first, (see `TagPolicy::suggest()`)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("phone_numbers")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("phone_numbers")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let _ = entity.insert_phone_number(
-    ///     "home".to_string(),
-    ///     "8482493561".to_string(),
-    ///     "USA".to_string(),
-    /// );
+    /// entity.add_tag("key account".to_string());
+    /// entity.add_tag("account".to_string());
     ///
-    /// assert_eq!(entity.search_phone_numbers_by_category("home".to_string()).len(), 1);
+    /// assert_eq!(entity.suggest_tags("acc".to_string()), vec!["account".to_string(), "key account".to_string()]);
     /// ```
-    fn search_phone_numbers_by_category(&self, category: String) -> Vec<PhoneNumber>;
+    fn suggest_tags(&self, partial: String) -> Vec<String>;
 
-    /// Removes a related PhoneNumber to the Entity.
+    /// This function adds a key-value tag to the object, (e.g.: `priority=high`),
+    /// replacing the value of the key if the object already has it
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("phone_numbers")]
-    /// #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingPhoneNumbers)]
+    /// #[scaffolding_struct("tags")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
     /// struct MyEntity {}
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("phone_numbers")]
+    ///     #[scaffolding_fn("tags")]
     ///     fn new() -> Self {
     ///         Self {}
     ///     }
     /// }
     ///
     /// let mut entity = MyEntity::new();
-    /// let id = entity.insert_phone_number(
-    ///     "home".to_string(),
-    ///     "8482493561".to_string(),
-    ///     "USA".to_string(),
-    /// );
-    /// assert_eq!(entity.phone_numbers.len(), 1);
+    /// entity.add_tag_value("priority".to_string(), "low".to_string());
+    /// entity.add_tag_value("priority".to_string(), "high".to_string());
     ///
-    /// entity.remove_phone_number(id);
-    /// assert_eq!(entity.phone_numbers.len(), 0);
+    /// assert_eq!(entity.tags, vec!["priority=high".to_string()]);
     /// ```
-    fn remove_phone_number(&mut self, id: String);
-}
+    fn add_tag_value(&mut self, key: String, value: String);
 
-/// The tagging behavior of a Scaffolding object
-pub trait ScaffoldingTags {
-    /// This function adds a tag to the object
+    /// This function returns the value of the key-value tag, (None when the object doesn't have the key or it has no value)
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
     /// #[scaffolding_struct("tags")]
@@ -1743,24 +12181,20 @@ pub trait ScaffoldingTags {
     /// }
     ///
     /// let mut entity = MyEntity::new();
+    /// entity.add_tag_value("priority".to_string(), "high".to_string());
     ///
-    /// entity.add_tag("tag_1".to_string());
-    /// // ignore any duplicates
-    /// entity.add_tag("tag_1".to_string());
-    /// entity.add_tag("tag_2".to_string());
-    /// entity.add_tag("tag_3".to_string());
-    ///
-    /// assert_eq!(entity.tags.len(), 3);
+    /// assert_eq!(entity.get_tag_value("priority".to_string()), Some("high".to_string()));
+    /// assert_eq!(entity.get_tag_value("source".to_string()), None);
     /// ```
-    fn add_tag(&mut self, tag: String);
+    fn get_tag_value(&self, key: String) -> Option<String>;
 
-    /// This function determines if the object has a specific tag
+    /// This function determines if the object has the key, (with any value or none) or the key with the value
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
     /// #[scaffolding_struct("tags")]
@@ -1775,41 +12209,69 @@ pub trait ScaffoldingTags {
     /// }
     ///
     /// let mut entity = MyEntity::new();
+    /// entity.add_tag_value("priority".to_string(), "high".to_string());
     ///
-    /// entity.add_tag("tag_1".to_string());
-    ///
-    /// assert!(entity.has_tag("tag_1".to_string()));
+    /// assert!(entity.has_tag_value("priority".to_string(), None));
+    /// assert!(entity.has_tag_value("priority".to_string(), Some("high".to_string())));
+    /// assert!(!entity.has_tag_value("priority".to_string(), Some("low".to_string())));
     /// ```
-    fn has_tag(&self, tag: String) -> bool;
+    fn has_tag_value(&self, key: String, value: Option<String>) -> bool;
+}
 
-    /// This function removes a specific tag from the object
+/// The declarative validation of a Scaffolding object
+pub trait ScaffoldingValidate {
+    /// This function checks the attributes against the rules declared using `#[validate(...)]`,
+    /// as well as the addresses, email addresses and phone numbers of the object, (if it has them).
+    /// All the violations are collected in the report.
+    ///
+    /// The rules are:
+    /// + `length(min = x, max = y)` - the number of characters or items
+    /// + `range(min = x, max = y)` - the numeric value
+    /// + `email` - the text is a properly formatted email address
+    /// + `required` - the optional attribute has a value
+    ///
+    /// Optional attributes without a value are only checked by `required`.
     ///
     /// #Example
     ///
     /// ```rust
     /// extern crate scaffolding_core;
-    ///  
+    ///
     /// use scaffolding_core::*;
     ///
-    /// #[scaffolding_struct("tags")]
-    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingTags)]
-    /// struct MyEntity {}
+    /// #[scaffolding_struct("email_addresses")]
+    /// #[derive(Clone, Debug, Scaffolding, ScaffoldingEmailAddresses, ScaffoldingValidate)]
+    /// struct MyEntity {
+    ///     #[validate(length(min = 1, max = 20))]
+    ///     name: String,
+    ///     #[validate(range(min = 18, max = 120))]
+    ///     age: u8,
+    ///     #[validate(email)]
+    ///     login: Option<String>,
+    /// }
     ///
     /// impl MyEntity {
-    ///     #[scaffolding_fn("tags")]
-    ///     fn new() -> Self {
-    ///         Self {}
+    ///     #[scaffolding_fn("email_addresses")]
+    ///     fn new(name: &str, age: u8) -> Self {
+    ///         Self {
+    ///             name: name.to_string(),
+    ///             age: age,
+    ///             login: None,
+    ///         }
     ///     }
     /// }
     ///
-    /// let mut entity = MyEntity::new();
+    /// let mut entity = MyEntity::new("Frank", 32);
+    /// assert!(entity.validate().is_ok());
     ///
-    /// entity.add_tag("tag_1".to_string());
-    /// assert_eq!(entity.tags.len(), 1);
-    /// entity.remove_tag("tag_1".to_string());
-    /// assert_eq!(entity.tags.len(), 0);
+    /// entity.login = Some("frank".to_string());
+    /// entity.insert_email_address("home".to_string(), "frank@".to_string());
+    /// let report = entity.validate().unwrap_err();
+    ///
+    /// assert_eq!(report.violations.len(), 2);
+    /// assert_eq!(report.violations[0].field, "login");
     /// ```
-    fn remove_tag(&mut self, tag: String);
+    fn validate(&self) -> Result<(), ValidationReport>;
 }
 
 // modules
@@ -1838,11 +12300,21 @@ mod tests {
     #[test]
     fn test_activityitem_serialization() {
         let serialized = r#"{"created_dtm":1711760135,"action":"updated","description":"The object has been updated."}"#;
-        let mut ai = ActivityItem::deserialized(&serialized.as_bytes()).unwrap();
+        let ai = ActivityItem::deserialized(serialized.as_bytes()).unwrap();
 
         assert_eq!(ai.created_dtm, 1711760135);
         assert_eq!(ai.action, "updated".to_string());
         assert_eq!(ai.description, "The object has been updated.".to_string());
         assert_eq!(ai.serialize(), serialized);
     }
+
+    #[test]
+    fn test_activityitem_with_data_serialization() {
+        let serialized = r#"{"created_dtm":1711760135,"action":"updated","description":"The object has been updated.","data":{"new":"closed","old":"open"}}"#;
+        let ai = ActivityItem::deserialized(serialized.as_bytes()).unwrap();
+
+        assert_eq!(ai.data.as_ref().unwrap()["old"], "open");
+        assert_eq!(ai.data.as_ref().unwrap()["new"], "closed");
+        assert_eq!(ai.serialize(), serialized);
+    }
 }
@@ -3,6 +3,7 @@ extern crate scaffolding_core;
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
+    use scaffolding_core::errors::ScaffoldingError;
     use scaffolding_core::*;
 
     #[scaffolding_struct("addresses")]
@@ -16,6 +17,18 @@ mod tests {
         }
     }
 
+    // locates only the addresses in Big City
+    struct BigCityGeocoder;
+
+    impl Geocoder for BigCityGeocoder {
+        fn geocode(&self, address: &Address) -> Result<(f64, f64), ScaffoldingError> {
+            match address.city.as_deref() {
+                Some("Big City") => Ok((40.7128, -74.006)),
+                _ => Err(ScaffoldingError::Geocode),
+            }
+        }
+    }
+
     #[test]
     fn test_address_new() {
         let address = Address::new(
@@ -37,6 +50,45 @@ mod tests {
         assert_eq!(address.modified_dtm, now);
     }
 
+    #[test]
+    fn test_address_category() {
+        let mut address = Address::new(
+            Category::Shipping,
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert_eq!(address.category, Category::from("shipping".to_string()));
+        assert_eq!(address.category, "shipping");
+
+        // the category is serialized as before, (a plain string)
+        let json: Value = address.serialize().parse().unwrap();
+        assert_eq!(json["category"], "shipping");
+
+        address.category = Category::Other("warehouse".to_string());
+        let loaded = Address::deserialized(address.serialize().as_bytes()).unwrap();
+        assert_eq!(loaded.category, Category::Other("warehouse".to_string()));
+
+        let mut entity = MyEntity::new();
+        let id = entity.insert_address(
+            "billing".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert_eq!(entity.get_address(id).unwrap().category, Category::Billing);
+        assert_eq!(
+            entity
+                .search_addresses_by_category(Category::Billing.to_string())
+                .len(),
+            1
+        );
+    }
+
     #[test]
     fn test_entity_addresses() {
         let mut entity = MyEntity::new();
@@ -93,4 +145,543 @@ mod tests {
         assert_eq!(shipping[0].category, "shipping".to_string());
         assert_eq!(shipping[1].category, "shipping".to_string());
     }
+
+    #[test]
+    fn test_address_locality() {
+        let mut address = Address::new(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        assert_eq!(address.city, Some("Big City".to_string()));
+        assert_eq!(address.region, Some("NY".to_string()));
+        assert_eq!(address.postal_code, Some("038845".to_string()));
+
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "Invalidenstrasse 10".to_string(),
+            "10115 Berlin".to_string(),
+            "Germany".to_string(),
+            "DEU".to_string(),
+        );
+
+        assert_eq!(address.city, Some("Berlin".to_string()));
+        assert_eq!(address.region, None);
+        assert_eq!(address.postal_code, Some("10115".to_string()));
+
+        address.erase();
+        assert_eq!(address.city, None);
+        assert_eq!(address.postal_code, None);
+    }
+
+    #[test]
+    fn test_address_parse_locality() {
+        assert_eq!(
+            Address::parse_locality("London, SW1A 2AA"),
+            (
+                Some("London".to_string()),
+                None,
+                Some("SW1A 2AA".to_string())
+            )
+        );
+        assert_eq!(
+            Address::parse_locality("Springfield 62704"),
+            (
+                Some("Springfield".to_string()),
+                None,
+                Some("62704".to_string())
+            )
+        );
+        assert_eq!(
+            Address::parse_locality("Tiny Town"),
+            (Some("Tiny Town".to_string()), None, None)
+        );
+        assert_eq!(Address::parse_locality(""), (None, None, None));
+    }
+
+    #[test]
+    fn test_entity_addresses_by_locality() {
+        let mut entity = MyEntity::new();
+
+        let _ = entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let _ = entity.insert_address(
+            "home".to_string(),
+            "Peter Petty".to_string(),
+            "23 Corner Lane".to_string(),
+            "Tiny Town, VT 044567".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        assert_eq!(entity.search_addresses_by_region("vt".to_string()).len(), 1);
+        assert_eq!(
+            entity
+                .search_addresses_by_postal_code("038845".to_string())
+                .len(),
+            1
+        );
+        assert_eq!(
+            entity
+                .search_addresses_by_postal_code("99999".to_string())
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_address_validate_postal_code() {
+        let countries = Countries::new();
+        let mut address = Address::new(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884-1234".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert!(address.validate_postal_code(&countries).is_ok());
+
+        // too many digits for a US zip code
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert!(address.validate_postal_code(&countries).is_err());
+
+        // missing postal code
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert!(address.validate_postal_code(&countries).is_err());
+
+        // unknown country
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "Nowhere".to_string(),
+            "XXX".to_string(),
+        );
+        assert!(address.validate_postal_code(&countries).is_err());
+
+        // an invalid postal code pattern is reported instead of panicking
+        let mut countries = Countries::new();
+        for country in countries.list.iter_mut().filter(|c| c.iso_3_code == "USA") {
+            country.postal_code_pattern = Some("[0-9".to_string());
+        }
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert!(matches!(
+            address.validate_postal_code(&countries),
+            Err(scaffolding_core::errors::ScaffoldingError::PostalCodePattern { .. })
+        ));
+    }
+
+    #[test]
+    fn test_address_validate_region() {
+        let countries = Countries::new();
+        let mut address = Address::new(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert!(address.validate_region(&countries).is_ok());
+
+        // the name of the subdivision
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "Marienplatz 8".to_string(),
+            "München, Bayern 80331".to_string(),
+            "Germany".to_string(),
+            "DEU".to_string(),
+        );
+        assert!(address.validate_region(&countries).is_ok());
+
+        // not a subdivision of the country
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, XY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert!(address.validate_region(&countries).is_err());
+
+        // outside of the previously bundled countries
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Kabul, KAB".to_string(),
+            "Afghanistan".to_string(),
+            "AFG".to_string(),
+        );
+        assert!(address.validate_region(&countries).is_ok());
+
+        // no subdivisions
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Oranjestad".to_string(),
+            "Aruba".to_string(),
+            "ABW".to_string(),
+        );
+        assert!(address.validate_region(&countries).is_ok());
+
+        // a region that cannot be verified
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Oranjestad, XY".to_string(),
+            "Aruba".to_string(),
+            "ABW".to_string(),
+        );
+        assert!(address.validate_region(&countries).is_err());
+
+        // unknown country
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "Nowhere".to_string(),
+            "XXX".to_string(),
+        );
+        assert!(address.validate_region(&countries).is_err());
+    }
+
+    #[test]
+    fn test_address_format() {
+        let countries = Countries::new();
+        let mut address = Address::new(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        assert_eq!(
+            address.format(&countries, AddressStyle::Label),
+            "acmes company\n14 Main Street\nBig City, NY 03884\nUNITED STATES"
+        );
+
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "10 Downing Street".to_string(),
+            "London, SW1A 2AA".to_string(),
+            "United Kingdom".to_string(),
+            "GBR".to_string(),
+        );
+
+        assert_eq!(
+            address.format(&countries, AddressStyle::Label),
+            "acmes company\n10 Downing Street\nLondon\nSW1A 2AA\nUNITED KINGDOM"
+        );
+
+        // the third line is kept when the city is unknown
+        address.update(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "Nowhere".to_string(),
+            "XXX".to_string(),
+        );
+
+        assert_eq!(
+            address.format(&countries, AddressStyle::SingleLine),
+            "acmes company, Nowhere"
+        );
+    }
+
+    #[test]
+    fn test_entity_geocode_addresses() {
+        let mut entity = MyEntity::new();
+        let found = entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let missing = entity.insert_address(
+            "home".to_string(),
+            "Peter Petty".to_string(),
+            "23 Corner Lane".to_string(),
+            "Tiny Town, VT 04456".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        assert_eq!(
+            entity.geocode_addresses(&BigCityGeocoder),
+            vec![missing.clone()]
+        );
+
+        let address = entity.get_address(found.clone()).unwrap();
+        assert_eq!(address.latitude, Some(40.7128));
+        assert_eq!(address.longitude, Some(-74.006));
+        assert_eq!(entity.get_address(missing).unwrap().latitude, None);
+
+        // the location is cleared when the address changes
+        entity.modify_address(
+            found.clone(),
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "16 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert_eq!(entity.get_address(found).unwrap().latitude, None);
+    }
+
+    #[test]
+    fn test_entity_addresses_within() {
+        let mut entity = MyEntity::new();
+        let warehouses = [
+            ("London, SW1A 2AA", "GBR", 51.5034, -0.1276),
+            ("75008 Paris", "FRA", 48.8704, 2.3167),
+            ("10115 Berlin", "DEU", 52.5200, 13.4050),
+        ];
+
+        for (line_3, country_code, latitude, longitude) in warehouses {
+            let id = entity.insert_address(
+                "warehouse".to_string(),
+                "acmes company".to_string(),
+                "1 Depot Road".to_string(),
+                line_3.to_string(),
+                country_code.to_string(),
+                country_code.to_string(),
+            );
+            let address = entity.addresses.get_mut(&id).unwrap();
+            address.latitude = Some(latitude);
+            address.longitude = Some(longitude);
+        }
+        // not geocoded
+        let _ = entity.insert_address(
+            "warehouse".to_string(),
+            "acmes company".to_string(),
+            "1 Depot Road".to_string(),
+            "1000 Brussels".to_string(),
+            "Belgium".to_string(),
+            "BEL".to_string(),
+        );
+
+        // Brussels
+        let nearest = entity.search_addresses_within((50.8503, 4.3517), 400.0);
+        assert_eq!(nearest.len(), 2);
+        assert_eq!(nearest[0].city, Some("Paris".to_string()));
+        assert_eq!(nearest[1].city, Some("London".to_string()));
+
+        assert_eq!(
+            entity
+                .search_addresses_within((50.8503, 4.3517), 1000.0)
+                .len(),
+            3
+        );
+        assert!(entity
+            .search_addresses_within((0.0, 0.0), 1000.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_entity_primary_address() {
+        let mut entity = MyEntity::new();
+        let shipping = entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let billing = entity.insert_address(
+            "billing".to_string(),
+            "acmes company".to_string(),
+            "PO Box 12".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        entity.set_primary_address(shipping.clone());
+        entity.set_primary_address(billing.clone());
+        entity.set_primary_address("unknown".to_string());
+
+        // one primary per category
+        assert_eq!(
+            entity
+                .get_primary_address("shipping".to_string())
+                .unwrap()
+                .id,
+            shipping
+        );
+        assert_eq!(
+            entity
+                .get_primary_address("billing".to_string())
+                .unwrap()
+                .id,
+            billing
+        );
+
+        // moving to another category drops the designation
+        entity.modify_address(
+            shipping.clone(),
+            "billing".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        assert!(!entity.get_address(shipping).unwrap().primary);
+        assert_eq!(
+            entity
+                .get_primary_address("billing".to_string())
+                .unwrap()
+                .id,
+            billing
+        );
+        assert!(entity.get_primary_address("shipping".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_entity_addresses_by_country() {
+        let mut entity = MyEntity::new();
+        let addresses = [
+            ("Big City, NY 03884", "USA"),
+            ("Tiny Town, VT 04456", "USA"),
+            ("London, SW1A 2AA", "GBR"),
+        ];
+
+        for (line_3, country_code) in addresses {
+            let _ = entity.insert_address(
+                "shipping".to_string(),
+                "acmes company".to_string(),
+                "1 Main Street".to_string(),
+                line_3.to_string(),
+                country_code.to_string(),
+                country_code.to_string(),
+            );
+        }
+
+        assert_eq!(
+            entity.search_addresses_by_country("USA".to_string()).len(),
+            2
+        );
+        assert_eq!(
+            entity.search_addresses_by_country("gbr".to_string()).len(),
+            1
+        );
+        assert!(entity
+            .search_addresses_by_country("DEU".to_string())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_entity_address_history() {
+        let mut entity = MyEntity::new();
+        let now = Utc::now().timestamp();
+        let first = entity.insert_address(
+            "home".to_string(),
+            "Peter Petty".to_string(),
+            "23 Corner Lane".to_string(),
+            "Tiny Town, VT 04456".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let second = entity.insert_address(
+            "home".to_string(),
+            "Peter Petty".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let future = entity.insert_address(
+            "home".to_string(),
+            "Peter Petty".to_string(),
+            "1 Beach Road".to_string(),
+            "Sunny Shore, FL 33101".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        entity.addresses.get_mut(&first).unwrap().valid_to = Some(now - 1000);
+        entity.addresses.get_mut(&second).unwrap().valid_from = Some(now - 1000);
+        entity.addresses.get_mut(&second).unwrap().valid_to = Some(now + 1000);
+        entity.addresses.get_mut(&future).unwrap().valid_from = Some(now + 1000);
+
+        assert_eq!(
+            entity
+                .address_as_of(now - 2000, "home".to_string())
+                .unwrap()
+                .id,
+            first
+        );
+        assert_eq!(
+            entity.current_address("home".to_string()).unwrap().id,
+            second
+        );
+        assert_eq!(
+            entity
+                .address_as_of(now + 1000, "home".to_string())
+                .unwrap()
+                .id,
+            future
+        );
+        assert!(entity.current_address("billing".to_string()).is_none());
+
+        // the primary address is preferred when several are in effect
+        entity.addresses.get_mut(&first).unwrap().valid_to = None;
+        assert_eq!(
+            entity.current_address("home".to_string()).unwrap().id,
+            second
+        );
+        entity.set_primary_address(first.clone());
+        assert_eq!(
+            entity.current_address("home".to_string()).unwrap().id,
+            first
+        );
+    }
 }
@@ -0,0 +1,74 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("all")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingAddresses,
+        ScaffoldingEmailAddresses,
+        ScaffoldingNotes,
+        ScaffoldingPhoneNumbers,
+        ScaffoldingRelationships,
+        ScaffoldingTags,
+    )]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("all")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct("all", "status", "tenant")]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingStatus, ScaffoldingTags, ScaffoldingTenant)]
+    struct MyTenantEntity {}
+
+    impl MyTenantEntity {
+        #[scaffolding_fn("all", "status", "tenant")]
+        fn new() -> Self {
+            Self {
+                tenant_id: "acme".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_collections() {
+        let mut entity = MyEntity::new();
+
+        assert_eq!(entity.addresses.len(), 0);
+        assert_eq!(entity.email_addresses.len(), 0);
+        assert_eq!(entity.metadata.len(), 0);
+        assert_eq!(entity.notes.len(), 0);
+        assert_eq!(entity.phone_numbers.len(), 0);
+        assert_eq!(entity.relationships.len(), 0);
+        assert_eq!(entity.tags.len(), 0);
+
+        entity.add_tag("customer".to_string());
+        let _ = entity.insert_email_address("home".to_string(), "fsmith@example.com".to_string());
+        entity
+            .metadata
+            .insert("name".to_string(), "Frank".to_string());
+
+        assert!(entity.has_tag("customer".to_string()));
+        assert_eq!(entity.email_addresses.len(), 1);
+        assert_eq!(entity.metadata.len(), 1);
+    }
+
+    #[test]
+    fn test_all_with_other_options() {
+        let entity = MyTenantEntity::new();
+
+        assert_eq!(entity.notes.len(), 0);
+        assert_eq!(entity.status, defaults::status());
+        assert!(entity.belongs_to_tenant("acme".to_string()));
+    }
+}
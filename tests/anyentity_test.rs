@@ -0,0 +1,150 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    struct Customer {
+        name: String,
+    }
+
+    impl Customer {
+        #[scaffolding_fn]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct("tags")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTags)]
+    struct Order {
+        total: u32,
+    }
+
+    impl Order {
+        #[scaffolding_fn("tags")]
+        fn new(total: u32) -> Self {
+            Self { total }
+        }
+    }
+
+    fn get_registry() -> EntityRegistry {
+        let mut registry = EntityRegistry::new();
+        registry.register::<Customer>("customer");
+        registry.register::<Order>("order");
+        registry
+    }
+
+    #[test]
+    fn test_any_entity_downcast() {
+        let customer = Customer::new("Frank");
+        let mut entity = AnyEntity::new("customer", customer.clone());
+
+        assert!(entity.is::<Customer>());
+        assert!(!entity.is::<Order>());
+        assert!(entity.downcast_ref::<Order>().is_none());
+        entity.downcast_mut::<Customer>().unwrap().name = "Frank Sinatra".to_string();
+
+        let entity = entity.downcast::<Order>().unwrap_err();
+        let copy = entity.downcast::<Customer>().unwrap();
+        assert_eq!(copy.id, customer.id);
+        assert_eq!(copy.name, "Frank Sinatra".to_string());
+    }
+
+    #[test]
+    fn test_any_entity_scaffolding() {
+        let order = Order::new(100);
+        let mut entity = AnyEntity::new("order", order.clone());
+        entity.deactivate();
+        entity
+            .entity_mut()
+            .log_activity("paid".to_string(), "The order was paid".to_string());
+
+        assert_eq!(entity.get_id(), order.id);
+        assert_eq!(
+            entity
+                .entity()
+                .get_activity("deactivated".to_string())
+                .len(),
+            1
+        );
+        assert_eq!(entity.get_activity("paid".to_string()).len(), 1);
+        assert_eq!(
+            entity.downcast_ref::<Order>().unwrap().inactive_dtm,
+            defaults::now()
+        );
+    }
+
+    #[test]
+    fn test_any_entity_collection() {
+        let mut collection = EntityCollection::new();
+        collection
+            .list
+            .push(AnyEntity::new("customer", Customer::new("Frank")));
+        collection
+            .list
+            .push(AnyEntity::new("order", Order::new(100)));
+        collection.list[1].expire_now();
+
+        let orders: Vec<&Order> = collection
+            .list
+            .iter()
+            .filter_map(|e| e.downcast_ref::<Order>())
+            .collect();
+
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].total, 100);
+        assert_eq!(
+            collection.list[1].get_activity("expired".to_string()).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_registry_roundtrip() {
+        let registry = get_registry();
+        let mut order = Order::new(100);
+        order.add_tag("rush".to_string());
+        let mut entities = [
+            AnyEntity::new("customer", Customer::new("Frank")),
+            AnyEntity::new("order", order.clone()),
+        ];
+
+        let loaded: Vec<AnyEntity> = entities
+            .iter_mut()
+            .map(|e| registry.deserialized(e.serialize().as_bytes()).unwrap())
+            .collect();
+
+        assert_eq!(
+            registry.kinds(),
+            vec!["customer".to_string(), "order".to_string()]
+        );
+        assert_eq!(loaded[0].kind(), "customer");
+        assert_eq!(
+            loaded[0].downcast_ref::<Customer>().unwrap().name,
+            "Frank".to_string()
+        );
+        assert_eq!(loaded[1].get_id(), order.id);
+        assert_eq!(loaded[1].downcast_ref::<Order>().unwrap().tags, order.tags);
+    }
+
+    #[test]
+    fn test_registry_unknown_kind() {
+        let registry = get_registry();
+        let entity = AnyEntity::new("invoice", Order::new(100));
+
+        assert!(registry
+            .deserialized(entity.serialize().as_bytes())
+            .is_err());
+        assert!(registry.deserialized(b"not json").is_err());
+        assert!(registry
+            .deserialized(b"{\"kind\": \"invoice\"}")
+            .unwrap_err()
+            .to_string()
+            .contains("AnyEntity"));
+    }
+}
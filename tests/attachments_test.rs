@@ -0,0 +1,133 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("attachments")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingAttachments,
+        ScaffoldingErasure,
+        ScaffoldingRetention,
+    )]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("attachments")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    fn get_entity() -> (MyEntity, String, String) {
+        let mut entity = MyEntity::new();
+        let pdf = entity.insert_attachment(
+            "Invoice-2024.pdf".to_string(),
+            "application/pdf".to_string(),
+            vec![37, 80, 68, 70],
+        );
+        let png = entity.insert_attachment_locator(
+            "passport.png".to_string(),
+            "image/png".to_string(),
+            "s3://documents/passport.png".to_string(),
+        );
+        let _ = entity.insert_attachment(
+            "photo.jpeg".to_string(),
+            "image/jpeg".to_string(),
+            Vec::new(),
+        );
+
+        (entity, pdf, png)
+    }
+
+    #[test]
+    fn test_attachment_insert_get_remove() {
+        let (mut entity, pdf, png) = get_entity();
+        assert_eq!(entity.attachments.len(), 3);
+
+        let attachment = entity.get_attachment(pdf.clone()).unwrap();
+        assert_eq!(attachment.filename, "Invoice-2024.pdf".to_string());
+        assert_eq!(attachment.size(), 4);
+        assert!(attachment.locator.is_none());
+
+        let attachment = entity.get_attachment(png.clone()).unwrap();
+        assert_eq!(attachment.size(), 0);
+        assert_eq!(
+            attachment.locator,
+            Some("s3://documents/passport.png".to_string())
+        );
+
+        entity.remove_attachment(pdf.clone());
+        assert!(entity.get_attachment(pdf).is_none());
+        assert_eq!(entity.attachments.len(), 2);
+    }
+
+    #[test]
+    fn test_attachment_search() {
+        let (entity, pdf, _png) = get_entity();
+
+        let found = entity.search_attachments_by_filename("INVOICE".to_string());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, pdf);
+        assert_eq!(
+            entity.search_attachments_by_filename("p".to_string()).len(),
+            3
+        );
+
+        assert_eq!(
+            entity
+                .search_attachments_by_mime_type("image/*".to_string())
+                .len(),
+            2
+        );
+        assert_eq!(
+            entity
+                .search_attachments_by_mime_type("application/pdf".to_string())
+                .len(),
+            1
+        );
+        assert_eq!(
+            entity
+                .search_attachments_by_mime_type("text/*".to_string())
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_attachment_serialization() {
+        let (entity, pdf, png) = get_entity();
+        let loaded = MyEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+
+        assert_eq!(loaded.attachments, entity.attachments);
+        assert_eq!(
+            loaded.get_attachment(pdf).unwrap().bytes,
+            Some(vec![37, 80, 68, 70])
+        );
+        assert!(loaded.get_attachment(png).unwrap().bytes.is_none());
+    }
+
+    #[test]
+    fn test_attachment_erasure_and_retention() {
+        let (mut entity, pdf, png) = get_entity();
+        entity.attachments.get_mut(&pdf).unwrap().created_dtm =
+            defaults::add_days(defaults::now(), -31);
+
+        let report = entity.apply_retention(&[RetentionPolicy::RemoveOlderThan {
+            attribute: "attachments".to_string(),
+            days: 30,
+        }]);
+        assert_eq!(report.records.get("attachments"), Some(&vec![pdf]));
+        assert_eq!(entity.attachments.len(), 2);
+
+        entity.erase_personal_data(Vec::new());
+        let attachment = entity.get_attachment(png).unwrap();
+        assert_eq!(attachment.filename, defaults::redacted());
+        assert!(attachment.locator.is_none());
+    }
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "axum")]
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use axum::body::{to_bytes, Body};
+    use axum::extract::{FromRequest, Request};
+    use axum::http::{header, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use scaffolding_core::errors::ScaffoldingError;
+    use scaffolding_core::*;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    #[scaffolding_struct("email_addresses")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingEmailAddresses,
+        ScaffoldingValidate,
+    )]
+    struct Customer {
+        #[validate(length(min = 1, max = 20))]
+        name: String,
+    }
+
+    impl Customer {
+        #[scaffolding_fn("email_addresses")]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    // the bodies are in memory, so a single poll is enough
+    fn block_on<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the future is not ready"),
+        }
+    }
+
+    fn request(content_type: &str, body: String) -> Request {
+        Request::builder()
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    fn extract(request: Request) -> Customer {
+        match block_on(Entity::<Customer>::from_request(request, &())) {
+            Ok(Entity(customer)) => customer,
+            Err(response) => panic!("the request was rejected with {}", response.status()),
+        }
+    }
+
+    fn reject(request: Request) -> Response {
+        match block_on(Entity::<Customer>::from_request(request, &())) {
+            Ok(_) => panic!("the request was accepted"),
+            Err(response) => response,
+        }
+    }
+
+    fn json(response: Response) -> Value {
+        let body = block_on(to_bytes(response.into_body(), usize::MAX)).unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn test_entity_extract() {
+        let customer = Customer::new("Frank");
+        let loaded = extract(request("application/json", customer.serialize()));
+
+        assert_eq!(loaded.id, customer.id);
+        assert_eq!(loaded.name, "Frank");
+    }
+
+    #[test]
+    fn test_entity_extract_invalid() {
+        let mut customer = Customer::new("");
+        customer.insert_email_address("home".to_string(), "frank@".to_string());
+
+        let response = reject(request("application/json", customer.serialize()));
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let report: ValidationReport = serde_json::from_value(json(response)).unwrap();
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.violations[0].field, "name");
+        assert_eq!(report.violations[1].code, "email");
+    }
+
+    #[test]
+    fn test_entity_extract_rejected() {
+        let customer = Customer::new("Frank");
+
+        let response = reject(request("text/plain", customer.serialize()));
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let response = reject(request("application/json", "{".to_string()));
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = reject(request(
+            "application/json",
+            r#"{"name":"Frank"}"#.to_string(),
+        ));
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(json(response)["error"]
+            .as_str()
+            .unwrap()
+            .contains("missing field"));
+    }
+
+    #[test]
+    fn test_entity_response() {
+        let customer = Customer::new("Frank");
+        let response = Entity(customer.clone()).into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(json(response)["id"], customer.id);
+    }
+
+    #[test]
+    fn test_error_response() {
+        let response = ScaffoldingError::NotFound {
+            id: "1234".to_string(),
+        }
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(json(response)["error"], "The object 1234 was not found.");
+
+        let response = ScaffoldingError::Storage("disk is full".into()).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            json(response)["error"],
+            "Unable to read or write the storage."
+        );
+
+        let mut report = ValidationReport::new();
+        report.add("name", "required", "is required");
+        let response = ScaffoldingError::Validation(report).into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json(response)["violations"][0]["field"], "name");
+    }
+}
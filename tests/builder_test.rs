@@ -0,0 +1,82 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("metadata", "status", "tags")]
+    #[derive(
+        Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingBuilder, ScaffoldingTags,
+    )]
+    struct MyEntity {
+        name: String,
+        age: u8,
+        nickname: Option<String>,
+    }
+
+    #[scaffolding_struct]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingBuilder)]
+    struct Wrapper<T: Clone> {
+        value: T,
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let entity = MyEntity::builder().name("Frank").age(42).build().unwrap();
+
+        assert_eq!(entity.name, "Frank".to_string());
+        assert_eq!(entity.age, 42);
+        assert_eq!(entity.nickname, None);
+        assert_eq!(
+            entity.id.len(),
+            "54324f57-9e6b-4142-b68d-1d4c86572d0a".len()
+        );
+        assert_eq!(entity.created_dtm, defaults::now());
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.inactive_dtm, defaults::add_days(defaults::now(), 90));
+        assert_eq!(entity.expired_dtm, defaults::add_years(defaults::now(), 3));
+        assert_eq!(entity.status, defaults::status());
+        assert_eq!(entity.activity.len(), 0);
+        assert_eq!(entity.metadata.len(), 0);
+        assert_eq!(entity.tags.len(), 0);
+    }
+
+    #[test]
+    fn test_builder_overrides() {
+        let entity = MyEntityBuilder::default()
+            .id("my-id")
+            .created_dtm(1711802687)
+            .expired_dtm(defaults::never())
+            .name("Frank")
+            .nickname("Frankie".to_string())
+            .age(42)
+            .tags(vec!["customer".to_string()])
+            .build()
+            .unwrap();
+
+        assert_eq!(entity.id, "my-id".to_string());
+        assert_eq!(entity.created_dtm, 1711802687);
+        assert_eq!(entity.expired_dtm, defaults::never());
+        assert_eq!(entity.nickname, Some("Frankie".to_string()));
+        assert!(entity.has_tag("customer".to_string()));
+    }
+
+    #[test]
+    fn test_builder_missing_required() {
+        let result = MyEntity::builder().name("Frank").build();
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unable to build, the required attribute age is missing.".to_string()
+        );
+    }
+
+    #[test]
+    fn test_builder_generics() {
+        let wrapper: Wrapper<Vec<i32>> = Wrapper::builder().value(vec![1, 2, 3]).build().unwrap();
+
+        assert_eq!(wrapper.value, vec![1, 2, 3]);
+        assert_eq!(wrapper.get_id(), wrapper.id);
+    }
+}
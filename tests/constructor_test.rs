@@ -0,0 +1,245 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    // runs a future to completion, (the futures in these tests never wait)
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    async fn lookup_name(id: u32) -> String {
+        format!("customer-{}", id)
+    }
+
+    #[scaffolding_struct("notes", "tags")]
+    #[derive(
+        Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes, ScaffoldingTags,
+    )]
+    struct MyEntity {
+        name: String,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("notes", "tags")]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn from_parts(first: &str, last: &str) -> Self {
+            Self {
+                name: format!("{} {}", first, last),
+                tags: vec!["imported".to_string()],
+            }
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn with_defaults() -> Self {
+            Self {
+                name: "unknown".to_string(),
+                expired_dtm: defaults::never(),
+            }
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn try_new(name: &str) -> Result<Self, String> {
+            if name.is_empty() {
+                return Err("The name is required".to_string());
+            }
+            Ok(Self {
+                name: name.to_string(),
+            })
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn parse(name: &str) -> Option<Self> {
+            match name.trim() {
+                "" => None,
+                trimmed => Some(Self {
+                    name: trimmed.to_string(),
+                }),
+            }
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn validated(name: &str) -> Result<Self, String> {
+            let mut entity = Self {
+                name: name.to_string(),
+            };
+            if entity.name.len() > 10 {
+                return Err("The name is too long".to_string());
+            }
+            entity.add_tag("validated".to_string());
+            Ok(entity)
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn early(name: &str) -> Result<Self, String> {
+            if name == "admin" {
+                return Ok(Self {
+                    name: "administrator".to_string(),
+                });
+            }
+            Err("Only the administrator is supported".to_string())
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        async fn load(id: u32) -> Self {
+            let name = lookup_name(id).await;
+            Self { name }
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        async fn load_tagged(id: u32) -> Result<Self, String> {
+            let mut entity = MyEntity {
+                name: lookup_name(id).await,
+            };
+            entity.add_tag("loaded".to_string());
+            Ok(entity)
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn renamed(&self, name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+                ..self.clone()
+            }
+        }
+
+        #[scaffolding_fn("constructor", "notes", "tags")]
+        fn anonymous() -> MyEntity {
+            MyEntity {
+                name: "anonymous".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_constructor_new() {
+        let entity = MyEntity::new("Frank");
+
+        assert_eq!(entity.name, "Frank".to_string());
+        assert_eq!(entity.created_dtm, defaults::now());
+        assert_eq!(entity.tags.len(), 0);
+    }
+
+    #[test]
+    fn test_constructor_from_parts() {
+        let entity = MyEntity::from_parts("Frank", "Smith");
+
+        assert_eq!(entity.name, "Frank Smith".to_string());
+        assert_eq!(
+            entity.id.len(),
+            "54324f57-9e6b-4142-b68d-1d4c86572d0a".len()
+        );
+        assert_eq!(entity.expired_dtm, defaults::add_years(defaults::now(), 3));
+        assert!(entity.has_tag("imported".to_string()));
+        assert_eq!(entity.notes.len(), 0);
+    }
+
+    #[test]
+    fn test_constructor_with_defaults() {
+        let entity = MyEntity::with_defaults();
+
+        assert_eq!(entity.name, "unknown".to_string());
+        assert_eq!(entity.expired_dtm, defaults::never());
+        assert_eq!(entity.inactive_dtm, defaults::add_days(defaults::now(), 90));
+    }
+
+    #[test]
+    fn test_constructor_marker() {
+        let entity = MyEntity::anonymous();
+
+        assert_eq!(entity.name, "anonymous".to_string());
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.activity.len(), 0);
+    }
+
+    #[test]
+    fn test_constructor_struct_update() {
+        let mut entity = MyEntity::new("Frank");
+        entity.add_tag("vip".to_string());
+        let renamed = entity.renamed("Francis");
+
+        assert_eq!(renamed.name, "Francis".to_string());
+        assert_eq!(renamed.id, entity.id);
+        assert_eq!(renamed.created_dtm, entity.created_dtm);
+        assert!(renamed.has_tag("vip".to_string()));
+        assert_eq!(renamed.activity.len(), entity.activity.len());
+    }
+
+    #[test]
+    fn test_constructor_result() {
+        let entity = MyEntity::try_new("Frank").unwrap();
+
+        assert_eq!(entity.name, "Frank".to_string());
+        assert_eq!(entity.created_dtm, defaults::now());
+        assert_eq!(entity.notes.len(), 0);
+        assert!(MyEntity::try_new("").is_err());
+    }
+
+    #[test]
+    fn test_constructor_option() {
+        let entity = MyEntity::parse(" Frank ").unwrap();
+
+        assert_eq!(entity.name, "Frank".to_string());
+        assert_eq!(
+            entity.id.len(),
+            "54324f57-9e6b-4142-b68d-1d4c86572d0a".len()
+        );
+        assert!(MyEntity::parse(" ").is_none());
+    }
+
+    #[test]
+    fn test_constructor_local_binding() {
+        let entity = MyEntity::validated("Frank").unwrap();
+
+        assert!(entity.has_tag("validated".to_string()));
+        assert_eq!(entity.expired_dtm, defaults::add_years(defaults::now(), 3));
+        assert!(MyEntity::validated("Frank Smith Jr.").is_err());
+    }
+
+    #[test]
+    fn test_constructor_early_return() {
+        let entity = MyEntity::early("admin").unwrap();
+
+        assert_eq!(entity.name, "administrator".to_string());
+        assert_eq!(entity.activity.len(), 0);
+        assert!(MyEntity::early("guest").is_err());
+    }
+
+    #[test]
+    fn test_constructor_async() {
+        let entity = block_on(MyEntity::load(7));
+
+        assert_eq!(entity.name, "customer-7".to_string());
+        assert_eq!(entity.created_dtm, defaults::now());
+        assert_eq!(entity.tags.len(), 0);
+    }
+
+    #[test]
+    fn test_constructor_async_not_last_statement() {
+        let entity = block_on(MyEntity::load_tagged(7)).unwrap();
+
+        assert_eq!(entity.name, "customer-7".to_string());
+        assert_eq!(
+            entity.id.len(),
+            "54324f57-9e6b-4142-b68d-1d4c86572d0a".len()
+        );
+        assert!(entity.has_tag("loaded".to_string()));
+        assert_eq!(entity.notes.len(), 0);
+    }
+}
@@ -0,0 +1,105 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("notes", "status", "tags")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingConvert,
+        ScaffoldingNotes,
+        ScaffoldingStatus,
+        ScaffoldingTags,
+    )]
+    struct Prospect {
+        name: String,
+    }
+
+    impl Prospect {
+        #[scaffolding_fn("notes", "status", "tags")]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct("default", "status", "tags", exclude("activity"))]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingConvert)]
+    struct Customer {
+        account: String,
+    }
+
+    #[scaffolding_struct(id_type = "u64")]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingConvert)]
+    struct Numbered {}
+
+    impl Numbered {
+        #[scaffolding_fn]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[test]
+    fn test_convert_core_attributes() {
+        let prospect = Prospect::new("Frank");
+        let core = prospect.core_attributes();
+
+        assert_eq!(core.id, prospect.id);
+        assert_eq!(core.created_dtm, prospect.created_dtm);
+        assert_eq!(core.inactive_dtm, Some(prospect.inactive_dtm));
+        assert_eq!(core.status, Some(prospect.status.clone()));
+        assert!(core.addresses.is_none());
+        assert!(core.notes.is_some());
+    }
+
+    #[test]
+    fn test_convert_adopt_core() {
+        let mut prospect = Prospect::new("Frank");
+        prospect.add_tag("vip".to_string());
+        assert!(prospect.transition_to("active".to_string()).is_ok());
+
+        let mut customer = Customer {
+            account: "ACC-100".to_string(),
+            ..Default::default()
+        };
+        customer.adopt_core(&prospect);
+
+        assert_eq!(customer.account, "ACC-100".to_string());
+        assert_eq!(customer.id, prospect.id);
+        assert_eq!(customer.created_dtm, prospect.created_dtm);
+        assert_eq!(customer.expired_dtm, prospect.expired_dtm);
+        assert_eq!(customer.status, "active".to_string());
+        assert_eq!(customer.tags, vec!["vip".to_string()]);
+    }
+
+    #[test]
+    fn test_convert_from_scaffolding() {
+        let mut prospect = Prospect::new("Frank");
+        prospect.log_activity("contacted".to_string(), "Called Frank".to_string());
+        let customer = Customer::from_scaffolding(&prospect);
+
+        assert_eq!(customer.id, prospect.id);
+        assert_eq!(customer.account, String::new());
+        assert_eq!(customer.get_activity("contacted".to_string()).len(), 0);
+
+        let mut copy = Prospect::new("Frank");
+        copy.adopt_core(&prospect);
+        assert_eq!(copy.get_activity("contacted".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_convert_id_type() {
+        let first = Numbered::new();
+        let mut second = Numbered::new();
+        second.adopt_core(&first);
+
+        assert_eq!(second.id, first.id);
+    }
+}
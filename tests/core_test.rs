@@ -1,3 +1,4 @@
+#![allow(clippy::bool_assert_comparison)]
 extern crate scaffolding_core;
 
 #[cfg(test)]
@@ -40,7 +41,11 @@ mod tests {
         assert_eq!(entity.created_dtm, now);
         assert_eq!(entity.modified_dtm, now);
         assert_eq!((entity.inactive_dtm - entity.modified_dtm) / 86400, 90);
-        assert_eq!((entity.expired_dtm - entity.modified_dtm) / 86400, 1095);
+        // 3 years are 1096 days when they span a leap day
+        assert!(matches!(
+            (entity.expired_dtm - entity.modified_dtm) / 86400,
+            1095 | 1096
+        ));
 
         // extended attributes
         assert_eq!(entity.b, true);
@@ -69,6 +74,313 @@ mod tests {
 
         assert_eq!(entity.activity.len(), 3);
         assert_eq!(entity.get_activity("updated".to_string()).len(), 2);
+
+        let cancelled = entity.get_activity_ref("cancelled");
+        assert_eq!(cancelled.len(), 1);
+        assert!(std::ptr::eq(cancelled[0], &entity.activity[2]));
+    }
+
+    #[test]
+    fn test_entity_activity_with_data() {
+        let mut entity = MyEntity::new(true);
+
+        entity.log_activity(
+            "updated".to_string(),
+            "The object has been updated".to_string(),
+        );
+        entity.log_activity_with_data(
+            "updated".to_string(),
+            "The status has been changed".to_string(),
+            json!({"field": "status", "old": "open", "new": "closed"}),
+        );
+
+        let updated = entity.get_activity("updated".to_string());
+        assert_eq!(updated.len(), 2);
+        assert!(updated[0].data.is_none());
+        assert_eq!(updated[1].data.as_ref().unwrap()["field"], "status");
+        assert_eq!(updated[1].data.as_ref().unwrap()["new"], "closed");
+    }
+
+    #[test]
+    fn test_entity_redact_activity() {
+        let mut entity = MyEntity::new(true);
+
+        entity.log_activity(
+            "updated".to_string(),
+            "The object has been updated".to_string(),
+        );
+        entity.log_activity_with_data(
+            "contacted".to_string(),
+            "Emailed fsmith@example.com".to_string(),
+            json!({"email": "fsmith@example.com"}),
+        );
+        let created_dtm = entity.activity[1].created_dtm;
+
+        let count = entity.redact_activity(&|a| a.action == "contacted");
+        assert_eq!(count, 1);
+        assert_eq!(entity.activity.len(), 2);
+
+        let contacted = entity.get_activity("contacted".to_string());
+        assert_eq!(contacted[0].created_dtm, created_dtm);
+        assert_eq!(contacted[0].description, defaults::redacted());
+        assert_eq!(contacted[0].data, Some(json!(defaults::redacted())));
+
+        let updated = entity.get_activity("updated".to_string());
+        assert_eq!(updated[0].description, "The object has been updated");
+        assert!(updated[0].data.is_none());
+
+        assert_eq!(entity.redact_activity(&|a| a.action == "missing"), 0);
+    }
+
+    #[test]
+    fn test_entity_activity_chain() {
+        let mut entity = MyEntity::new(true);
+
+        entity.log_activity(
+            "created".to_string(),
+            "The object has been created".to_string(),
+        );
+        assert!(!entity.verify_activity_chain());
+
+        entity.enable_activity_chain();
+        entity.log_activity(
+            "updated".to_string(),
+            "The object has been updated".to_string(),
+        );
+        entity.log_activity_with_data(
+            "updated".to_string(),
+            "The status has been changed".to_string(),
+            json!({"old": "open", "new": "closed"}),
+        );
+        assert_eq!(entity.activity.len(), 4);
+        assert_eq!(entity.get_activity("chained".to_string()).len(), 1);
+        assert!(entity.activity.iter().all(|a| a.hash.is_some()));
+        assert!(entity.verify_activity_chain());
+
+        // the chain survives serialization
+        let json = entity.serialize();
+        let deserialized = MyEntity::deserialized(json.as_bytes()).unwrap();
+        assert!(deserialized.verify_activity_chain());
+
+        // modified payload
+        let mut tampered = entity.clone();
+        tampered.activity[3].data = Some(json!({"old": "open", "new": "open"}));
+        assert!(!tampered.verify_activity_chain());
+
+        // removed item
+        let mut tampered = entity.clone();
+        tampered.activity.remove(1);
+        assert!(!tampered.verify_activity_chain());
+
+        // reordered items
+        let mut tampered = entity.clone();
+        tampered.activity.swap(1, 2);
+        assert!(!tampered.verify_activity_chain());
+    }
+
+    #[test]
+    fn test_entity_enable_activity_chain_again() {
+        let mut entity = MyEntity::new(true);
+
+        entity.enable_activity_chain();
+        entity.log_activity(
+            "updated".to_string(),
+            "The object has been updated".to_string(),
+        );
+
+        // enabling it again does not re-hash the activity log
+        entity.enable_activity_chain();
+        assert_eq!(entity.get_activity("chained".to_string()).len(), 1);
+        assert!(entity.verify_activity_chain());
+
+        // the tampering is still detected
+        entity.activity[1].description = "The object has been tampered with".to_string();
+        assert!(!entity.verify_activity_chain());
+        entity.enable_activity_chain();
+        assert!(!entity.verify_activity_chain());
+
+        // the items added without the chain are chained to the last hashed one
+        let mut entity = MyEntity::new(true);
+        entity.enable_activity_chain();
+        entity.activity.push(ActivityItem::new(
+            "imported".to_string(),
+            "The object has been imported".to_string(),
+        ));
+        assert!(!entity.verify_activity_chain());
+        let hash = entity.activity[0].hash.clone();
+        entity.enable_activity_chain();
+        assert_eq!(entity.activity[0].hash, hash);
+        assert_eq!(entity.get_activity("chained".to_string()).len(), 2);
+        assert!(entity.verify_activity_chain());
+    }
+
+    #[test]
+    fn test_entity_redact_activity_chain() {
+        let mut entity = MyEntity::new(true);
+
+        entity.enable_activity_chain();
+        entity.log_activity(
+            "contacted".to_string(),
+            "Called the customer at 8482493561".to_string(),
+        );
+        entity.log_activity(
+            "updated".to_string(),
+            "The object has been updated".to_string(),
+        );
+
+        assert_eq!(entity.redact_activity(&|a| a.action == "contacted"), 1);
+        assert!(entity.verify_activity_chain());
+        assert_eq!(entity.activity.len(), 4);
+        assert_eq!(
+            entity.get_activity("redacted".to_string())[0].data,
+            Some(json!({"count": 1}))
+        );
+
+        // a tampered activity log is not re-chained
+        entity.activity[1].description = "Called the customer".to_string();
+        entity.redact_activity(&|a| a.action == "updated");
+        assert!(!entity.verify_activity_chain());
+    }
+
+    #[test]
+    fn test_entity_export_activity() {
+        let json = r#"{
+            "b":true,
+            "n":253402261199,
+            "id":"b4d6c6db-7468-400a-8536-a5e83b1f2bdc",
+            "created_dtm":1711802687,
+            "modified_dtm":1711802687,
+            "inactive_dtm":1719578687,
+            "expired_dtm":1806410687,
+            "activity":[
+                {
+                    "created_dtm":1711802600,
+                    "action":"created",
+                    "description":"The object has been created"
+                },
+                {
+                    "created_dtm":1711802687,
+                    "action":"updated",
+                    "description":"The object has been \"updated\", again",
+                    "data":{"old":"open","new":"closed"}
+                },
+                {
+                    "created_dtm":1711802700,
+                    "action":"cancelled",
+                    "description":"The object has been cancelled"
+                }
+                ]
+            }"#;
+        let entity = MyEntity::deserialized(json.as_bytes()).unwrap();
+
+        let csv = entity.export_activity(ExportFormat::Csv, None, None);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "created_dtm,action,description,data");
+        assert_eq!(lines[1], "1711802600,created,The object has been created,");
+        assert_eq!(
+            lines[2],
+            r#"1711802687,updated,"The object has been ""updated"", again","{""new"":""closed"",""old"":""open""}""#
+        );
+
+        let jsonl =
+            entity.export_activity(ExportFormat::JsonLines, Some(1711802687), Some(1711802700));
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            ActivityItem::deserialized(lines[0].as_bytes())
+                .unwrap()
+                .action,
+            "updated"
+        );
+
+        let csv = entity.export_activity(ExportFormat::Csv, None, Some(1711802600));
+        assert_eq!(csv.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_entity_etag() {
+        let json = r#"{
+            "b":true,
+            "n":253402261199,
+            "id":"b4d6c6db-7468-400a-8536-a5e83b1f2bdc",
+            "created_dtm":1711802687,
+            "modified_dtm":1711802687,
+            "inactive_dtm":1719578687,
+            "expired_dtm":1806410687,
+            "activity":[]
+            }"#;
+        let mut entity = MyEntity::deserialized(json.as_bytes()).unwrap();
+        let etag = entity.etag(&[]);
+        let stable = entity.etag(&["modified_dtm", "activity"]);
+
+        // the same content always produces the same etag
+        assert_eq!(etag.len(), 64);
+        assert_eq!(
+            MyEntity::deserialized(json.as_bytes()).unwrap().etag(&[]),
+            etag
+        );
+
+        // volatile attributes can be excluded
+        entity.modified_dtm = defaults::now();
+        entity.log_activity(
+            "viewed".to_string(),
+            "The object has been viewed".to_string(),
+        );
+        assert_ne!(entity.etag(&[]), etag);
+        assert_eq!(entity.etag(&["modified_dtm", "activity"]), stable);
+
+        // changes to the content change the etag
+        entity.b = false;
+        assert_ne!(entity.etag(&["modified_dtm", "activity"]), stable);
+    }
+
+    #[test]
+    fn test_entity_deactivate() {
+        let mut entity = MyEntity::new(true);
+        entity.modified_dtm = 1711802687;
+        entity.deactivate();
+
+        assert_eq!(entity.inactive_dtm, defaults::now());
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.get_activity("deactivated".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_entity_expire_now() {
+        let mut entity = MyEntity::new(true);
+        entity.modified_dtm = 1711802687;
+        entity.expire_now();
+
+        assert_eq!(entity.expired_dtm, defaults::now());
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.get_activity("expired".to_string()).len(), 1);
+        assert!(entity.reactivate(30, 90).is_err());
+    }
+
+    #[test]
+    fn test_entity_reactivate() {
+        let mut entity = MyEntity::new(true);
+        entity.inactive_dtm = defaults::add_days(defaults::now(), -1);
+        entity.modified_dtm = 1711802687;
+
+        assert!(entity.reactivate(30, 90).is_ok());
+        assert_eq!(entity.inactive_dtm, defaults::add_days(defaults::now(), 30));
+        assert_eq!(entity.expired_dtm, defaults::add_days(defaults::now(), 90));
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.get_activity("reactivated".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_entity_reactivate_expired() {
+        let mut entity = MyEntity::new(true);
+        let inactive_dtm = defaults::add_days(defaults::now(), -10);
+        entity.inactive_dtm = inactive_dtm;
+        entity.expired_dtm = defaults::add_days(defaults::now(), -1);
+
+        assert!(entity.reactivate(30, 90).is_err());
+        assert_eq!(entity.inactive_dtm, inactive_dtm);
+        assert_eq!(entity.get_activity("reactivated".to_string()).len(), 0);
     }
 
     #[test]
@@ -103,11 +415,34 @@ mod tests {
         let deserialized = MyEntity::deserialized(json.as_bytes()).unwrap();
         assert_eq!(deserialized.id, "b4d6c6db-7468-400a-8536-a5e83b1f2bdc");
         assert_eq!(deserialized.activity.len(), 3);
-        assert_eq!(deserialized.b, true);
+        assert!(deserialized.b);
         assert_eq!(deserialized.n, never);
         assert_eq!(deserialized.my_func(), "my function");
     }
 
+    #[test]
+    fn test_entity_deserialize_error() {
+        use scaffolding_core::errors::ScaffoldingError;
+        use std::error::Error;
+
+        let err = MyEntity::deserialized(br#"{"b":true}"#).unwrap_err();
+
+        match &err {
+            ScaffoldingError::Deserialize { source, type_name } => {
+                assert!(type_name.ends_with("MyEntity"));
+                assert!(source.is_data());
+            }
+            _ => panic!("expected a deserialize error"),
+        }
+        assert!(err.source().is_some());
+        assert!(err.to_string().starts_with("Unable to deserialize the "));
+        assert!(err
+            .to_string()
+            .contains("missing field `n` at line 1 column 10"));
+        assert_eq!(err.line(), Some(1));
+        assert_eq!(err.column(), Some(10));
+    }
+
     #[test]
     #[ignore]
     fn test_entity_serialize() {
@@ -154,4 +489,15 @@ mod tests {
 
         assert_eq!(entity.serialize(), expected);
     }
+
+    #[test]
+    fn test_entity_trait_object() {
+        let entity = MyEntity::new(true);
+        let id = entity.id.clone();
+        let mut entities: Vec<Box<dyn Scaffolding<Id = String>>> = vec![Box::new(entity)];
+        entities[0].expire_now();
+
+        assert_eq!(entities[0].get_id(), id);
+        assert_eq!(entities[0].get_activity("expired".to_string()).len(), 1);
+    }
 }
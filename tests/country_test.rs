@@ -1,3 +1,4 @@
+#![allow(clippy::assertions_on_constants, clippy::bool_assert_comparison)]
 extern crate scaffolding_core;
 
 #[cfg(test)]
@@ -100,4 +101,413 @@ mod tests {
         assert_eq!(country.iso_2_code, "US".to_string());
         assert_eq!(country.iso_3_code, "USA".to_string());
     }
+
+    #[test]
+    fn test_country_postal_code_pattern() {
+        let countries = Countries::new();
+        let gbr = countries
+            .get_country_by_iso_3_code("GBR".to_string())
+            .unwrap();
+
+        assert!(gbr.is_valid_postal_code("SW1A 2AA"));
+        assert!(gbr.is_valid_postal_code("sw1a2aa"));
+        assert!(!gbr.is_valid_postal_code("90210"));
+
+        // no known pattern
+        let afg = countries
+            .get_country_by_iso_3_code("AFG".to_string())
+            .unwrap();
+        assert!(afg.postal_code_pattern.is_none());
+        assert!(afg.is_valid_postal_code("anything"));
+
+        // the bundled patterns are valid
+        assert!(countries
+            .list
+            .iter()
+            .all(|c| c.validate_postal_code_pattern().is_ok()));
+
+        // an invalid pattern is an error rather than a panic
+        let mut gbr = gbr.clone();
+        gbr.postal_code_pattern = Some("[A-Z".to_string());
+        assert!(matches!(
+            gbr.validate_postal_code_pattern(),
+            Err(scaffolding_core::errors::ScaffoldingError::PostalCodePattern { .. })
+        ));
+        assert!(!gbr.is_valid_postal_code("SW1A 2AA"));
+    }
+
+    #[test]
+    fn test_countries_global() {
+        let countries = Countries::global();
+
+        assert_eq!(countries.list.len(), 240);
+        assert!(std::ptr::eq(countries, Countries::global()));
+        assert_eq!(
+            countries
+                .get_country_by_iso_2_code("GB".to_string())
+                .unwrap()
+                .iso_3_code,
+            "GBR"
+        );
+        assert!(countries
+            .get_country_by_iso_3_code("ABC".to_string())
+            .is_none());
+    }
+
+    #[test]
+    fn test_countries_reindex() {
+        let mut countries = Countries::new();
+        countries.list.remove(0);
+
+        // the index is checked against the list
+        assert!(countries
+            .get_country_by_iso_3_code("AFG".to_string())
+            .is_none());
+
+        countries.reindex();
+        assert_eq!(
+            countries
+                .get_country_by_iso_3_code("ALB".to_string())
+                .unwrap()
+                .name,
+            "Albania"
+        );
+        assert!(countries
+            .get_country_by_iso_3_code("AFG".to_string())
+            .is_none());
+    }
+
+    #[test]
+    fn test_countries_get_subdivisions() {
+        let countries = Countries::new();
+
+        assert_eq!(countries.get_subdivisions("USA".to_string()).len(), 57);
+        assert_eq!(countries.get_subdivisions("CAN".to_string()).len(), 13);
+        assert_eq!(countries.get_subdivisions("AFG".to_string()).len(), 34);
+        assert_eq!(countries.get_subdivisions("FRA".to_string()).len(), 26);
+        assert!(countries.get_subdivisions("ABW".to_string()).is_empty());
+        assert!(countries.get_subdivisions("XXX".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_country_subdivision() {
+        let countries = Countries::new();
+        let can = countries
+            .get_country_by_iso_3_code("CAN".to_string())
+            .unwrap();
+
+        let qc = can.get_subdivision(" qc ").unwrap();
+        assert_eq!(qc.code, "CA-QC");
+        assert_eq!(qc.abbreviation(), "QC");
+        assert_eq!(can.get_subdivision("CA-QC"), Some(qc));
+        assert_eq!(can.get_subdivision("quebec"), Some(qc));
+        assert!(can.is_valid_subdivision("Ontario"));
+        assert!(!can.is_valid_subdivision("NY"));
+
+        // no subdivisions
+        let abw = countries
+            .get_country_by_iso_3_code("ABW".to_string())
+            .unwrap();
+        assert!(!abw.is_valid_subdivision("anything"));
+    }
+
+    #[test]
+    fn test_countries_get_currency_for() {
+        let countries = Countries::new();
+
+        assert_eq!(
+            countries.get_currency_for("DEU".to_string()),
+            Some(&Currency::new(
+                "EUR".to_string(),
+                "Euro".to_string(),
+                "€".to_string()
+            ))
+        );
+        assert_eq!(
+            countries
+                .get_currency_for("GBR".to_string())
+                .unwrap()
+                .symbol,
+            "£"
+        );
+        // shared currency
+        assert_eq!(
+            countries.get_currency_for("PRI".to_string()).unwrap().code,
+            "USD"
+        );
+        // no currency
+        assert!(countries.get_currency_for("ATA".to_string()).is_none());
+        assert!(countries.get_currency_for("XXX".to_string()).is_none());
+        assert_eq!(
+            countries
+                .list
+                .iter()
+                .filter(|c| c.currency.is_some())
+                .count(),
+            239
+        );
+    }
+
+    #[test]
+    fn test_countries_get_countries_by_language() {
+        let countries = Countries::new();
+
+        let french: Vec<String> = countries
+            .get_countries_by_language(" FR ".to_string())
+            .iter()
+            .map(|c| c.iso_3_code.clone())
+            .collect();
+        assert!(french.contains(&"FRA".to_string()));
+        assert!(french.contains(&"CAN".to_string()));
+        assert!(french.contains(&"CHE".to_string()));
+        assert!(!french.contains(&"DEU".to_string()));
+
+        let che = countries
+            .get_country_by_iso_3_code("CHE".to_string())
+            .unwrap();
+        assert_eq!(che.languages, vec!["de", "fr", "it", "rm"]);
+        assert!(countries
+            .get_countries_by_language("xx".to_string())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_countries_get_numeric_code() {
+        let countries = Countries::new();
+
+        assert_eq!(
+            countries
+                .get_country_by_numeric_code(276)
+                .unwrap()
+                .iso_3_code,
+            "DEU"
+        );
+        // the leading zeros of the code aren't significant, (e.g.: 004)
+        assert_eq!(
+            countries.get_country_by_numeric_code(4).unwrap().iso_3_code,
+            "AFG"
+        );
+        assert!(countries.get_country_by_numeric_code(999).is_none());
+        // no numeric code
+        assert!(countries
+            .get_country_by_iso_3_code("XKX".to_string())
+            .unwrap()
+            .numeric_code
+            .is_none());
+    }
+
+    #[test]
+    fn test_countries_normalized_lookups() {
+        let countries = Countries::new();
+
+        assert_eq!(
+            countries
+                .get_country_by_iso_2_code(" us ".to_string())
+                .unwrap()
+                .iso_3_code,
+            "USA"
+        );
+        assert_eq!(
+            countries
+                .get_country_by_iso_3_code("gbr".to_string())
+                .unwrap()
+                .iso_2_code,
+            "GB"
+        );
+        assert_eq!(
+            countries
+                .get_country_by_phone_code(" +44".to_string())
+                .unwrap()
+                .iso_3_code,
+            "GBR"
+        );
+        assert_eq!(
+            countries
+                .get_country_by_name("NEW   zealand".to_string())
+                .unwrap()
+                .iso_3_code,
+            "NZL"
+        );
+        assert!(countries
+            .get_country_by_name("Atlantis".to_string())
+            .is_none());
+        assert!(countries
+            .get_country_by_iso_2_code("".to_string())
+            .is_none());
+    }
+
+    #[test]
+    fn test_countries_search() {
+        let countries = Countries::new();
+
+        // misspelled
+        let found = countries.search("Untied States".to_string());
+        assert_eq!(found[0].iso_3_code, "USA");
+        assert_eq!(countries.search("Germnay".to_string())[0].iso_3_code, "DEU");
+
+        // contains
+        let korea: Vec<&str> = countries
+            .search("Korea".to_string())
+            .iter()
+            .map(|c| c.iso_3_code.as_str())
+            .collect();
+        assert_eq!(korea, vec!["PRK", "KOR"]);
+
+        // the same name is ranked before the names that start with it
+        let guinea: Vec<&str> = countries
+            .search("guinea".to_string())
+            .iter()
+            .map(|c| c.iso_3_code.as_str())
+            .collect();
+        assert_eq!(guinea[0], "GIN");
+        assert_eq!(guinea[1], "GNB");
+        assert!(guinea.contains(&"PNG"));
+
+        assert!(countries.search("Atlantis".to_string()).is_empty());
+        assert!(countries.search(" ".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_countries_from_json() {
+        // the format of the bundled dataset
+        let countries = Countries::from_json(include_bytes!("../src/countries.json")).unwrap();
+        assert_eq!(countries.list.len(), 240);
+        assert_eq!(
+            countries.get_country_by_numeric_code(826).unwrap().name,
+            "United Kingdom"
+        );
+        assert!(countries.list[0].currency.is_none());
+
+        let countries = Countries::from_json_file("./src/countries.json").unwrap();
+        assert_eq!(countries.list.len(), 240);
+
+        assert!(Countries::from_json(b"[{\"name\": \"Atlantis\"}]").is_err());
+        assert!(matches!(
+            Countries::from_json_file("./missing/countries.json"),
+            Err(scaffolding_core::errors::ScaffoldingError::Storage(_))
+        ));
+        assert!(Countries::from_json(b"not json").is_err());
+        assert!(Countries::from_json_file("./missing.json").is_err());
+
+        // an invalid postal code pattern
+        let data = r#"[{"name": "Atlantis", "phone_code": "999", "iso_2_code": "XA", "iso_3_code": "XAT", "postal_code_pattern": "[0-9"}]"#;
+        assert!(matches!(
+            Countries::from_json(data.as_bytes()),
+            Err(scaffolding_core::errors::ScaffoldingError::PostalCodePattern { .. })
+        ));
+    }
+
+    #[test]
+    fn test_countries_with_overrides() {
+        let mut macedonia = Country::new(
+            "North Macedonia".to_string(),
+            "389".to_string(),
+            "MK".to_string(),
+            "mkd".to_string(),
+        );
+        macedonia.languages = vec!["mk".to_string()];
+        let countries = Countries::new()
+            .with_overrides(vec![
+                macedonia,
+                Country::new(
+                    "Head Office".to_string(),
+                    "0".to_string(),
+                    "XH".to_string(),
+                    "XHO".to_string(),
+                ),
+            ])
+            .unwrap();
+
+        assert_eq!(countries.list.len(), 241);
+        let mkd = countries
+            .get_country_by_iso_3_code("MKD".to_string())
+            .unwrap();
+        assert_eq!(mkd.name, "North Macedonia");
+        assert_eq!(mkd.languages, vec!["mk"]);
+        assert_eq!(mkd.numeric_code, Some(807));
+        assert_eq!(mkd.currency.as_ref().unwrap().code, "MKD");
+        assert!(countries
+            .get_country_by_name("Macedonia".to_string())
+            .is_none());
+        assert_eq!(
+            countries
+                .get_country_by_iso_2_code("XH".to_string())
+                .unwrap()
+                .name,
+            "Head Office"
+        );
+
+        // an invalid postal code pattern
+        let mut atlantis = Country::new(
+            "Atlantis".to_string(),
+            "999".to_string(),
+            "XA".to_string(),
+            "XAT".to_string(),
+        );
+        atlantis.postal_code_pattern = Some("[0-9".to_string());
+        assert!(Countries::new().with_overrides(vec![atlantis]).is_err());
+    }
+
+    #[test]
+    fn test_countries_get_timezones() {
+        let countries = Countries::new();
+
+        assert_eq!(
+            countries.get_timezones("JPN".to_string()),
+            vec!["Asia/Tokyo".to_string()]
+        );
+        assert!(countries.get_timezones("RUS".to_string()).len() > 10);
+        assert!(countries.get_timezones("XXX".to_string()).is_empty());
+        assert!(countries.list.iter().all(|c| !c.timezones.is_empty()));
+    }
+
+    #[test]
+    fn test_country_name_in() {
+        let countries = Countries::new();
+        let japan = countries
+            .get_country_by_iso_3_code("JPN".to_string())
+            .unwrap();
+
+        assert_eq!(japan.name_in("ja"), "日本");
+        assert_eq!(japan.name_in(" JA-jp "), "日本");
+        assert_eq!(japan.name_in("es"), "Japón");
+        assert_eq!(japan.name_in("ru"), "Япония");
+        assert_eq!(japan.name_in("ar"), "اليابان");
+        assert_eq!(japan.name_in("zh_CN"), "日本");
+        assert_eq!(japan.name_in("xx"), "Japan");
+        assert_eq!(japan.name_in(""), "Japan");
+        assert!(
+            countries
+                .list
+                .iter()
+                .filter(|c| c.names.contains_key("zh"))
+                .count()
+                > 200
+        );
+    }
+
+    #[test]
+    fn test_country_name_in_localized() {
+        let countries = Countries::new();
+        let samples = [
+            ("TUR", "fr", "Turquie"),
+            ("TUR", "es", "Turquía"),
+            ("DZA", "es", "Argelia"),
+            ("CIV", "fr", "Côte d'Ivoire"),
+            ("CIV", "es", "Costa de Marfil"),
+            ("CPV", "es", "Cabo Verde"),
+            ("UKR", "es", "Ucrania"),
+            ("COG", "es", "República del Congo"),
+            ("CUW", "fr", "Curaçao"),
+            ("SWZ", "de", "Eswatini"),
+        ];
+
+        for (code, language, name) in samples {
+            let country = countries
+                .get_country_by_iso_3_code(code.to_string())
+                .unwrap();
+            assert_ne!(country.name, name);
+            assert_eq!(country.name_in(language), name);
+        }
+    }
 }
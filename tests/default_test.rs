@@ -0,0 +1,70 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("default", "notes", "status", "tags")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTags)]
+    struct MyEntity {
+        name: String,
+        count: u32,
+        nickname: Option<String>,
+    }
+
+    #[scaffolding_struct("default")]
+    #[derive(Clone, Debug, Scaffolding)]
+    struct Wrapper<T> {
+        value: T,
+    }
+
+    #[test]
+    fn test_default() {
+        let entity = MyEntity::default();
+
+        assert_eq!(entity.name, String::new());
+        assert_eq!(entity.count, 0);
+        assert_eq!(entity.nickname, None);
+        assert_eq!(
+            entity.id.len(),
+            "54324f57-9e6b-4142-b68d-1d4c86572d0a".len()
+        );
+        assert_eq!(entity.created_dtm, defaults::now());
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.inactive_dtm, defaults::add_days(defaults::now(), 90));
+        assert_eq!(entity.expired_dtm, defaults::add_years(defaults::now(), 3));
+        assert_eq!(entity.status, defaults::status());
+        assert_eq!(entity.activity.len(), 0);
+        assert_eq!(entity.notes.len(), 0);
+        assert_eq!(entity.tags.len(), 0);
+    }
+
+    #[test]
+    fn test_default_unique_id() {
+        assert_ne!(MyEntity::default().id, MyEntity::default().id);
+    }
+
+    #[test]
+    fn test_default_mem_take() {
+        let mut entity = MyEntity {
+            name: "Frank".to_string(),
+            ..Default::default()
+        };
+        entity.add_tag("customer".to_string());
+
+        let taken = std::mem::take(&mut entity);
+
+        assert_eq!(taken.name, "Frank".to_string());
+        assert!(taken.has_tag("customer".to_string()));
+        assert_eq!(entity.name, String::new());
+        assert!(!entity.has_tag("customer".to_string()));
+    }
+
+    #[test]
+    fn test_default_generics() {
+        let wrapper: Wrapper<Vec<u8>> = Wrapper::default();
+
+        assert_eq!(wrapper.value.len(), 0);
+        assert_eq!(wrapper.get_id(), wrapper.id);
+    }
+}
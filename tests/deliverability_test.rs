@@ -0,0 +1,93 @@
+#![cfg(feature = "deliverability")]
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::errors::ScaffoldingError;
+    use scaffolding_core::*;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    #[scaffolding_struct("email_addresses")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingEmailAddresses)]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("email_addresses")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    // only used for the checks that fail before any lookup, so a single poll is enough
+    fn block_on<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the future is not ready"),
+        }
+    }
+
+    #[test]
+    fn test_deliverability_new() {
+        let hosts = vec!["mx1.example.com".to_string(), "mx2.example.com".to_string()];
+
+        assert!(EmailDeliverability::new(hosts.clone(), None).deliverable);
+        assert!(EmailDeliverability::new(hosts.clone(), Some(true)).deliverable);
+        assert!(!EmailDeliverability::new(hosts, Some(false)).deliverable);
+        assert!(!EmailDeliverability::new(Vec::new(), None).deliverable);
+        // null MX, (RFC 7505)
+        assert!(!EmailDeliverability::new(vec!["".to_string()], None).deliverable);
+    }
+
+    #[test]
+    fn test_verify_deliverability_without_domain() {
+        let mut email = EmailAddress::new("login".to_string(), "not an email".to_string());
+
+        assert!(block_on(email.verify_deliverability(false)).is_err());
+        assert!(email.deliverability.is_none());
+    }
+
+    #[test]
+    fn test_verify_deliverability_line_breaks() {
+        let mut email = EmailAddress::new(
+            "login".to_string(),
+            "me@example.com>\r\nRCPT TO:<other@example.com".to_string(),
+        );
+
+        assert!(block_on(email.verify_deliverability(true)).is_err());
+        assert!(email.deliverability.is_none());
+    }
+
+    #[test]
+    fn test_verify_email_address_errors() {
+        let mut entity = MyEntity::new();
+        let id = entity.insert_email_address("login".to_string(), "nobody".to_string());
+
+        assert!(block_on(entity.verify_email_address(id.clone(), false)).is_err());
+        assert!(matches!(
+            block_on(entity.verify_email_address("unknown".to_string(), false)),
+            Err(ScaffoldingError::NotFound { id }) if id == "unknown"
+        ));
+        assert!(entity
+            .get_email_address(id)
+            .unwrap()
+            .deliverability
+            .is_none());
+        assert_eq!(entity.activity.len(), 0);
+    }
+
+    #[test]
+    fn test_deliverability_serialized() {
+        let mut email = EmailAddress::new("login".to_string(), "me@example.com".to_string());
+        assert!(!email.serialize().contains("deliverability"));
+
+        email.deliverability = Some(EmailDeliverability::new(
+            vec!["mx.example.com".to_string()],
+            None,
+        ));
+        let loaded = EmailAddress::deserialized(email.serialize().as_bytes()).unwrap();
+
+        assert_eq!(loaded.deliverability, email.deliverability);
+    }
+}
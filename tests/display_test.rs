@@ -0,0 +1,80 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("notes", "soft_delete")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingDisplay,
+        ScaffoldingNotes,
+        ScaffoldingSoftDelete,
+    )]
+    struct MyEntity {
+        ssn: String,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("notes", "soft_delete")]
+        fn new(ssn: &str) -> Self {
+            Self {
+                ssn: ssn.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct(exclude("inactive_dtm", "expired_dtm"))]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingDisplay)]
+    enum MyEvent {
+        Opened { by: String },
+    }
+
+    #[test]
+    fn test_display_active() {
+        let mut entity = MyEntity::new("123-45-6789");
+        entity.created_dtm = 1711295319;
+
+        assert_eq!(
+            entity.to_string(),
+            format!("MyEntity {} (active, created 2024-03-24)", entity.id)
+        );
+        assert!(!entity.to_string().contains(&entity.ssn));
+    }
+
+    #[test]
+    fn test_display_lifecycle() {
+        let mut entity = MyEntity::new("123-45-6789");
+        entity.deactivate();
+        assert!(entity.to_string().contains("(inactive, created"));
+
+        entity.expire_now();
+        assert!(entity.to_string().contains("(expired, created"));
+
+        entity.delete();
+        assert!(entity.to_string().contains("(deleted, created"));
+    }
+
+    #[test]
+    fn test_display_enum() {
+        let event = MyEvent::Opened {
+            by: "jdoe".to_string(),
+            id: "abc".to_string(),
+            created_dtm: 1711295319,
+            modified_dtm: 1711295319,
+            activity: Vec::new(),
+        };
+
+        let MyEvent::Opened { by, .. } = &event;
+
+        assert_eq!(by, "jdoe");
+        assert_eq!(
+            event.to_string(),
+            "MyEvent abc (active, created 2024-03-24)"
+        );
+    }
+}
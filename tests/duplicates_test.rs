@@ -0,0 +1,228 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct(
+        "addresses",
+        "email_addresses",
+        "metadata",
+        "phone_numbers",
+        "soft_delete"
+    )]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingAddresses,
+        ScaffoldingDuplicates,
+        ScaffoldingEmailAddresses,
+        ScaffoldingErasure,
+        ScaffoldingPhoneNumbers,
+        ScaffoldingSoftDelete,
+    )]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn(
+            "addresses",
+            "email_addresses",
+            "metadata",
+            "phone_numbers",
+            "soft_delete"
+        )]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingDuplicates)]
+    struct MyBareEntity {}
+
+    impl MyBareEntity {
+        #[scaffolding_fn]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    fn get_entity(first: &str, last: &str, email: &str, phone: &str) -> MyEntity {
+        let mut entity = MyEntity::new();
+        entity
+            .metadata
+            .insert("first_name".to_string(), first.to_string());
+        entity
+            .metadata
+            .insert("last_name".to_string(), last.to_string());
+        entity.insert_email_address("home".to_string(), email.to_string());
+        entity.insert_phone_number("home".to_string(), phone.to_string(), "USA".to_string());
+        entity
+    }
+
+    #[test]
+    fn test_similarity() {
+        let left = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let right = vec!["c".to_string(), "b".to_string()];
+
+        assert_eq!(DuplicateCandidate::similarity(&left, &left), Some(1.0));
+        assert_eq!(
+            DuplicateCandidate::similarity(&left, &right),
+            Some(2.0 / 3.0)
+        );
+        assert_eq!(
+            DuplicateCandidate::similarity(&left, &["d".to_string()]),
+            Some(0.0)
+        );
+        assert_eq!(DuplicateCandidate::similarity(&Vec::new(), &right), None);
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        let mut entity = get_entity("Frank", "Smith", " FSmith@Example.com", "+1 (848) 249-3561");
+        let _ = entity.insert_address(
+            "shipping".to_string(),
+            "Acme's Company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let keys = entity.duplicate_keys();
+
+        assert_eq!(keys.get("name").unwrap(), &vec!["frank smith".to_string()]);
+        assert_eq!(
+            keys.get("email_addresses").unwrap(),
+            &vec!["fsmith@example.com".to_string()]
+        );
+        assert_eq!(
+            keys.get("phone_numbers").unwrap(),
+            &vec!["18482493561".to_string()]
+        );
+        assert_eq!(
+            keys.get("addresses").unwrap(),
+            &vec!["acme s company 14 main street big city ny 038845 usa usa".to_string()]
+        );
+        assert!(MyBareEntity::new().duplicate_keys().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_score() {
+        let entity = get_entity("Frank", "Smith", "fsmith@example.com", "848-249-3561");
+
+        assert_eq!(
+            entity.duplicate_score(&get_entity(
+                "FRANK",
+                "smith",
+                "FSmith@example.com",
+                "(848) 249 3561"
+            )),
+            1.0
+        );
+        assert_eq!(
+            entity.duplicate_score(&get_entity(
+                "Frank",
+                "Smith",
+                "frank@example.com",
+                "848-249-3561"
+            )),
+            2.0 / 3.0
+        );
+        assert_eq!(
+            entity.duplicate_score(&get_entity(
+                "Jane",
+                "Doe",
+                "jdoe@example.com",
+                "555-555-5555"
+            )),
+            0.0
+        );
+        assert_eq!(
+            MyBareEntity::new().duplicate_score(&MyBareEntity::new()),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_duplicate_score_erased() {
+        let mut frank = get_entity("Frank", "Smith", "fsmith@example.com", "848-249-3561");
+        let mut jane = get_entity("Jane", "Doe", "jdoe@example.com", "555-555-5555");
+        for (entity, street) in [(&mut frank, "14 Main Street"), (&mut jane, "7 Elm Road")] {
+            let _ = entity.insert_address(
+                "shipping".to_string(),
+                "acmes company".to_string(),
+                street.to_string(),
+                "Big City, NY 038845".to_string(),
+                "USA".to_string(),
+                "USA".to_string(),
+            );
+        }
+        assert_eq!(frank.duplicate_score(&jane), 0.0);
+
+        let names = vec!["first_name".to_string(), "last_name".to_string()];
+        frank.erase_personal_data(names.clone());
+        jane.erase_personal_data(names);
+
+        // the redacted values are not compared
+        let keys = frank.duplicate_keys();
+        assert!(!keys.contains_key("name"));
+        assert!(keys.get("email_addresses").unwrap().is_empty());
+        assert!(keys.get("phone_numbers").unwrap().is_empty());
+        assert!(keys.get("addresses").unwrap().is_empty());
+        assert_eq!(frank.duplicate_score(&jane), 0.0);
+
+        let mut collection = EntityCollection::new();
+        collection.list.push(frank);
+        collection.list.push(jane);
+        assert!(collection.find_duplicates(0.0).is_empty());
+    }
+
+    #[test]
+    fn test_collection_find_duplicates() {
+        let mut collection = EntityCollection::new();
+        collection.list.push(get_entity(
+            "Frank",
+            "Smith",
+            "fsmith@example.com",
+            "848-249-3561",
+        ));
+        collection.list.push(get_entity(
+            "Jane",
+            "Doe",
+            "jdoe@example.com",
+            "555-555-5555",
+        ));
+        collection.list.push(get_entity(
+            "Frank",
+            "Smith",
+            "frank@example.com",
+            "848-249-3561",
+        ));
+        collection.list.push(get_entity(
+            "frank",
+            "SMITH",
+            "FSMITH@example.com",
+            "8482493561",
+        ));
+
+        let duplicates = collection.find_duplicates(0.5);
+        assert_eq!(duplicates.len(), 3);
+        // highest confidence first
+        assert_eq!(duplicates[0].left_id, collection.list[0].id);
+        assert_eq!(duplicates[0].right_id, collection.list[3].id);
+        assert_eq!(duplicates[0].score, 1.0);
+        assert_eq!(duplicates[1].score, 2.0 / 3.0);
+        assert_eq!(duplicates[2].score, 2.0 / 3.0);
+
+        assert_eq!(collection.find_duplicates(0.9).len(), 1);
+        assert_eq!(collection.find_duplicates(0.0).len(), 3);
+
+        // soft deleted entities are not reported
+        collection.list[3].delete();
+        assert!(collection.find_duplicates(0.9).is_empty());
+        assert_eq!(collection.find_duplicates(0.0).len(), 1);
+    }
+}
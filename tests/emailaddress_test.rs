@@ -1,3 +1,4 @@
+#![allow(clippy::bool_assert_comparison)]
 extern crate scaffolding_core;
 
 #[cfg(test)]
@@ -65,4 +66,119 @@ mod tests {
         assert_eq!(home.len(), 1);
         assert_eq!(home[0].category, "home".to_string());
     }
+
+    #[test]
+    fn test_entity_primary_email_address() {
+        let mut entity = MyEntity::new();
+        let first = entity.insert_email_address("work".to_string(), "me@example.com".to_string());
+        let second =
+            entity.insert_email_address("work".to_string(), "sales@example.com".to_string());
+
+        assert!(entity
+            .get_primary_email_address("work".to_string())
+            .is_none());
+
+        entity.set_primary_email_address(first.clone());
+        entity.set_primary_email_address(second.clone());
+
+        assert!(!entity.get_email_address(first).unwrap().primary);
+        assert_eq!(
+            entity
+                .get_primary_email_address("work".to_string())
+                .unwrap()
+                .id,
+            second
+        );
+    }
+
+    #[test]
+    fn test_email_canonical() {
+        let gmail = EmailAddress::new("home".to_string(), " F.o.o+shop@Gmail.com".to_string());
+        let other = EmailAddress::new("home".to_string(), "F.o.o+shop@Example.com".to_string());
+
+        assert_eq!(gmail.normalized(), "f.o.o+shop@gmail.com".to_string());
+        assert_eq!(gmail.canonical(), "foo@gmail.com".to_string());
+        assert_eq!(other.canonical(), "f.o.o+shop@example.com".to_string());
+    }
+
+    #[test]
+    fn test_entity_email_address_unique() {
+        let mut entity = MyEntity::new();
+        let id =
+            entity.insert_email_address_unique("home".to_string(), "foo@example.com".to_string());
+
+        assert_eq!(
+            entity.insert_email_address_unique("home".to_string(), "Foo@Example.com ".to_string()),
+            id
+        );
+        assert_ne!(
+            entity.insert_email_address_unique("home".to_string(), "bar@example.com".to_string()),
+            id
+        );
+        assert_eq!(entity.email_addresses.len(), 2);
+
+        // the aliases of the same gmail mailbox
+        let gmail = entity
+            .insert_email_address_unique("home".to_string(), "john.doe+x@gmail.com".to_string());
+        assert_eq!(
+            entity.insert_email_address_unique("work".to_string(), "johndoe@gmail.com".to_string()),
+            gmail
+        );
+        assert_eq!(entity.email_addresses.len(), 3);
+    }
+
+    #[test]
+    fn test_email_domain_lists() {
+        let disposable = EmailAddress::new("login".to_string(), "x@YopMail.com".to_string());
+        let free = EmailAddress::new("home".to_string(), "x@outlook.com".to_string());
+        let company = EmailAddress::new("work".to_string(), "x@example.com".to_string());
+        let invalid = EmailAddress::new("work".to_string(), "not an email".to_string());
+
+        assert!(disposable.is_disposable());
+        assert!(!disposable.is_free_provider());
+        assert!(free.is_free_provider());
+        assert!(!free.is_disposable());
+        assert!(!company.is_disposable());
+        assert!(!company.is_free_provider());
+        assert_eq!(invalid.domain(), None);
+        assert!(!invalid.is_disposable());
+    }
+
+    #[test]
+    fn test_email_update() {
+        let mut entity = MyEntity::new();
+        let id = entity.insert_email_address("home".to_string(), "me@example.com".to_string());
+        entity.set_primary_email_address(id.clone());
+
+        let email = entity.email_addresses.get_mut(&id).unwrap();
+        email.modified_dtm = 0;
+        email.update("home".to_string(), "Me@Example.com".to_string());
+
+        assert_eq!(email.address, "Me@Example.com".to_string());
+        assert!(email.modified_dtm > 0);
+        assert!(email.primary);
+
+        // moving to another category drops the designation
+        email.update("work".to_string(), "me@acme.com".to_string());
+        assert_eq!(email.category, "work".to_string());
+        assert!(!email.primary);
+    }
+
+    #[test]
+    fn test_entity_email_addresses_by_domain() {
+        let mut entity = MyEntity::new();
+        let _ = entity.insert_email_address("work".to_string(), "fsmith@acme.com".to_string());
+        let _ = entity.insert_email_address("work".to_string(), "JDoe@ACME.com".to_string());
+        let _ = entity.insert_email_address("home".to_string(), "fsmith@gmail.com".to_string());
+        let _ = entity.insert_email_address("home".to_string(), "fsmith@sub.acme.com".to_string());
+
+        let found = entity.search_email_addresses_by_domain("Acme.com".to_string());
+        assert_eq!(found.len(), 2);
+        assert!(found
+            .iter()
+            .all(|e| e.domain() == Some("acme.com".to_string())));
+        assert!(entity
+            .search_email_addresses_by_domain("example.com".to_string())
+            .is_empty());
+    }
 }
@@ -0,0 +1,202 @@
+#![cfg(feature = "encryption")]
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("notes")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes)]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("notes")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[test]
+    fn test_note_encrypted() {
+        let key = [7u8; 32];
+        let note = Note::new_encrypted(
+            "fsmith".to_string(),
+            "This is confidential".as_bytes().to_vec(),
+            &key,
+        );
+
+        assert_eq!(note.author, "fsmith".to_string());
+        assert_eq!(note.access, "encrypted".to_string());
+        assert_ne!(note.content, "This is confidential".as_bytes().to_vec());
+        assert_eq!(
+            note.decrypt(&key).unwrap(),
+            "This is confidential".as_bytes().to_vec()
+        );
+    }
+
+    #[test]
+    fn test_note_decrypt_bad() {
+        let key = [7u8; 32];
+        let mut note = Note::new_encrypted(
+            "fsmith".to_string(),
+            "This is confidential".as_bytes().to_vec(),
+            &key,
+        );
+
+        // wrong key
+        assert!(note.decrypt(&[8u8; 32]).is_err());
+
+        // tampered content
+        let last = note.content.len() - 1;
+        note.content[last] ^= 1;
+        assert!(note.decrypt(&key).is_err());
+
+        // not encrypted
+        let plain = Note::new("fsmith".to_string(), "abc".as_bytes().to_vec(), None);
+        assert!(plain.decrypt(&key).is_err());
+    }
+
+    #[test]
+    fn test_entity_notes_encrypted() {
+        let key = [7u8; 32];
+        let mut entity = MyEntity::new();
+        let id = entity.insert_note_encrypted(
+            "fsmith".to_string(),
+            "This is confidential".as_bytes().to_vec(),
+            &key,
+        );
+
+        // the content is not stored as plain bytes
+        let json = entity.serialize();
+        let plain: Vec<u8> = "This is confidential".as_bytes().to_vec();
+        assert!(!json.contains(&format!("{:?}", plain).replace(' ', "")));
+
+        let entity = MyEntity::deserialized(json.as_bytes()).unwrap();
+        assert_eq!(entity.get_note(id.clone()).unwrap().access, "encrypted");
+        assert_eq!(entity.decrypt_note(id, &key).unwrap(), plain);
+        assert!(entity.decrypt_note("1234".to_string(), &key).is_err());
+    }
+
+    #[test]
+    fn test_note_access_keys() {
+        let mut entity = MyEntity::new();
+        let keys = AccessKeys::new()
+            .with_key("confidential".to_string(), [7u8; 32])
+            .with_key("internal".to_string(), [6u8; 32]);
+        let secret = entity.insert_note_with_keys(
+            "fsmith".to_string(),
+            "The salary was raised".as_bytes().to_vec(),
+            Some("confidential".to_string()),
+            &keys,
+        );
+        let public = entity.insert_note_with_keys(
+            "fsmith".to_string(),
+            "This was updated".as_bytes().to_vec(),
+            None,
+            &keys,
+        );
+
+        // encrypted at rest
+        let note = entity.get_note(secret.clone()).unwrap();
+        assert!(note.encrypted);
+        assert_eq!(note.access, "confidential".to_string());
+        assert_ne!(note.content, "The salary was raised".as_bytes().to_vec());
+        assert!(!entity.get_note(public.clone()).unwrap().encrypted);
+
+        // decrypted only when the key is supplied
+        assert_eq!(
+            entity.read_note(secret.clone(), &keys).unwrap(),
+            "The salary was raised".as_bytes().to_vec()
+        );
+        let internal_only = AccessKeys::new().with_key("internal".to_string(), [6u8; 32]);
+        assert!(entity.read_note(secret.clone(), &internal_only).is_err());
+        let wrong = AccessKeys::new().with_key("confidential".to_string(), [8u8; 32]);
+        assert!(entity.read_note(secret.clone(), &wrong).is_err());
+        assert_eq!(
+            entity.read_note(public, &AccessKeys::new()).unwrap(),
+            "This was updated".as_bytes().to_vec()
+        );
+        assert!(matches!(
+            entity.read_note("unknown".to_string(), &keys),
+            Err(errors::ScaffoldingError::NotFound { .. })
+        ));
+
+        // the flag survives serialization
+        let loaded = MyEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+        assert_eq!(
+            loaded.read_note(secret.clone(), &keys).unwrap(),
+            "The salary was raised".as_bytes().to_vec()
+        );
+
+        // replacing the content stores it as given
+        entity.modify_note(
+            secret.clone(),
+            "fsmith".to_string(),
+            "Redacted by HR".as_bytes().to_vec(),
+            None,
+        );
+        assert!(!entity.get_note(secret).unwrap().encrypted);
+    }
+
+    #[test]
+    fn test_note_access_keys_modify_and_reply() {
+        let mut entity = MyEntity::new();
+        let keys = AccessKeys::new().with_key("confidential".to_string(), [7u8; 32]);
+        let secret = entity.insert_note_with_keys(
+            "fsmith".to_string(),
+            "The salary was raised".as_bytes().to_vec(),
+            Some("confidential".to_string()),
+            &keys,
+        );
+
+        // an edit stays encrypted
+        entity.modify_note_with(
+            secret.clone(),
+            "fsmith".to_string(),
+            "The salary was raised again".as_bytes().to_vec(),
+            None,
+            &keys,
+        );
+        let note = entity.get_note(secret.clone()).unwrap();
+        assert!(note.encrypted);
+        assert_ne!(
+            note.content,
+            "The salary was raised again".as_bytes().to_vec()
+        );
+        assert_eq!(
+            entity.read_note(secret.clone(), &keys).unwrap(),
+            "The salary was raised again".as_bytes().to_vec()
+        );
+
+        // a reply in the thread is encrypted with the key of the thread
+        let reply = entity
+            .reply_to_note_with(
+                secret.clone(),
+                "jdoe".to_string(),
+                "Approved".as_bytes().to_vec(),
+                &keys,
+            )
+            .unwrap();
+        let note = entity.get_note(reply.clone()).unwrap();
+        assert!(note.encrypted);
+        assert_eq!(note.access, "confidential".to_string());
+        assert_eq!(note.parent_id, Some(secret.clone()));
+        assert_eq!(
+            entity.read_note(reply, &keys).unwrap(),
+            "Approved".as_bytes().to_vec()
+        );
+        assert!(entity
+            .reply_to_note_with("unknown".to_string(), "jdoe".to_string(), Vec::new(), &keys)
+            .is_none());
+
+        // moving the note to a level without a key stores it as given
+        entity.modify_note_with(
+            secret.clone(),
+            "fsmith".to_string(),
+            "Public now".as_bytes().to_vec(),
+            Some("public".to_string()),
+            &keys,
+        );
+        assert!(!entity.get_note(secret).unwrap().encrypted);
+    }
+}
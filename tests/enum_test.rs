@@ -0,0 +1,173 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct(
+        "email_addresses",
+        "metadata",
+        "notes",
+        "soft_delete",
+        "status",
+        "tags"
+    )]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingEmailAddresses,
+        ScaffoldingErasure,
+        ScaffoldingMasking,
+        ScaffoldingNotes,
+        ScaffoldingSoftDelete,
+        ScaffoldingStatus,
+        ScaffoldingTags,
+    )]
+    enum Account {
+        Person {
+            first_name: String,
+            last_name: String,
+        },
+        Company {
+            name: String,
+        },
+    }
+
+    impl Account {
+        #[scaffolding_fn(
+            "email_addresses",
+            "metadata",
+            "notes",
+            "soft_delete",
+            "status",
+            "tags"
+        )]
+        fn new(first_name: &str, last_name: &str) -> Self {
+            Self::Person {
+                first_name: first_name.to_string(),
+                last_name: last_name.to_string(),
+            }
+        }
+    }
+
+    fn get_company() -> Account {
+        Account::Company {
+            name: "Acme".to_string(),
+            id: defaults::id(),
+            created_dtm: defaults::now(),
+            modified_dtm: defaults::now(),
+            inactive_dtm: defaults::add_days(defaults::now(), 90),
+            expired_dtm: defaults::add_years(defaults::now(), 3),
+            activity: Vec::new(),
+            email_addresses: BTreeMap::new(),
+            metadata: BTreeMap::new(),
+            notes: BTreeMap::new(),
+            deleted_dtm: None,
+            status: defaults::status(),
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_enum_new() {
+        let person = Account::new("Frank", "Smith");
+
+        match person {
+            Account::Person {
+                first_name,
+                id,
+                status,
+                ..
+            } => {
+                assert_eq!(first_name, "Frank".to_string());
+                assert_eq!(id.len(), "54324f57-9e6b-4142-b68d-1d4c86572d0a".len());
+                assert_eq!(status, defaults::status());
+            }
+            Account::Company { .. } => panic!("expected a person"),
+        }
+    }
+
+    #[test]
+    fn test_enum_core() {
+        let mut person = Account::new("Frank", "Smith");
+        let company = get_company();
+
+        assert_ne!(person.get_id(), company.get_id());
+
+        person.log_activity("updated".to_string(), "The person was updated".to_string());
+        assert_eq!(person.get_activity("updated".to_string()).len(), 1);
+
+        person.deactivate();
+        assert!(person.reactivate(30, 90).is_ok());
+        assert_eq!(person.get_activity("reactivated".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_enum_serialization() {
+        let company = get_company();
+        let id = company.get_id();
+        let deserialized = Account::deserialized(company.serialize().as_bytes()).unwrap();
+
+        assert_eq!(deserialized.get_id(), id);
+        assert!(matches!(deserialized, Account::Company { .. }));
+    }
+
+    #[test]
+    fn test_enum_optional_attributes() {
+        let mut person = Account::new("Frank", "Smith");
+        let mut company = get_company();
+
+        person.add_tag("customer".to_string());
+        company.add_tag("vendor".to_string());
+        assert!(person.has_tag("customer".to_string()));
+        assert!(!company.has_tag("customer".to_string()));
+
+        let email =
+            person.insert_email_address("home".to_string(), "fsmith@example.com".to_string());
+        assert_eq!(
+            person.get_email_address(email).unwrap().address,
+            "fsmith@example.com".to_string()
+        );
+
+        let note = company.insert_note(
+            "fsmith".to_string(),
+            "Call me at 8482493561".as_bytes().to_vec(),
+            None,
+        );
+        let masked = company.mask(&MaskPolicy::new(MaskStyle::Full));
+        assert_eq!(
+            masked
+                .get_note(note.clone())
+                .unwrap()
+                .content_as_string()
+                .unwrap(),
+            "*********************".to_string()
+        );
+        assert_eq!(
+            company.get_note(note).unwrap().content_as_string().unwrap(),
+            "Call me at 8482493561".to_string()
+        );
+
+        person.erase_personal_data(Vec::new());
+        assert_eq!(
+            person.search_email_addresses_by_category("home".to_string())[0].address,
+            defaults::redacted()
+        );
+    }
+
+    #[test]
+    fn test_enum_lifecycle() {
+        let mut company = get_company();
+
+        assert!(company.transition_to("active".to_string()).is_ok());
+        assert_eq!(company.get_activity("status_changed".to_string()).len(), 1);
+
+        company.delete();
+        assert!(company.is_deleted());
+        assert!(company.restore().is_ok());
+        assert!(!company.is_deleted());
+    }
+}
@@ -0,0 +1,133 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("addresses", "email_addresses", "metadata", "notes", "phone_numbers")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingAddresses,
+        ScaffoldingEmailAddresses,
+        ScaffoldingErasure,
+        ScaffoldingNotes,
+        ScaffoldingPhoneNumbers,
+    )]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("addresses", "email_addresses", "metadata", "notes", "phone_numbers")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingErasure)]
+    struct MyCoreEntity {}
+
+    impl MyCoreEntity {
+        #[scaffolding_fn]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[test]
+    fn test_entity_erase_personal_data() {
+        let mut entity = MyEntity::new();
+        let address = entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let email =
+            entity.insert_email_address("home".to_string(), "myemail@example.com".to_string());
+        let note = entity.insert_note(
+            "fsmith".to_string(),
+            "Call me at 8482493561".as_bytes().to_vec(),
+            None,
+        );
+        let phone = entity.insert_phone_number(
+            "home".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+        );
+        entity
+            .metadata
+            .insert("ssn".to_string(), "123-45-6789".to_string());
+        entity
+            .metadata
+            .insert("segment".to_string(), "retail".to_string());
+        entity.log_activity_with_data(
+            "updated".to_string(),
+            "The email address changed to myemail@example.com".to_string(),
+            json!({"old": "old@example.com", "new": "myemail@example.com"}),
+        );
+
+        let id = entity.id.clone();
+        let created_dtm = entity.created_dtm;
+        let inactive_dtm = entity.inactive_dtm;
+        let expired_dtm = entity.expired_dtm;
+
+        entity.erase_personal_data(vec!["ssn".to_string(), "missing".to_string()]);
+
+        // tombstones
+        let addr = entity.get_address(address).unwrap();
+        assert_eq!(addr.line_1, defaults::redacted());
+        assert_eq!(addr.line_2, defaults::redacted());
+        assert_eq!(addr.line_3, defaults::redacted());
+        assert_eq!(addr.line_4, defaults::redacted());
+        assert_eq!(
+            entity.get_email_address(email).unwrap().address,
+            defaults::redacted()
+        );
+        assert_eq!(
+            entity.get_note(note).unwrap().content_as_string().unwrap(),
+            defaults::redacted()
+        );
+        assert_eq!(
+            entity.get_phone_number(phone).unwrap().number,
+            defaults::redacted()
+        );
+        assert_eq!(entity.addresses.len(), 1);
+        assert_eq!(entity.email_addresses.len(), 1);
+        assert_eq!(entity.notes.len(), 1);
+        assert_eq!(entity.phone_numbers.len(), 1);
+
+        // metadata
+        assert_eq!(entity.metadata.get("ssn").unwrap(), &defaults::redacted());
+        assert_eq!(entity.metadata.get("segment").unwrap(), "retail");
+        assert!(!entity.metadata.contains_key("missing"));
+
+        // referential integrity
+        assert_eq!(entity.id, id);
+        assert_eq!(entity.created_dtm, created_dtm);
+        assert_eq!(entity.inactive_dtm, inactive_dtm);
+        assert_eq!(entity.expired_dtm, expired_dtm);
+        assert_eq!(entity.get_activity("erased".to_string()).len(), 1);
+
+        // activity
+        let updated = entity.get_activity("updated".to_string());
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].description, defaults::redacted());
+        assert_eq!(updated[0].data, Some(json!(defaults::redacted())));
+        assert!(!entity.serialize().contains("example.com"));
+    }
+
+    #[test]
+    fn test_entity_erase_without_personal_data() {
+        let mut entity = MyCoreEntity::new();
+
+        entity.erase_personal_data(vec!["ssn".to_string()]);
+
+        assert_eq!(entity.get_activity("erased".to_string()).len(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("soft_delete", exclude("activity", "expired_dtm"))]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingRetention,
+        ScaffoldingSoftDelete,
+    )]
+    struct MyValue {
+        amount: u32,
+    }
+
+    impl MyValue {
+        #[scaffolding_fn("soft_delete", exclude("activity", "expired_dtm"))]
+        fn new(amount: u32) -> Self {
+            Self { amount }
+        }
+    }
+
+    #[scaffolding_struct("default", exclude("inactive_dtm", "expired_dtm", "activity"))]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingBuilder)]
+    struct MyLeanValue {
+        amount: u32,
+    }
+
+    #[test]
+    fn test_exclude_new() {
+        let value = MyValue::new(10);
+        let json: Value = value.serialize().parse().unwrap();
+
+        assert_eq!(value.amount, 10);
+        assert_eq!(value.inactive_dtm, defaults::add_days(defaults::now(), 90));
+        assert!(json.get("inactive_dtm").is_some());
+        assert!(json.get("expired_dtm").is_none());
+        assert!(json.get("activity").is_none());
+    }
+
+    #[test]
+    fn test_exclude_activity() {
+        let mut value = MyValue::new(10);
+        value.log_activity("updated".to_string(), "The amount changed".to_string());
+
+        assert_eq!(value.get_activity("updated".to_string()).len(), 0);
+        assert_eq!(value.redact_activity(&|_| true), 0);
+        assert!(!value.verify_activity_chain());
+    }
+
+    #[test]
+    fn test_exclude_lifecycle() {
+        let mut value = MyValue::new(10);
+        value.expire_now();
+        value.deactivate();
+
+        assert_eq!(value.inactive_dtm, defaults::now());
+        assert!(value.reactivate(30, 365).is_ok());
+        assert_eq!(value.inactive_dtm, defaults::add_days(defaults::now(), 30));
+    }
+
+    #[test]
+    fn test_exclude_soft_delete() {
+        let mut value = MyValue::new(10);
+        value.delete();
+        assert!(value.is_deleted());
+
+        assert!(value.restore().is_ok());
+        assert!(!value.is_deleted());
+    }
+
+    #[test]
+    fn test_exclude_retention() {
+        let mut value = MyValue::new(10);
+        let report = value.apply_retention(&[
+            RetentionPolicy::RemoveOlderThan {
+                attribute: "activity".to_string(),
+                days: 0,
+            },
+            RetentionPolicy::ExpireAfterInactivity { years: 1 },
+        ]);
+
+        assert_eq!(report.activity, 0);
+        assert_eq!(report.entities.len(), 0);
+    }
+
+    #[test]
+    fn test_exclude_all_lifecycle() {
+        let default = MyLeanValue::default();
+        let built = MyLeanValue::builder().amount(5u32).build().unwrap();
+
+        assert_eq!(default.amount, 0);
+        assert_eq!(built.amount, 5);
+        assert_eq!(built.created_dtm, defaults::now());
+    }
+}
@@ -0,0 +1,81 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("all")]
+    #[derive(Clone, Debug, Deserialize, Serialize, ScaffoldingFull)]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("all")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct("notes", "tags")]
+    #[derive(Clone, Debug, ScaffoldingFull, ScaffoldingMasking)]
+    struct MyPartialEntity {}
+
+    impl MyPartialEntity {
+        #[scaffolding_fn("notes", "tags")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[test]
+    fn test_full_all() {
+        let mut entity = MyEntity::new();
+
+        entity.log_activity("updated".to_string(), "The entity was updated".to_string());
+        let _ = entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let _ = entity.insert_email_address("home".to_string(), "fsmith@example.com".to_string());
+        let _ = entity.insert_note("fsmith".to_string(), "Hello".as_bytes().to_vec(), None);
+        let _ = entity.insert_phone_number(
+            "home".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+        );
+        let _ = entity.insert_relationship("parent".to_string(), "ORG-100".to_string());
+        entity.add_tag("customer".to_string());
+
+        assert_eq!(entity.get_activity("updated".to_string()).len(), 1);
+        assert_eq!(entity.addresses.len(), 1);
+        assert_eq!(entity.email_addresses.len(), 1);
+        assert_eq!(entity.notes.len(), 1);
+        assert_eq!(entity.phone_numbers.len(), 1);
+        assert_eq!(entity.relationships.len(), 1);
+        assert!(entity.has_tag("customer".to_string()));
+
+        let deserialized = MyEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+        assert_eq!(deserialized.id, entity.id);
+    }
+
+    #[test]
+    fn test_full_partial() {
+        let mut entity = MyPartialEntity::new();
+        let id = entity.insert_note(
+            "fsmith".to_string(),
+            "Call me at 8482493561".as_bytes().to_vec(),
+            None,
+        );
+        entity.add_tag("customer".to_string());
+        let masked = entity.mask(&MaskPolicy::new(MaskStyle::Full));
+
+        assert!(masked.has_tag("customer".to_string()));
+        assert_eq!(
+            masked.get_note(id).unwrap().content_as_string().unwrap(),
+            "*********************".to_string()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+    use std::fmt::Display;
+
+    #[scaffolding_struct("metadata", "tags")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTags)]
+    struct Wrapper<T: serde::Serialize> {
+        value: T,
+    }
+
+    impl<T: serde::Serialize> Wrapper<T> {
+        #[scaffolding_fn("metadata", "tags")]
+        fn new(value: T) -> Self {
+            Self { value }
+        }
+    }
+
+    #[scaffolding_struct("notes")]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingNotes, ScaffoldingMasking)]
+    struct Labeled<K, V>
+    where
+        K: Clone + Display,
+        V: Clone,
+    {
+        key: K,
+        value: V,
+    }
+
+    impl<K, V> Labeled<K, V>
+    where
+        K: Clone + Display,
+        V: Clone,
+    {
+        #[scaffolding_fn("notes")]
+        fn new(key: K, value: V) -> Self {
+            Self { key, value }
+        }
+    }
+
+    #[test]
+    fn test_generic_new() {
+        let wrapper = Wrapper::new("hello".to_string());
+
+        assert_eq!(wrapper.value, "hello".to_string());
+        assert_eq!(wrapper.get_id(), wrapper.id);
+        assert_eq!(wrapper.metadata.len(), 0);
+    }
+
+    #[test]
+    fn test_generic_behavior() {
+        let mut wrapper = Wrapper::new(42);
+        wrapper.add_tag("answer".to_string());
+        wrapper.log_activity("updated".to_string(), "The value was updated".to_string());
+
+        assert!(wrapper.has_tag("answer".to_string()));
+        assert_eq!(wrapper.get_activity("updated".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_generic_serialization() {
+        let wrapper = Wrapper::new(vec![1, 2, 3]);
+        let deserialized: Wrapper<Vec<i32>> =
+            Wrapper::deserialized(wrapper.serialize().as_bytes()).unwrap();
+
+        assert_eq!(deserialized.id, wrapper.id);
+        assert_eq!(deserialized.value, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_generic_where_clause() {
+        let mut labeled = Labeled::new("color", 7u8);
+        let id = labeled.insert_note(
+            "fsmith".to_string(),
+            "Call me at 8482493561".as_bytes().to_vec(),
+            None,
+        );
+        let masked = labeled.mask(&MaskPolicy::new(MaskStyle::Full));
+
+        assert_eq!(masked.key, "color");
+        assert_eq!(masked.value, 7u8);
+        assert_eq!(
+            masked.get_note(id).unwrap().content_as_string().unwrap(),
+            "*********************".to_string()
+        );
+    }
+
+    #[test]
+    fn test_generic_collection() {
+        let mut collection: EntityCollection<Wrapper<String>> = EntityCollection::new();
+        collection.list.push(Wrapper::new("a".to_string()));
+        collection.list.push(Wrapper::new("b".to_string()));
+        assert_eq!(collection.search_not_deleted().len(), 2);
+    }
+}
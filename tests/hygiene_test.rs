@@ -0,0 +1,69 @@
+extern crate scaffolding_core;
+
+// NOTE: nothing is imported so the generated code must use fully qualified paths
+#[cfg(test)]
+mod tests {
+    #[scaffolding_core::scaffolding_struct("all", "ownership", "soft_delete", "status", "tenant")]
+    #[derive(
+        Clone,
+        Debug,
+        scaffolding_core::Deserialize,
+        scaffolding_core::Serialize,
+        scaffolding_core::ScaffoldingFull,
+        scaffolding_core::ScaffoldingBuilder,
+        scaffolding_core::ScaffoldingDuplicates,
+        scaffolding_core::ScaffoldingErasure,
+        scaffolding_core::ScaffoldingMasking,
+        scaffolding_core::ScaffoldingOwnership,
+        scaffolding_core::ScaffoldingRetention,
+        scaffolding_core::ScaffoldingSoftDelete,
+        scaffolding_core::ScaffoldingStatus,
+        scaffolding_core::ScaffoldingTenant,
+    )]
+    struct MyEntity {
+        name: String,
+    }
+
+    impl MyEntity {
+        #[scaffolding_core::scaffolding_fn("all", "ownership", "soft_delete", "status", "tenant")]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_hygiene_new() {
+        let entity = MyEntity::new("Frank");
+
+        assert_eq!(entity.name, "Frank".to_string());
+        assert_eq!(entity.created_dtm, scaffolding_core::defaults::now());
+        assert_eq!(entity.notes.len(), 0);
+        assert_eq!(entity.status, scaffolding_core::defaults::status());
+    }
+
+    #[test]
+    fn test_hygiene_behavior() {
+        use scaffolding_core::{
+            Scaffolding, ScaffoldingSoftDelete, ScaffoldingStatus, ScaffoldingTags,
+        };
+
+        let mut entity = MyEntity::new("Frank");
+        entity.add_tag("customer".to_string());
+        assert!(entity.transition_to("active".to_string()).is_ok());
+        entity.delete();
+
+        assert!(entity.has_tag("customer".to_string()));
+        assert!(entity.is_deleted());
+        assert_eq!(entity.get_activity("status_changed".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_hygiene_builder() {
+        let entity = MyEntity::builder().name("Frank").build().unwrap();
+
+        assert_eq!(entity.name, "Frank".to_string());
+        assert_eq!(entity.tags.len(), 0);
+    }
+}
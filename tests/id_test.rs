@@ -0,0 +1,117 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+    use std::fmt;
+
+    #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+    struct EntityId(String);
+
+    impl fmt::Display for EntityId {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "entity-{}", self.0)
+        }
+    }
+
+    impl ScaffoldingId for EntityId {
+        fn generate() -> Self {
+            EntityId(defaults::id())
+        }
+    }
+
+    #[scaffolding_struct("relationships", id_type = "u64")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingRelationships,
+        ScaffoldingRetention,
+    )]
+    struct MyNumbered {
+        name: String,
+    }
+
+    impl MyNumbered {
+        #[scaffolding_fn("relationships")]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct("default", id_type = "EntityId")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingBuilder)]
+    struct MyTyped {
+        name: String,
+    }
+
+    #[test]
+    fn test_id_type_u64() {
+        let first = MyNumbered::new("first");
+        let second = MyNumbered::new("second");
+        let id: u64 = first.get_id();
+
+        assert_eq!(id, first.id);
+        assert_ne!(first.id, second.id);
+    }
+
+    #[test]
+    fn test_id_type_newtype() {
+        let entity = MyTyped::default();
+        let built = MyTyped::builder()
+            .id(EntityId("abc".to_string()))
+            .name("Frank")
+            .build()
+            .unwrap();
+
+        assert_eq!(entity.get_id(), entity.id);
+        assert_eq!(
+            entity.id.0.len(),
+            "54324f57-9e6b-4142-b68d-1d4c86572d0a".len()
+        );
+        assert_eq!(built.get_id(), EntityId("abc".to_string()));
+        assert_eq!(built.get_id().to_string(), "entity-abc");
+    }
+
+    #[test]
+    fn test_id_type_serialize() {
+        let entity = MyNumbered::new("first");
+        let json: Value = entity.serialize().parse().unwrap();
+
+        assert_eq!(json["id"], entity.id);
+        assert_eq!(
+            MyNumbered::deserialized(entity.serialize().as_bytes())
+                .unwrap()
+                .id,
+            entity.id
+        );
+    }
+
+    #[test]
+    fn test_id_type_collection() {
+        let mut child = MyNumbered::new("child");
+        let parent = MyNumbered::new("parent");
+        let _ = child.insert_relationship("parent".to_string(), parent.id.to_string());
+        let mut collection = EntityCollection::new();
+        collection.list.push(child.clone());
+        collection.list.push(parent.clone());
+
+        let related = collection.related(&child, "parent".to_string());
+
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].id, parent.id);
+    }
+
+    #[test]
+    fn test_id_type_retention() {
+        let mut entity = MyNumbered::new("first");
+        entity.expire_now();
+        let report = entity.apply_retention(&[]);
+
+        assert_eq!(report.entities, vec![entity.id.to_string()]);
+    }
+}
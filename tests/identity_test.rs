@@ -0,0 +1,94 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+    use std::collections::{HashMap, HashSet};
+
+    #[scaffolding_struct("tags")]
+    #[derive(
+        Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingIdentity, ScaffoldingTags,
+    )]
+    struct MyEntity {
+        name: String,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("tags")]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct(id_type = "u64")]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingIdentity)]
+    enum MyEvent {
+        Opened { by: String },
+        Closed { by: String },
+    }
+
+    impl MyEvent {
+        #[scaffolding_fn]
+        fn opened(by: &str) -> Self {
+            Self::Opened { by: by.to_string() }
+        }
+    }
+
+    #[test]
+    fn test_identity_eq() {
+        let entity = MyEntity::new("Frank");
+        let mut copy = entity.clone();
+        copy.name = "Frank Sinatra".to_string();
+        copy.add_tag("singer".to_string());
+
+        assert_eq!(entity, copy);
+        assert_ne!(entity, MyEntity::new("Frank"));
+    }
+
+    #[test]
+    fn test_identity_hash() {
+        let entity = MyEntity::new("Frank");
+        let mut copy = entity.clone();
+        copy.name = "Frank Sinatra".to_string();
+
+        let mut set = HashSet::new();
+        set.insert(entity.clone());
+        set.insert(copy);
+        set.insert(MyEntity::new("Dean"));
+        assert_eq!(set.len(), 2);
+
+        let mut map = HashMap::new();
+        map.insert(entity.clone(), 1);
+        assert_eq!(map.get(&entity), Some(&1));
+    }
+
+    #[test]
+    fn test_identity_enum() {
+        let opened = MyEvent::opened("jdoe");
+        let closed = match opened.clone() {
+            MyEvent::Opened {
+                by,
+                id,
+                created_dtm,
+                modified_dtm,
+                inactive_dtm,
+                expired_dtm,
+                activity,
+            } => MyEvent::Closed {
+                by,
+                id,
+                created_dtm,
+                modified_dtm,
+                inactive_dtm,
+                expired_dtm,
+                activity,
+            },
+            closed => closed,
+        };
+
+        assert_eq!(opened, closed);
+        assert_ne!(opened, MyEvent::opened("jdoe"));
+    }
+}
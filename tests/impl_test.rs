@@ -0,0 +1,99 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("notes", "tags")]
+    #[derive(
+        Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingNotes, ScaffoldingTags,
+    )]
+    struct MyEntity {
+        name: String,
+    }
+
+    #[scaffolding_impl("notes", "tags")]
+    impl MyEntity {
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+
+        fn with_tag(name: &str, tag: &str) -> Self {
+            Self {
+                name: name.to_string(),
+                tags: vec![tag.to_string()],
+            }
+        }
+
+        fn parse(name: &str) -> Result<Self, String> {
+            match name.is_empty() {
+                true => Err("the name is missing".to_string()),
+                false => Ok(Self {
+                    name: name.to_string(),
+                }),
+            }
+        }
+
+        #[scaffolding_fn("notes", "tags")]
+        fn archived(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+                expired_dtm: defaults::now(),
+            }
+        }
+
+        fn renamed(&self, name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+                ..self.clone()
+            }
+        }
+
+        fn name(&self) -> String {
+            self.name.clone()
+        }
+    }
+
+    #[test]
+    fn test_impl_new() {
+        let entity = MyEntity::new("Frank");
+
+        assert_eq!(entity.name(), "Frank".to_string());
+        assert_eq!(entity.created_dtm, defaults::now());
+        assert_eq!(entity.expired_dtm, defaults::add_years(defaults::now(), 3));
+        assert_eq!(entity.notes.len(), 0);
+        assert_eq!(entity.tags.len(), 0);
+    }
+
+    #[test]
+    fn test_impl_returns_self() {
+        let entity = MyEntity::with_tag("Frank", "vip");
+        assert!(entity.has_tag("vip".to_string()));
+
+        let parsed = MyEntity::parse("Frank").unwrap();
+        assert_eq!(parsed.name, "Frank".to_string());
+        assert!(MyEntity::parse("").is_err());
+    }
+
+    #[test]
+    fn test_impl_struct_update() {
+        let mut entity = MyEntity::new("Frank");
+        entity.add_tag("vip".to_string());
+        let renamed = entity.renamed("Francis");
+
+        assert_eq!(renamed.name(), "Francis".to_string());
+        assert_eq!(renamed.id, entity.id);
+        assert_eq!(renamed.created_dtm, entity.created_dtm);
+        assert!(renamed.has_tag("vip".to_string()));
+    }
+
+    #[test]
+    fn test_impl_scaffolding_fn() {
+        let entity = MyEntity::archived("Frank");
+
+        assert_eq!(entity.expired_dtm, defaults::now());
+        assert_eq!(entity.notes.len(), 0);
+    }
+}
@@ -0,0 +1,82 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("tags")]
+    #[derive(Clone, Debug, Serialize, Scaffolding, ScaffoldingTags)]
+    struct View<'a> {
+        name: &'a str,
+    }
+
+    impl<'a> View<'a> {
+        #[scaffolding_fn("tags")]
+        fn new(name: &'a str) -> Self {
+            Self { name }
+        }
+    }
+
+    #[scaffolding_struct("notes")]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingNotes)]
+    struct Pair<'a, 'b: 'a, T>
+    where
+        T: Clone,
+    {
+        left: &'a str,
+        right: &'b [T],
+    }
+
+    impl<'a, 'b: 'a, T: Clone> Pair<'a, 'b, T> {
+        #[scaffolding_fn("notes")]
+        fn new(left: &'a str, right: &'b [T]) -> Self {
+            Self { left, right }
+        }
+    }
+
+    #[test]
+    fn test_lifetime_new() {
+        let name = String::from("Frank Smith");
+        let view = View::new(&name);
+
+        assert_eq!(view.name, "Frank Smith");
+        assert_eq!(view.get_id(), view.id);
+    }
+
+    #[test]
+    fn test_lifetime_behavior() {
+        let name = String::from("Frank Smith");
+        let mut view = View::new(&name);
+        view.add_tag("readonly".to_string());
+        view.log_activity("viewed".to_string(), "The view was read".to_string());
+
+        assert!(view.has_tag("readonly".to_string()));
+        assert_eq!(view.get_activity("viewed".to_string()).len(), 1);
+        assert!(view.serialize().contains("\"name\":\"Frank Smith\""));
+    }
+
+    #[test]
+    fn test_lifetime_bounds() {
+        let values = vec![1, 2, 3];
+        let mut pair = Pair::new("numbers", &values);
+        let id = pair.insert_note("fsmith".to_string(), "Hello".as_bytes().to_vec(), None);
+
+        assert_eq!(pair.left, "numbers");
+        assert_eq!(pair.right, &[1, 2, 3]);
+        assert_eq!(
+            pair.get_note(id).unwrap().content_as_string().unwrap(),
+            "Hello"
+        );
+    }
+
+    #[test]
+    fn test_lifetime_collection() {
+        let names = ["a".to_string(), "b".to_string()];
+        let mut collection = EntityCollection::new();
+        for name in names.iter() {
+            collection.list.push(View::new(name));
+        }
+
+        assert_eq!(collection.search_not_deleted().len(), 2);
+    }
+}
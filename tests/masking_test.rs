@@ -0,0 +1,183 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("addresses", "email_addresses", "notes", "phone_numbers")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingAddresses,
+        ScaffoldingEmailAddresses,
+        ScaffoldingMasking,
+        ScaffoldingNotes,
+        ScaffoldingPhoneNumbers,
+    )]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("addresses", "email_addresses", "notes", "phone_numbers")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct("tags")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMasking)]
+    struct MyTaggedEntity {}
+
+    impl MyTaggedEntity {
+        #[scaffolding_fn("tags")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    fn get_entity() -> (MyEntity, String, String, String, String) {
+        let mut entity = MyEntity::new();
+        let address = entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let email =
+            entity.insert_email_address("home".to_string(), "myemail@example.com".to_string());
+        let note = entity.insert_note(
+            "fsmith".to_string(),
+            "Call me at 8482493561".as_bytes().to_vec(),
+            None,
+        );
+        let phone = entity.insert_phone_number(
+            "home".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+        );
+        (entity, address, email, note, phone)
+    }
+
+    #[test]
+    fn test_mask_style() {
+        assert_eq!(MaskStyle::Full.mask("abc def"), "*******");
+        assert_eq!(MaskStyle::Partial.mask("abc def"), "a** d**");
+        assert_eq!(MaskStyle::Partial.mask(""), "");
+        assert_eq!(
+            MaskStyle::Hash.mask("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(MaskStyle::Unmasked.mask("abc def"), "abc def");
+    }
+
+    #[test]
+    fn test_mask_style_email() {
+        assert_eq!(
+            MaskStyle::Partial.mask_email("myemail@example.com"),
+            "m****@example.com"
+        );
+        assert_eq!(
+            MaskStyle::Partial.mask_email("not an email"),
+            "n** a* e****"
+        );
+        assert_eq!(MaskStyle::Full.mask_email("me@a.com"), "********");
+        assert_eq!(MaskStyle::Hash.mask_email("me@a.com").len(), 64);
+    }
+
+    #[test]
+    fn test_mask_style_phone() {
+        assert_eq!(MaskStyle::Partial.mask_phone("8482493561"), "******3561");
+        assert_eq!(MaskStyle::Partial.mask_phone("123"), "123");
+        assert_eq!(MaskStyle::Full.mask_phone("123"), "***");
+    }
+
+    #[test]
+    fn test_mask_phone_extension() {
+        let mut phone = PhoneNumber::new_with_extension(
+            "work".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+            "204".to_string(),
+        );
+        phone.mask(&MaskStyle::Full);
+
+        assert_eq!(phone.number, "**********".to_string());
+        assert_eq!(phone.extension, Some("***".to_string()));
+    }
+
+    #[test]
+    fn test_mask_encrypted_note() {
+        let mut note = Note::new("fsmith".to_string(), vec![0xd3, 0x00, 0x9f, 0x41], None);
+        note.encrypted = true;
+
+        let mut unmasked = note.clone();
+        unmasked.mask(&MaskStyle::Unmasked);
+        assert_eq!(unmasked.content, note.content);
+
+        note.mask(&MaskStyle::Partial);
+        assert_eq!(note.content_as_string().unwrap(), defaults::encrypted());
+        assert!(!note.encrypted);
+    }
+
+    #[test]
+    fn test_entity_mask_partial() {
+        let (entity, address, email, note, phone) = get_entity();
+        let masked = entity.mask(&MaskPolicy::new(MaskStyle::Partial));
+
+        let addr = masked.get_address(address).unwrap();
+        assert_eq!(addr.line_1, "a**** c******");
+        assert_eq!(addr.line_2, "1* M*** S*****");
+        assert_eq!(addr.line_3, "B** C**** N* 0*****");
+        assert_eq!(addr.line_4, "U**");
+        assert_eq!(addr.category, "shipping");
+        assert_eq!(
+            masked.get_email_address(email).unwrap().address,
+            "m****@example.com"
+        );
+        assert_eq!(
+            masked.get_note(note).unwrap().content_as_string().unwrap(),
+            "C*** m* a* 8*********"
+        );
+        assert_eq!(masked.get_phone_number(phone).unwrap().number, "******3561");
+
+        // the id and lifecycle attributes are kept
+        assert_eq!(masked.id, entity.id);
+        assert_eq!(masked.created_dtm, entity.created_dtm);
+    }
+
+    #[test]
+    fn test_entity_mask_policy() {
+        let (entity, address, email, note, phone) = get_entity();
+        let mut policy = MaskPolicy::new(MaskStyle::Unmasked);
+        policy.email_addresses = MaskStyle::Hash;
+        policy.notes = MaskStyle::Full;
+        let masked = entity.mask(&policy);
+
+        assert_eq!(
+            masked.get_address(address).unwrap().line_2,
+            "14 Main Street"
+        );
+        assert_eq!(
+            masked.get_email_address(email).unwrap().address,
+            MaskStyle::Hash.mask("myemail@example.com")
+        );
+        assert_eq!(
+            masked.get_note(note).unwrap().content_as_string().unwrap(),
+            "*********************"
+        );
+        assert_eq!(masked.get_phone_number(phone).unwrap().number, "8482493561");
+    }
+
+    #[test]
+    fn test_entity_mask_without_personal_data() {
+        let mut entity = MyTaggedEntity::new();
+        entity.tags.push("tag_1".to_string());
+        let masked = entity.mask(&MaskPolicy::new(MaskStyle::Full));
+
+        assert_eq!(masked.tags, entity.tags);
+    }
+}
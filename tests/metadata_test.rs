@@ -5,7 +5,7 @@ mod tests {
     use scaffolding_core::*;
 
     #[scaffolding_struct("metadata")]
-    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
     struct MyEntity {}
 
     impl MyEntity {
@@ -15,6 +15,33 @@ mod tests {
         }
     }
 
+    #[scaffolding_struct("metadata")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadata)]
+    #[scaffolding_metadata(
+        "source: required",
+        "seats: integer",
+        "tier: required bronze|silver|gold"
+    )]
+    struct MySchemaEntity {}
+
+    impl MySchemaEntity {
+        #[scaffolding_fn("metadata")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct("metadata_json")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingMetadataJson)]
+    struct MyJsonEntity {}
+
+    impl MyJsonEntity {
+        #[scaffolding_fn("metadata_json")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
     #[test]
     fn test_entity_new() {
         let mut entity = MyEntity::new();
@@ -25,4 +52,239 @@ mod tests {
             .insert("field_1".to_string(), "myvalue".to_string());
         assert_eq!(entity.metadata.len(), 1);
     }
+
+    #[test]
+    fn test_upsert_metadata() {
+        let mut entity = MyEntity::new();
+        entity.modified_dtm = 1711833619;
+
+        entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+        assert_eq!(
+            entity.get_metadata("source".to_string()),
+            Some(&"import-2024".to_string())
+        );
+        assert!(entity.modified_dtm > 1711833619);
+        assert_eq!(entity.activity.len(), 1);
+        assert_eq!(entity.activity[0].action, "metadata_changed".to_string());
+        assert_eq!(
+            entity.activity[0].data,
+            Some(json!({"key": "source", "change": "upserted"}))
+        );
+
+        // the same value isn't a change
+        entity.modified_dtm = 1711833619;
+        entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+        assert_eq!(entity.modified_dtm, 1711833619);
+        assert_eq!(entity.activity.len(), 1);
+
+        entity.upsert_metadata("source".to_string(), "import-2025".to_string());
+        assert_eq!(
+            entity.get_metadata("source".to_string()),
+            Some(&"import-2025".to_string())
+        );
+        assert_eq!(entity.activity.len(), 2);
+        // the values aren't logged
+        assert!(!entity.activity[1].description.contains("import"));
+    }
+
+    #[test]
+    fn test_remove_metadata() {
+        let mut entity = MyEntity::new();
+        entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+        entity.modified_dtm = 1711833619;
+
+        assert_eq!(entity.remove_metadata("missing".to_string()), None);
+        assert_eq!(entity.modified_dtm, 1711833619);
+        assert_eq!(entity.activity.len(), 1);
+
+        assert_eq!(
+            entity.remove_metadata("source".to_string()),
+            Some("import-2024".to_string())
+        );
+        assert!(entity.metadata.is_empty());
+        assert!(entity.modified_dtm > 1711833619);
+        assert_eq!(
+            entity.activity[1].data,
+            Some(json!({"key": "source", "change": "removed"}))
+        );
+    }
+
+    #[test]
+    fn test_get_metadata_path() {
+        let mut entity = MyJsonEntity::new();
+        entity.upsert_metadata_json(
+            "billing".to_string(),
+            json!({"plan": {"tier": "gold", "seats": 5}, "invoices": [{"id": "inv-1"}]}),
+        );
+
+        assert_eq!(
+            entity.get_metadata_path("billing.plan.tier".to_string()),
+            Some(&json!("gold"))
+        );
+        assert_eq!(
+            entity.get_metadata_path("billing.plan.seats".to_string()),
+            Some(&json!(5))
+        );
+        assert_eq!(
+            entity.get_metadata_path("billing.invoices.0.id".to_string()),
+            Some(&json!("inv-1"))
+        );
+        assert_eq!(
+            entity.get_metadata_path("billing".to_string()),
+            entity.get_metadata_json("billing".to_string())
+        );
+        assert_eq!(
+            entity.get_metadata_path("billing.invoices.1".to_string()),
+            None
+        );
+        assert_eq!(
+            entity.get_metadata_path("billing.plan.tier.name".to_string()),
+            None
+        );
+        assert_eq!(entity.get_metadata_path("shipping.plan".to_string()), None);
+    }
+
+    #[test]
+    fn test_upsert_remove_metadata_json() {
+        let mut entity = MyJsonEntity::new();
+        entity.modified_dtm = 1711833619;
+
+        entity.upsert_metadata_json("billing".to_string(), json!({"plan": "pro"}));
+        entity.upsert_metadata_json("billing".to_string(), json!({"plan": "pro"}));
+        assert!(entity.modified_dtm > 1711833619);
+        assert_eq!(entity.activity.len(), 1);
+        assert_eq!(
+            entity.activity[0].data,
+            Some(json!({"key": "billing", "change": "upserted"}))
+        );
+
+        // the values are stored as JSON, not as encoded strings
+        let loaded = MyJsonEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+        assert_eq!(loaded.metadata_json, entity.metadata_json);
+        assert!(entity
+            .serialize()
+            .contains(r#""metadata_json":{"billing":{"plan":"pro"}}"#));
+
+        assert_eq!(
+            entity.remove_metadata_json("billing".to_string()),
+            Some(json!({"plan": "pro"}))
+        );
+        assert_eq!(entity.remove_metadata_json("billing".to_string()), None);
+        assert_eq!(entity.activity.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_metadata() {
+        let mut entity = MySchemaEntity::new();
+
+        assert_eq!(
+            entity.validate_metadata(),
+            vec![
+                MetadataViolation::Missing("source".to_string()),
+                MetadataViolation::Missing("tier".to_string()),
+            ]
+        );
+
+        entity.upsert_metadata("source".to_string(), "import-2024".to_string());
+        entity.upsert_metadata("seats".to_string(), "5.5".to_string());
+        entity.upsert_metadata("tier".to_string(), "platinum".to_string());
+        let violations = entity.validate_metadata();
+        assert_eq!(
+            violations,
+            vec![
+                MetadataViolation::InvalidType("seats".to_string(), MetadataType::Integer),
+                MetadataViolation::NotAllowed("tier".to_string(), "platinum".to_string()),
+            ]
+        );
+        assert_eq!(
+            violations[0].to_string(),
+            "The metadata seats must be of type Integer."
+        );
+
+        entity.upsert_metadata("seats".to_string(), "5".to_string());
+        entity.upsert_metadata("tier".to_string(), "gold".to_string());
+        // keys that aren't in the schema are not validated
+        entity.upsert_metadata("region".to_string(), "emea".to_string());
+        assert!(entity.validate_metadata().is_empty());
+
+        // without a schema, any metadata is valid
+        assert!(MyEntity::new().metadata_schema().keys.is_empty());
+        assert!(MyEntity::new().validate_metadata().is_empty());
+    }
+
+    #[test]
+    fn test_metadata_schema_from_rules() {
+        let schema = MetadataSchema::from_rules(vec![
+            "active: boolean",
+            "ratio: number required",
+            "region",
+            ": integer",
+        ]);
+
+        assert_eq!(schema.keys.len(), 3);
+        assert_eq!(
+            schema.keys.get("ratio"),
+            Some(&MetadataRule::new(true, MetadataType::Number, Vec::new()))
+        );
+        assert_eq!(schema.keys.get("region"), Some(&MetadataRule::default()));
+        assert!(MetadataType::Number.accepts("-4.2e3"));
+        assert!(!MetadataType::Number.accepts("NaN"));
+        assert!(MetadataType::Boolean.accepts("false"));
+    }
+
+    #[test]
+    fn test_search_metadata() {
+        let mut entity = MyEntity::new();
+        entity.upsert_metadata("source".to_string(), "Import-2024".to_string());
+        entity.upsert_metadata("source_system".to_string(), "crm".to_string());
+        entity.upsert_metadata("resource".to_string(), "import-2024".to_string());
+
+        let found = entity.search_metadata("source".to_string(), "import-2024".to_string());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found.get("source"), Some(&"Import-2024".to_string()));
+        assert_eq!(
+            entity
+                .search_metadata("*source*".to_string(), String::new())
+                .len(),
+            3
+        );
+        assert_eq!(
+            entity
+                .search_metadata("source_??????".to_string(), "CRM".to_string())
+                .len(),
+            1
+        );
+        assert_eq!(
+            entity
+                .search_metadata("source?".to_string(), String::new())
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_collection_search_metadata() {
+        let mut collection = EntityCollection::new();
+        for source in ["import-2024", "import-2025", "manual"] {
+            let mut entity = MyEntity::new();
+            entity.upsert_metadata("source".to_string(), source.to_string());
+            collection.list.push(entity);
+        }
+
+        assert_eq!(
+            collection
+                .search_metadata("source".to_string(), "import-2024".to_string())
+                .len(),
+            1
+        );
+        assert_eq!(
+            collection
+                .search_metadata("*".to_string(), "import".to_string())
+                .len(),
+            2
+        );
+        assert!(collection
+            .search_metadata("target".to_string(), String::new())
+            .is_empty());
+    }
 }
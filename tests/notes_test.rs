@@ -1,3 +1,4 @@
+#![allow(clippy::assertions_on_constants)]
 extern crate scaffolding_core;
 
 #[cfg(test)]
@@ -88,6 +89,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_notes_for_access() {
+        let mut entity = MyEntity::new();
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This is public".as_bytes().to_vec(),
+            None,
+        );
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This is internal".as_bytes().to_vec(),
+            Some("internal".to_string()),
+        );
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This is confidential".as_bytes().to_vec(),
+            Some("confidential".to_string()),
+        );
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This is private".as_bytes().to_vec(),
+            Some("private".to_string()),
+        );
+
+        assert_eq!(entity.get_notes_for_access("public".to_string()).len(), 1);
+        assert_eq!(entity.get_notes_for_access("internal".to_string()).len(), 2);
+        assert_eq!(
+            entity
+                .get_notes_for_access("confidential".to_string())
+                .len(),
+            3
+        );
+        // unknown levels only match themselves
+        assert_eq!(entity.get_notes_for_access("private".to_string()).len(), 1);
+        assert_eq!(entity.get_notes_for_access("unknown".to_string()).len(), 0);
+    }
+
+    #[test]
+    fn test_get_notes_for_access_with_policy() {
+        struct AuthorsOnly;
+
+        impl AccessPolicy for AuthorsOnly {
+            fn can_access(&self, clearance: &str, access: &str) -> bool {
+                clearance == "author" || access == "public"
+            }
+        }
+
+        let mut entity = MyEntity::new();
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This is public".as_bytes().to_vec(),
+            None,
+        );
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This is confidential".as_bytes().to_vec(),
+            Some("confidential".to_string()),
+        );
+
+        assert_eq!(
+            entity
+                .get_notes_for_access_with_policy("author".to_string(), &AuthorsOnly)
+                .len(),
+            2
+        );
+        assert_eq!(
+            entity
+                .get_notes_for_access_with_policy("confidential".to_string(), &AuthorsOnly)
+                .len(),
+            1
+        );
+    }
+
     #[test]
     fn test_get_note_bad() {
         let entity = MyEntity::new();
@@ -119,6 +193,13 @@ mod tests {
 
         let search_results = entity.search_notes("thing".to_string());
         assert_eq!(search_results.len(), 2);
+
+        let borrowed = entity.search_notes_ref("thing");
+        assert_eq!(borrowed.len(), 2);
+        assert!(borrowed
+            .iter()
+            .all(|n| search_results.iter().any(|r| r.id == n.id)));
+        assert_eq!(entity.search_notes_ref("nowhere").len(), 0);
     }
 
     #[test]
@@ -162,4 +243,329 @@ mod tests {
             "This was updated again".to_string()
         );
     }
+
+    #[test]
+    fn test_note_mime_type() {
+        let mut entity = MyEntity::new();
+        let plain = entity.insert_note(
+            "fsmith".to_string(),
+            "<b>not bold</b>".as_bytes().to_vec(),
+            None,
+        );
+        let markdown = entity.insert_note_with_mime_type(
+            "fsmith".to_string(),
+            "- first\n- second".as_bytes().to_vec(),
+            None,
+            "text/markdown; charset=UTF-8".to_string(),
+        );
+        let pdf = entity.insert_note_with_mime_type(
+            "fsmith".to_string(),
+            vec![37, 80, 68, 70, 255],
+            None,
+            "application/pdf".to_string(),
+        );
+
+        let note = entity.get_note(plain).unwrap();
+        assert_eq!(note.mime_type, "text/plain".to_string());
+        assert_eq!(
+            note.content_as_markdown_html().unwrap(),
+            "<p>&lt;b&gt;not bold&lt;/b&gt;</p>\n".to_string()
+        );
+        assert_eq!(
+            entity
+                .get_note(markdown.clone())
+                .unwrap()
+                .content_as_markdown_html()
+                .unwrap(),
+            "<ul>\n<li>first</li>\n<li>second</li>\n</ul>\n".to_string()
+        );
+
+        // the untrusted HTML is escaped
+        let html = Note::new_with_mime_type(
+            "fsmith".to_string(),
+            "<img src=x onerror=alert(1)>".as_bytes().to_vec(),
+            None,
+            "text/html".to_string(),
+        );
+        assert_eq!(
+            html.content_as_markdown_html().unwrap(),
+            "<p>&lt;img src=x onerror=alert(1)&gt;</p>\n".to_string()
+        );
+        let markdown_html = Note::new_with_mime_type(
+            "fsmith".to_string(),
+            "Hi <b onclick=\"alert(1)\">there</b> [link](javascript:alert(1)) [ok](https://example.com)"
+                .as_bytes()
+                .to_vec(),
+            None,
+            "text/markdown".to_string(),
+        );
+        let rendered = markdown_html.content_as_markdown_html().unwrap();
+        assert!(!rendered.contains("<b"));
+        assert!(!rendered.contains("javascript:"));
+        assert!(rendered.contains("<a href=\"#\">link</a>"));
+        assert!(rendered.contains("<a href=\"https://example.com\">ok</a>"));
+        assert!(entity
+            .get_note(pdf)
+            .unwrap()
+            .content_as_markdown_html()
+            .is_none());
+
+        // the content type is kept when the note is modified
+        entity.modify_note(
+            markdown.clone(),
+            "fsmith".to_string(),
+            "*changed*".as_bytes().to_vec(),
+            None,
+        );
+        assert_eq!(
+            entity.get_note(markdown).unwrap().mime_type,
+            "text/markdown; charset=UTF-8".to_string()
+        );
+    }
+
+    #[test]
+    fn test_note_mime_type_default() {
+        let serialized = r#"{"id":"2d624160-16b1-49ce-9b90-09a82127d6ac","created_dtm":1711833619,"modified_dtm":1711833619,"author":"fsmith","access":"public","content":[72,105]}"#;
+        let note = Note::deserialized(serialized.as_bytes()).unwrap();
+
+        assert_eq!(note.mime_type, "text/plain".to_string());
+    }
+
+    #[test]
+    fn test_note_thread() {
+        let mut entity = MyEntity::new();
+        let question = entity.insert_note(
+            "fsmith".to_string(),
+            "Can we ship on Monday?".as_bytes().to_vec(),
+            Some("internal".to_string()),
+        );
+        let answer = entity
+            .reply_to_note(
+                question.clone(),
+                "jdoe".to_string(),
+                "Only the first half".as_bytes().to_vec(),
+            )
+            .unwrap();
+        let follow_up = entity
+            .reply_to_note(
+                answer.clone(),
+                "fsmith".to_string(),
+                "Which half?".as_bytes().to_vec(),
+            )
+            .unwrap();
+        let other = entity
+            .reply_to_note(
+                question.clone(),
+                "mjones".to_string(),
+                "I can help".as_bytes().to_vec(),
+            )
+            .unwrap();
+        entity.notes.get_mut(&other).unwrap().created_dtm += 10;
+        let unrelated = entity.insert_note("fsmith".to_string(), "Paid".as_bytes().to_vec(), None);
+
+        // replies keep the access of the conversation
+        assert_eq!(
+            entity.get_note(follow_up.clone()).unwrap().access,
+            "internal".to_string()
+        );
+
+        let ids: Vec<String> = entity
+            .get_note_thread(follow_up.clone())
+            .iter()
+            .map(|n| n.id.clone())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![question.clone(), answer.clone(), follow_up.clone(), other]
+        );
+        assert_eq!(entity.get_note(follow_up).unwrap().parent_id, Some(answer));
+
+        assert_eq!(entity.get_note_thread(unrelated).len(), 1);
+        assert!(entity.get_note_thread("unknown".to_string()).is_empty());
+        assert!(entity
+            .reply_to_note("unknown".to_string(), "jdoe".to_string(), Vec::new())
+            .is_none());
+    }
+
+    #[test]
+    fn test_note_thread_loop() {
+        let mut entity = MyEntity::new();
+        let first = entity.insert_note("fsmith".to_string(), "First".as_bytes().to_vec(), None);
+        let second = entity
+            .reply_to_note(
+                first.clone(),
+                "jdoe".to_string(),
+                "Second".as_bytes().to_vec(),
+            )
+            .unwrap();
+        entity.notes.get_mut(&first).unwrap().parent_id = Some(second.clone());
+
+        assert_eq!(entity.get_note_thread(second).len(), 2);
+    }
+
+    #[test]
+    fn test_search_notes_by_author_and_access() {
+        let mut entity = MyEntity::new();
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This was updated".as_bytes().to_vec(),
+            None,
+        );
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "The salary was raised".as_bytes().to_vec(),
+            Some("confidential".to_string()),
+        );
+        let _ = entity.insert_note(
+            "jdoe".to_string(),
+            "This was reviewed".as_bytes().to_vec(),
+            Some("internal".to_string()),
+        );
+
+        assert_eq!(entity.search_notes_by_author("fsmith".to_string()).len(), 2);
+        assert_eq!(entity.search_notes_by_author("jdoe".to_string()).len(), 1);
+        assert_eq!(entity.search_notes_by_author("JDOE".to_string()).len(), 0);
+        assert_eq!(entity.search_notes_by_author_ref("fsmith").len(), 2);
+
+        let confidential = entity.search_notes_by_access("confidential".to_string());
+        assert_eq!(confidential.len(), 1);
+        assert_eq!(confidential[0].author, "fsmith".to_string());
+        assert_eq!(
+            entity.search_notes_by_access("internal".to_string()).len(),
+            1
+        );
+        assert_eq!(entity.search_notes_by_access("secret".to_string()).len(), 0);
+    }
+
+    #[test]
+    fn test_list_notes() {
+        let mut entity = MyEntity::new();
+        let mut ids = Vec::new();
+        for i in 0..25 {
+            let id = entity.insert_note(
+                format!("author_{:02}", i % 5),
+                format!("Note {}", i).as_bytes().to_vec(),
+                None,
+            );
+            entity.notes.get_mut(&id).unwrap().created_dtm = 1711833619 + i;
+            ids.push(id);
+        }
+        assert_eq!(entity.count_notes(), 25);
+
+        // the newest 20 notes
+        let newest = entity.list_notes(NoteSort::Created, SortOrder::Descending, 0, 20);
+        assert_eq!(newest.len(), 20);
+        assert_eq!(newest[0].id, ids[24]);
+        assert_eq!(newest[19].id, ids[5]);
+
+        // the second page
+        let page = entity.list_notes(NoteSort::Created, SortOrder::Descending, 20, 20);
+        assert_eq!(page.len(), 5);
+        assert_eq!(page[4].id, ids[0]);
+
+        let oldest = entity.list_notes(NoteSort::Created, SortOrder::Ascending, 0, 1);
+        assert_eq!(oldest[0].id, ids[0]);
+
+        let by_author = entity.list_notes(NoteSort::Author, SortOrder::Ascending, 0, 25);
+        assert_eq!(by_author[0].author, "author_00".to_string());
+        assert_eq!(by_author[24].author, "author_04".to_string());
+
+        assert!(entity
+            .list_notes(NoteSort::Modified, SortOrder::Ascending, 30, 10)
+            .is_empty());
+        assert!(entity
+            .list_notes(NoteSort::Access, SortOrder::Ascending, 0, 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_export_import_notes() {
+        let dir = std::env::temp_dir().join(defaults::id());
+        let path = dir.to_string_lossy().to_string();
+        let mut entity = MyEntity::new();
+        let text = entity.insert_note(
+            "fsmith".to_string(),
+            "This was updated".as_bytes().to_vec(),
+            Some("internal".to_string()),
+        );
+        let pdf = entity.insert_note_with_mime_type(
+            "jdoe".to_string(),
+            vec![37, 80, 68, 70, 0, 255],
+            None,
+            "application/pdf".to_string(),
+        );
+        let reply = entity
+            .reply_to_note(
+                text.clone(),
+                "jdoe".to_string(),
+                "Thanks".as_bytes().to_vec(),
+            )
+            .unwrap();
+
+        assert_eq!(entity.export_notes(path.clone()).unwrap(), 3);
+        assert_eq!(
+            std::fs::read(dir.join(&pdf)).unwrap(),
+            vec![37, 80, 68, 70, 0, 255]
+        );
+        let manifest = std::fs::read_to_string(dir.join("index.json")).unwrap();
+        assert!(manifest.contains("\"author\": \"fsmith\""));
+        assert!(!manifest.contains("content"));
+
+        let mut copy = MyEntity::new();
+        assert_eq!(copy.import_notes(path.clone()).unwrap(), 3);
+        for id in [text, pdf, reply] {
+            let original = entity.get_note(id.clone()).unwrap();
+            let imported = copy.get_note(id).unwrap();
+            assert_eq!(imported.content, original.content);
+            assert_eq!(imported.author, original.author);
+            assert_eq!(imported.access, original.access);
+            assert_eq!(imported.created_dtm, original.created_dtm);
+            assert_eq!(imported.mime_type, original.mime_type);
+            assert_eq!(imported.parent_id, original.parent_id);
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_export_import_notes_errors() {
+        let dir = std::env::temp_dir().join(defaults::id());
+        let path = dir.to_string_lossy().to_string();
+        let mut entity = MyEntity::new();
+
+        // nothing to import, (the io error is kept as the source)
+        match entity.import_notes(path.clone()) {
+            Err(errors::ScaffoldingError::Storage(source)) => {
+                assert!(source.downcast_ref::<std::io::Error>().is_some())
+            }
+            _ => panic!("expected a storage error"),
+        }
+
+        // ids that aren't safe file names
+        let id = entity.insert_note("fsmith".to_string(), "Hi".as_bytes().to_vec(), None);
+        let mut note = entity.notes.remove(&id).unwrap();
+        note.id = "../outside".to_string();
+        entity.notes.insert(note.id.clone(), note);
+        assert!(entity.export_notes(path.clone()).is_err());
+
+        // a missing note file
+        std::fs::write(
+            dir.join("index.json"),
+            r#"[{"id":"missing","created_dtm":1711833619,"modified_dtm":1711833619,"author":"fsmith","access":"public"}]"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            entity.import_notes(path.clone()),
+            Err(errors::ScaffoldingError::Storage(_))
+        ));
+        assert_eq!(entity.notes.len(), 1);
+
+        // a manifest that isn't valid
+        std::fs::write(dir.join("index.json"), "[{").unwrap();
+        let err = entity.import_notes(path).unwrap_err();
+        assert!(matches!(err, errors::ScaffoldingError::Deserialize { .. }));
+        assert!(err.line().is_some());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
 }
@@ -0,0 +1,101 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("ownership")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingOwnership)]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("ownership")]
+        fn new(owner: &str) -> Self {
+            Self {
+                owner: owner.to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_entity_new() {
+        let entity = MyEntity::new("fsmith");
+
+        assert_eq!(entity.owner, "fsmith".to_string());
+        assert_eq!(entity.shared_with.len(), 0);
+        assert!(entity.can_read("fsmith".to_string()));
+        assert!(entity.can_write("fsmith".to_string()));
+        assert!(!entity.can_read("jdoe".to_string()));
+        assert!(!entity.can_write("jdoe".to_string()));
+    }
+
+    #[test]
+    fn test_entity_share() {
+        let mut entity = MyEntity::new("fsmith");
+        entity.share("jdoe".to_string(), Permission::Read);
+        entity.share("bjones".to_string(), Permission::Write);
+
+        assert!(entity.can_read("jdoe".to_string()));
+        assert!(!entity.can_write("jdoe".to_string()));
+        assert!(entity.can_read("bjones".to_string()));
+        assert!(entity.can_write("bjones".to_string()));
+
+        // replaces the permission
+        entity.share("jdoe".to_string(), Permission::Write);
+        assert_eq!(entity.shared_with.len(), 2);
+        assert!(entity.can_write("jdoe".to_string()));
+
+        entity.unshare("jdoe".to_string());
+        assert_eq!(entity.shared_with.len(), 1);
+        assert!(!entity.can_read("jdoe".to_string()));
+    }
+
+    #[test]
+    fn test_entity_empty_principal() {
+        // an unowned object
+        let mut entity = MyEntity::new("");
+
+        assert!(!entity.can_read("".to_string()));
+        assert!(!entity.can_write("".to_string()));
+        assert!(!entity.can_read(" ".to_string()));
+
+        entity.share("".to_string(), Permission::Write);
+        assert!(entity.shared_with.is_empty());
+        assert!(!entity.can_write("".to_string()));
+    }
+
+    #[test]
+    fn test_entity_share_activity() {
+        let mut entity = MyEntity::new("fsmith");
+        entity.modified_dtm = 0;
+
+        entity.share("jdoe".to_string(), Permission::Read);
+        assert!(entity.modified_dtm > 0);
+        assert_eq!(
+            entity.get_activity("shared".to_string())[0].data,
+            Some(json!({"principal": "jdoe", "permission": "read"}))
+        );
+
+        entity.modified_dtm = 0;
+        entity.unshare("jdoe".to_string());
+        assert!(entity.modified_dtm > 0);
+        assert_eq!(entity.get_activity("unshared".to_string()).len(), 1);
+
+        // nothing to unshare
+        entity.unshare("jdoe".to_string());
+        assert_eq!(entity.get_activity("unshared".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_entity_ownership_serialization() {
+        let mut entity = MyEntity::new("fsmith");
+        entity.share("jdoe".to_string(), Permission::Read);
+
+        let json = entity.serialize();
+        assert!(json.contains(r#""owner":"fsmith","shared_with":[["jdoe","read"]]"#));
+
+        let deserialized = MyEntity::deserialized(json.as_bytes()).unwrap();
+        assert!(deserialized.can_read("jdoe".to_string()));
+        assert!(!deserialized.can_write("jdoe".to_string()));
+    }
+}
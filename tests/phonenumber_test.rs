@@ -1,8 +1,10 @@
+#![allow(clippy::assertions_on_constants)]
 extern crate scaffolding_core;
 
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
+    use scaffolding_core::errors::PhoneNumberError;
     use scaffolding_core::*;
 
     #[scaffolding_struct("phone_numbers")]
@@ -80,4 +82,273 @@ mod tests {
         assert_eq!(home.len(), 1);
         assert_eq!(home[0].category, "home".to_string());
     }
+
+    #[test]
+    fn test_entity_primary_phone_number() {
+        let mut entity = MyEntity::new();
+        let first = entity.insert_phone_number(
+            "work".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+        );
+        let second = entity.insert_phone_number(
+            "work".to_string(),
+            "8482493562".to_string(),
+            "USA".to_string(),
+        );
+
+        entity.set_primary_phone_number(first.clone());
+        entity.set_primary_phone_number(second.clone());
+
+        assert!(!entity.get_phone_number(first).unwrap().primary);
+        assert_eq!(
+            entity
+                .get_primary_phone_number("work".to_string())
+                .unwrap()
+                .id,
+            second
+        );
+    }
+
+    #[test]
+    fn test_phone_parse() {
+        for raw in [
+            "+1 (848) 249-3561",
+            "8482493561",
+            "18482493561",
+            "001 848 249 3561",
+        ] {
+            let phone = PhoneNumber::parse(raw, "USA").unwrap();
+            assert_eq!(phone.number, "8482493561".to_string());
+            assert_eq!(phone.country_code, "USA".to_string());
+        }
+
+        // the default country is preferred when countries share a calling code
+        let canada = PhoneNumber::parse("+1 613 555 0199", "CAN").unwrap();
+        assert_eq!(canada.country_code, "CAN".to_string());
+
+        // the longest prefix wins, (Puerto Rico)
+        let pr = PhoneNumber::parse("+1 787 555 0199", "USA").unwrap();
+        assert_eq!(pr.country_code, "PRI".to_string());
+        assert_eq!(pr.number, "7875550199".to_string());
+
+        // trunk prefix
+        let berlin = PhoneNumber::parse("030 1234567", "DEU").unwrap();
+        assert_eq!(berlin.number, "301234567".to_string());
+        let rome = PhoneNumber::parse("+39 06 1234 5678", "USA").unwrap();
+        assert_eq!(rome.number, "0612345678".to_string());
+
+        assert!(PhoneNumber::parse("123", "USA").is_none());
+        assert!(PhoneNumber::parse("8482493561", "XXX").is_none());
+        assert!(PhoneNumber::parse("+1 848 249 3561 0000 0000", "USA").is_none());
+    }
+
+    #[test]
+    fn test_phone_to_e164() {
+        let countries = Countries::new();
+        let phones = [
+            ("+1 (848) 249-3561", "USA", "+18482493561"),
+            ("(848) 249-3561", "USA", "+18482493561"),
+            ("18482493561", "USA", "+18482493561"),
+            ("+44 (0)20 7946 0958", "GBR", "+442079460958"),
+            ("020 7946 0958", "GBR", "+442079460958"),
+            ("06 1234 5678", "ITA", "+390612345678"),
+        ];
+
+        for (number, country_code, e164) in phones {
+            let phone = PhoneNumber::new(
+                "home".to_string(),
+                number.to_string(),
+                country_code.to_string(),
+            );
+            assert_eq!(phone.to_e164(&countries), Some(e164.to_string()));
+        }
+
+        let unknown = PhoneNumber::new("home".to_string(), "12345".to_string(), "XXX".to_string());
+        assert_eq!(unknown.to_e164(&countries), None);
+    }
+
+    #[test]
+    fn test_phone_validate() {
+        let countries = Countries::new();
+        let phones = [
+            ("+1 (848) 249-3561", "USA", Ok(())),
+            ("848.249.3561", "USA", Ok(())),
+            ("020 7946 0958", "GBR", Ok(())),
+            (
+                "+44 20 7946 0958",
+                "USA",
+                Err(PhoneNumberError::WrongCountryCode),
+            ),
+            ("+1 613 555 0199", "USA", Ok(())),
+            ("848-249-356", "USA", Err(PhoneNumberError::TooShort)),
+            ("848-249-35611", "USA", Err(PhoneNumberError::TooLong)),
+            (
+                "848-CALL-NOW",
+                "USA",
+                Err(PhoneNumberError::InvalidCharacters),
+            ),
+            ("12345", "XXX", Err(PhoneNumberError::UnknownCountry)),
+            // no bundled lengths
+            ("20 1234", "AFG", Ok(())),
+        ];
+
+        for (number, country_code, expected) in phones {
+            let phone = PhoneNumber::new(
+                "home".to_string(),
+                number.to_string(),
+                country_code.to_string(),
+            );
+            assert_eq!(phone.validate(&countries), expected, "{}", number);
+            assert_eq!(phone.is_valid(&countries), expected.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_phone_extension() {
+        let mut phone = PhoneNumber::new_with_extension(
+            "work".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+            "204".to_string(),
+        );
+        let loaded = PhoneNumber::deserialized(phone.serialize().as_bytes()).unwrap();
+        assert_eq!(loaded.extension, Some("204".to_string()));
+
+        phone.update(
+            "work".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+            None,
+        );
+        assert_eq!(phone.extension, None);
+        assert!(!phone.serialize().contains("extension"));
+
+        for raw in [
+            "+1 (848) 249-3561 ext. 204",
+            "848-249-3561 x204",
+            "848 249 3561 #204",
+            "848 249 3561 Extension 204",
+        ] {
+            let parsed = PhoneNumber::parse(raw, "USA").unwrap();
+            assert_eq!(parsed.number, "8482493561".to_string(), "{}", raw);
+            assert_eq!(parsed.extension, Some("204".to_string()), "{}", raw);
+        }
+        assert_eq!(
+            PhoneNumber::parse("848 249 3561", "USA").unwrap().extension,
+            None
+        );
+    }
+
+    #[test]
+    fn test_entity_phone_numbers_by_capability() {
+        let mut entity = MyEntity::new();
+        let mobile = entity.insert_phone_number(
+            "personal".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+        );
+        let office = entity.insert_phone_number(
+            "work".to_string(),
+            "8482490000".to_string(),
+            "USA".to_string(),
+        );
+
+        let phone = entity.phone_numbers.get_mut(&mobile).unwrap();
+        phone.add_capability(PhoneCapability::Mobile);
+        phone.add_capability(PhoneCapability::Sms);
+        let phone = entity.phone_numbers.get_mut(&office).unwrap();
+        phone.add_capability(PhoneCapability::Voice);
+        phone.add_capability(PhoneCapability::Fax);
+
+        let sms = entity.search_phone_numbers_by_capability(PhoneCapability::Sms);
+        assert_eq!(sms.len(), 1);
+        assert_eq!(sms[0].id, mobile);
+        assert_eq!(
+            entity.search_phone_numbers_by_capability(PhoneCapability::Fax)[0].id,
+            office
+        );
+
+        // serialized as a list of names
+        let mut phone = entity.get_phone_number(mobile).unwrap().clone();
+        assert!(phone
+            .serialize()
+            .contains(r#""capabilities":["mobile","sms"]"#));
+        phone.remove_capability(PhoneCapability::Sms);
+        let loaded = PhoneNumber::deserialized(phone.serialize().as_bytes()).unwrap();
+        assert!(loaded.has_capability(PhoneCapability::Mobile));
+        assert!(!loaded.has_capability(PhoneCapability::Sms));
+    }
+
+    #[test]
+    fn test_phone_extract_all() {
+        let text = "Thanks for meeting on 2024-03-15!\n\
+            Frank Smith, Acme Inc. (Suite 400)\n\
+            Office: 848.249.3561 x 12\n\
+            Mobile: +1 848 249 0000\n\
+            Berlin: +49 30 1234567\n\
+            Office again: (848) 249-3561";
+        let mut entity = MyEntity::new();
+
+        let phones = PhoneNumber::extract_all(text, "USA");
+        assert_eq!(phones.len(), 3);
+        assert_eq!(phones[0].number, "8482493561".to_string());
+        assert_eq!(phones[0].extension, Some("12".to_string()));
+        assert_eq!(phones[1].number, "8482490000".to_string());
+        assert_eq!(phones[2].number, "301234567".to_string());
+        assert_eq!(phones[2].country_code, "DEU".to_string());
+
+        for phone in phones {
+            entity.insert_phone_number("work".to_string(), phone.number, phone.country_code);
+        }
+        assert_eq!(entity.phone_numbers.len(), 3);
+
+        assert!(PhoneNumber::extract_all("no numbers here, 12345", "USA").is_empty());
+    }
+
+    #[test]
+    fn test_search_phone_numbers_by_number() {
+        let mut entity = MyEntity::new();
+        let us = entity.insert_phone_number(
+            "work".to_string(),
+            "848-249-3561".to_string(),
+            "USA".to_string(),
+        );
+        let de = entity.insert_phone_number(
+            "work".to_string(),
+            "301234567".to_string(),
+            "DEU".to_string(),
+        );
+
+        let found = entity.search_phone_numbers_by_number("18482493561".to_string());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, us);
+
+        let found = entity.search_phone_numbers_by_number("0049 30 1234567".to_string());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, de);
+        assert_eq!(
+            entity
+                .search_phone_numbers_by_number("030/1234567".to_string())
+                .len(),
+            1
+        );
+
+        assert_eq!(
+            entity
+                .search_phone_numbers_by_number("3561".to_string())
+                .len(),
+            1
+        );
+        assert_eq!(
+            entity.search_phone_numbers_by_number("".to_string()).len(),
+            0
+        );
+        assert_eq!(
+            entity
+                .search_phone_numbers_by_number("+44 20 7946 0958".to_string())
+                .len(),
+            0
+        );
+    }
 }
@@ -0,0 +1,227 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("relationships")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingRelationships)]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("relationships")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct("relationships", "soft_delete")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingRelationships,
+        ScaffoldingSoftDelete,
+    )]
+    struct MyDeletableEntity {}
+
+    impl MyDeletableEntity {
+        #[scaffolding_fn("relationships", "soft_delete")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[test]
+    fn test_relationship_new() {
+        let relationship = Relationship::new("parent".to_string(), "ORG-100".to_string());
+        let now = Utc::now().timestamp();
+
+        // scaffolding attributes
+        assert_eq!(
+            relationship.id.len(),
+            "54324f57-9e6b-4142-b68d-1d4c86572d0a".len()
+        );
+        assert_eq!(relationship.created_dtm, now);
+        assert_eq!(relationship.modified_dtm, now);
+        assert_eq!(relationship.kind, "parent".to_string());
+        assert_eq!(relationship.target_id, "ORG-100".to_string());
+    }
+
+    #[test]
+    fn test_relationship_serialization() {
+        let relationship = Relationship::new("employer".to_string(), "ORG-100".to_string());
+        let deserialized = Relationship::deserialized(relationship.serialize().as_bytes()).unwrap();
+
+        assert_eq!(deserialized.id, relationship.id);
+        assert_eq!(deserialized.kind, "employer".to_string());
+    }
+
+    #[test]
+    fn test_entity_relationships() {
+        let mut entity = MyEntity::new();
+        let parent = MyEntity::new();
+
+        assert_eq!(entity.relationships.len(), 0);
+
+        let rel1 = entity.insert_relationship("parent".to_string(), parent.id.clone());
+        let rel2 = entity.insert_relationship("employer".to_string(), "ORG-100".to_string());
+        let _ = entity.insert_relationship("duplicate_of".to_string(), parent.id.clone());
+
+        assert_eq!(entity.relationships.len(), 3);
+        assert_eq!(
+            entity.get_relationship(rel1).unwrap().target_id,
+            parent.id.clone()
+        );
+
+        let parents = entity.search_relationships_by_kind("parent".to_string());
+        assert_eq!(parents.len(), 1);
+        assert_eq!(parents[0].target_id, parent.id.clone());
+
+        assert_eq!(entity.search_relationships_by_target(parent.id).len(), 2);
+
+        entity.remove_relationship(rel2);
+        assert_eq!(entity.relationships.len(), 2);
+        assert_eq!(
+            entity
+                .search_relationships_by_kind("employer".to_string())
+                .len(),
+            0
+        );
+    }
+
+    fn get_hierarchy() -> (MyEntity, MyEntity, MyEntity, MyEntity) {
+        let ceo = MyEntity::new();
+        let mut vp = MyEntity::new();
+        vp.insert_relationship("parent".to_string(), ceo.id.clone());
+        let mut manager = MyEntity::new();
+        manager.insert_relationship("parent".to_string(), vp.id.clone());
+        let mut engineer = MyEntity::new();
+        engineer.insert_relationship("parent".to_string(), manager.id.clone());
+        engineer.insert_relationship("employer".to_string(), ceo.id.clone());
+
+        (ceo, vp, manager, engineer)
+    }
+
+    #[test]
+    fn test_collection_related() {
+        let (ceo, vp, manager, engineer) = get_hierarchy();
+        let mut collection = EntityCollection::new();
+        collection.list.push(ceo.clone());
+        collection.list.push(vp.clone());
+        collection.list.push(manager.clone());
+        collection.list.push(engineer.clone());
+
+        let parents = collection.related(&engineer, "parent".to_string());
+        assert_eq!(parents.len(), 1);
+        assert_eq!(parents[0].id, manager.id);
+
+        let employers = collection.related(&engineer, "employer".to_string());
+        assert_eq!(employers.len(), 1);
+        assert_eq!(employers[0].id, ceo.id);
+
+        assert_eq!(collection.related(&ceo, "parent".to_string()).len(), 0);
+    }
+
+    #[test]
+    fn test_collection_ancestors() {
+        let (ceo, vp, manager, engineer) = get_hierarchy();
+        let mut collection = EntityCollection::new();
+        collection.list.push(engineer.clone());
+        collection.list.push(manager.clone());
+        collection.list.push(vp.clone());
+        collection.list.push(ceo.clone());
+
+        let ancestors: Vec<String> = collection
+            .ancestors(&engineer, "parent".to_string())
+            .iter()
+            .map(|e| e.id.clone())
+            .collect();
+        assert_eq!(ancestors, vec![manager.id, vp.id, ceo.id.clone()]);
+        assert_eq!(collection.ancestors(&ceo, "parent".to_string()).len(), 0);
+    }
+
+    #[test]
+    fn test_collection_descendants() {
+        let (ceo, vp, manager, engineer) = get_hierarchy();
+        let mut collection = EntityCollection::new();
+        collection.list.push(engineer.clone());
+        collection.list.push(manager.clone());
+        collection.list.push(vp.clone());
+        collection.list.push(ceo.clone());
+
+        let descendants: Vec<String> = collection
+            .descendants(&ceo, "parent".to_string())
+            .iter()
+            .map(|e| e.id.clone())
+            .collect();
+        assert_eq!(descendants, vec![vp.id, manager.id, engineer.id.clone()]);
+        assert_eq!(
+            collection
+                .descendants(&engineer, "parent".to_string())
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_collection_has_cycle() {
+        let (mut ceo, vp, manager, engineer) = get_hierarchy();
+        let mut collection = EntityCollection::new();
+        collection.list.push(ceo.clone());
+        collection.list.push(vp.clone());
+        collection.list.push(manager.clone());
+        collection.list.push(engineer.clone());
+
+        assert!(!collection.has_cycle("parent".to_string()));
+        assert!(!collection.has_cycle("employer".to_string()));
+
+        // the walk terminates even though the hierarchy loops
+        ceo.insert_relationship("parent".to_string(), engineer.id.clone());
+        collection.list[0] = ceo.clone();
+        assert!(collection.has_cycle("parent".to_string()));
+        assert_eq!(collection.ancestors(&ceo, "parent".to_string()).len(), 3);
+        assert_eq!(collection.descendants(&ceo, "parent".to_string()).len(), 3);
+    }
+
+    #[test]
+    fn test_collection_has_cycle_self() {
+        let mut entity = MyEntity::new();
+        entity.insert_relationship("duplicate_of".to_string(), entity.id.clone());
+        let mut collection = EntityCollection::new();
+        collection.list.push(entity);
+
+        assert!(collection.has_cycle("duplicate_of".to_string()));
+    }
+
+    #[test]
+    fn test_collection_excludes_deleted() {
+        let ceo = MyDeletableEntity::new();
+        let mut vp = MyDeletableEntity::new();
+        vp.insert_relationship("parent".to_string(), ceo.id.clone());
+        let mut manager = MyDeletableEntity::new();
+        manager.insert_relationship("parent".to_string(), vp.id.clone());
+        let mut collection = EntityCollection::new();
+        collection.list.push(ceo.clone());
+        collection.list.push(vp.clone());
+        collection.list.push(manager.clone());
+        collection.list[0].insert_relationship("parent".to_string(), manager.id.clone());
+        assert!(collection.has_cycle("parent".to_string()));
+
+        collection.list[1].delete();
+
+        assert!(collection
+            .related(&manager, "parent".to_string())
+            .is_empty());
+        assert!(collection
+            .ancestors(&manager, "parent".to_string())
+            .is_empty());
+        assert!(collection
+            .descendants(&ceo, "parent".to_string())
+            .is_empty());
+        assert!(!collection.has_cycle("parent".to_string()));
+    }
+}
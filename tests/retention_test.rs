@@ -0,0 +1,182 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("addresses", "notes", "phone_numbers")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingAddresses,
+        ScaffoldingNotes,
+        ScaffoldingPhoneNumbers,
+        ScaffoldingRetention,
+    )]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("addresses", "notes", "phone_numbers")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    fn get_entity() -> (MyEntity, String) {
+        let mut entity = MyEntity::new();
+        let old = entity.insert_note(
+            "fsmith".to_string(),
+            "This is old".as_bytes().to_vec(),
+            None,
+        );
+        let _ = entity.insert_note(
+            "fsmith".to_string(),
+            "This is new".as_bytes().to_vec(),
+            None,
+        );
+        entity.notes.get_mut(&old).unwrap().created_dtm = defaults::add_days(defaults::now(), -731);
+        let _ = entity.insert_phone_number(
+            "home".to_string(),
+            "8482493561".to_string(),
+            "USA".to_string(),
+        );
+        entity.log_activity("old".to_string(), "This is old".to_string());
+        entity.log_activity("new".to_string(), "This is new".to_string());
+        entity.activity[0].created_dtm = defaults::add_days(defaults::now(), -731);
+
+        (entity, old)
+    }
+
+    #[test]
+    fn test_entity_apply_retention() {
+        let (mut entity, old) = get_entity();
+
+        let report = entity.apply_retention(&[
+            RetentionPolicy::RemoveOlderThan {
+                attribute: "notes".to_string(),
+                days: 730,
+            },
+            RetentionPolicy::RemoveOlderThan {
+                attribute: "phone_numbers".to_string(),
+                days: 730,
+            },
+            RetentionPolicy::RemoveOlderThan {
+                attribute: "activity".to_string(),
+                days: 730,
+            },
+        ]);
+
+        assert_eq!(entity.notes.len(), 1);
+        assert_eq!(entity.phone_numbers.len(), 1);
+        // the new activity and the pruning of the notes
+        assert_eq!(entity.activity.len(), 2);
+        assert_eq!(
+            entity.get_activity("pruned".to_string())[0]
+                .data
+                .as_ref()
+                .unwrap()["attribute"],
+            json!("notes")
+        );
+        assert_eq!(report.records.get("notes").unwrap(), &vec![old]);
+        assert!(report.records.get("phone_numbers").unwrap().is_empty());
+        assert_eq!(report.activity, 1);
+        assert!(report.entities.is_empty());
+        assert!(!report.is_empty());
+    }
+
+    #[test]
+    fn test_entity_apply_retention_activity_chain() {
+        let mut entity = MyEntity::new();
+        entity.log_activity("old".to_string(), "This is old".to_string());
+        entity.activity[0].created_dtm = defaults::add_days(defaults::now(), -731);
+        entity.enable_activity_chain();
+        entity.log_activity("new".to_string(), "This is new".to_string());
+        assert!(entity.verify_activity_chain());
+
+        let report = entity.apply_retention(&[RetentionPolicy::RemoveOlderThan {
+            attribute: "activity".to_string(),
+            days: 730,
+        }]);
+
+        assert_eq!(report.activity, 1);
+        assert!(entity.get_activity("old".to_string()).is_empty());
+        assert_eq!(entity.get_activity("pruned".to_string()).len(), 1);
+        assert!(entity.verify_activity_chain());
+    }
+
+    #[test]
+    fn test_entity_apply_retention_unknown_attribute() {
+        let (mut entity, _) = get_entity();
+
+        let report = entity.apply_retention(&[RetentionPolicy::RemoveOlderThan {
+            attribute: "tags".to_string(),
+            days: 0,
+        }]);
+
+        assert!(report.is_empty());
+        assert_eq!(report.unknown_attributes, vec!["tags".to_string()]);
+        assert_eq!(entity.notes.len(), 2);
+
+        // a typo is reported
+        let report = entity.apply_retention(&[RetentionPolicy::RemoveOlderThan {
+            attribute: "note".to_string(),
+            days: 730,
+        }]);
+        assert_eq!(report.unknown_attributes, vec!["note".to_string()]);
+        assert_eq!(entity.notes.len(), 2);
+    }
+
+    #[test]
+    fn test_entity_expire_after_inactivity() {
+        let mut entity = MyEntity::new();
+        entity.inactive_dtm = defaults::add_days(defaults::now(), -800);
+
+        entity.modified_dtm = 0;
+
+        let report = entity.apply_retention(&[RetentionPolicy::ExpireAfterInactivity { years: 7 }]);
+        assert_eq!(
+            entity.expired_dtm,
+            defaults::add_years(entity.inactive_dtm, 7)
+        );
+        assert!(report.entities.is_empty());
+        assert!(entity.modified_dtm > 0);
+        assert_eq!(
+            entity.get_activity("expiration_changed".to_string())[0].data,
+            Some(json!({"expired_dtm": entity.expired_dtm, "years": 7}))
+        );
+
+        // nothing changes when the policy is applied again
+        let _ = entity.apply_retention(&[RetentionPolicy::ExpireAfterInactivity { years: 7 }]);
+        assert_eq!(
+            entity.get_activity("expiration_changed".to_string()).len(),
+            1
+        );
+
+        let report = entity.apply_retention(&[RetentionPolicy::ExpireAfterInactivity { years: 2 }]);
+        assert_eq!(report.entities, vec![entity.id.clone()]);
+    }
+
+    #[test]
+    fn test_collection_apply_retention() {
+        let (first, old) = get_entity();
+        let mut expired = MyEntity::new();
+        expired.expired_dtm = defaults::add_days(defaults::now(), -1);
+        let mut collection = EntityCollection::new();
+        collection.list.push(first.clone());
+        collection.list.push(expired.clone());
+        collection.list.push(MyEntity::new());
+
+        let report = collection.apply_retention(&[RetentionPolicy::RemoveOlderThan {
+            attribute: "notes".to_string(),
+            days: 730,
+        }]);
+
+        assert_eq!(collection.list.len(), 2);
+        assert_eq!(report.entities, vec![expired.id]);
+        assert_eq!(report.records.get("notes").unwrap(), &vec![old]);
+        assert_eq!(collection.list[0].notes.len(), 1);
+    }
+}
@@ -0,0 +1,170 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("tags", rename_all = "camelCase")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTags)]
+    struct MyEntity {
+        first_name: String,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("tags")]
+        fn new(first_name: &str) -> Self {
+            Self {
+                first_name: first_name.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct]
+    #[scaffolding(
+        created_dtm(serde(rename = "createdAt")),
+        activity(serde(skip_serializing))
+    )]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    struct MyRecord {
+        name: String,
+    }
+
+    impl MyRecord {
+        #[scaffolding_fn]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct("all", "skip_empty")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTags)]
+    struct MyLeanEntity {}
+
+    impl MyLeanEntity {
+        #[scaffolding_fn("all")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct(rename_all = "camelCase")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding)]
+    enum MyEvent {
+        Opened { opened_by: String },
+        Closed { closed_by: String },
+    }
+
+    #[test]
+    fn test_serde_rename_all() {
+        let entity = MyEntity::new("Frank");
+        let json: Value = entity.serialize().parse().unwrap();
+
+        assert_eq!(json["firstName"], "Frank");
+        assert_eq!(json["createdDtm"], entity.created_dtm);
+        assert_eq!(json["expiredDtm"], entity.expired_dtm);
+        assert!(json.get("created_dtm").is_none());
+        assert!(json.get("tags").is_some());
+    }
+
+    #[test]
+    fn test_serde_rename_all_roundtrip() {
+        let entity = MyEntity::new("Frank");
+        let json = entity.serialize();
+        let copy = MyEntity::deserialized(json.as_bytes()).unwrap();
+
+        assert_eq!(copy.id, entity.id);
+        assert_eq!(copy.first_name, entity.first_name);
+    }
+
+    #[test]
+    fn test_serde_field_passthrough() {
+        let record = MyRecord::new("Frank");
+        let json: Value = record.serialize().parse().unwrap();
+
+        assert_eq!(json["createdAt"], record.created_dtm);
+        assert_eq!(json["modified_dtm"], record.modified_dtm);
+        assert!(json.get("created_dtm").is_none());
+        assert!(json.get("activity").is_none());
+    }
+
+    #[test]
+    fn test_serde_rename_all_enum() {
+        let event = MyEvent::Opened {
+            opened_by: "jdoe".to_string(),
+            id: defaults::id(),
+            created_dtm: 1711281600,
+            modified_dtm: defaults::now(),
+            inactive_dtm: defaults::now(),
+            expired_dtm: defaults::now(),
+            activity: Vec::new(),
+        };
+        let json: Value = event.serialize().parse().unwrap();
+
+        assert_eq!(json["Opened"]["openedBy"], "jdoe");
+        assert_eq!(json["Opened"]["createdDtm"], 1711281600);
+    }
+
+    #[test]
+    fn test_serde_skip_empty() {
+        let entity = MyLeanEntity::new();
+        let json: Value = entity.serialize().parse().unwrap();
+
+        assert!(json.get("id").is_some());
+        assert!(json.get("activity").is_some());
+        assert!(json.get("addresses").is_none());
+        assert!(json.get("email_addresses").is_none());
+        assert!(json.get("metadata").is_none());
+        assert!(json.get("notes").is_none());
+        assert!(json.get("phone_numbers").is_none());
+        assert!(json.get("relationships").is_none());
+        assert!(json.get("tags").is_none());
+    }
+
+    #[test]
+    fn test_serde_skip_empty_not_empty() {
+        let mut entity = MyLeanEntity::new();
+        entity.add_tag("customer".to_string());
+        let json: Value = entity.serialize().parse().unwrap();
+
+        assert_eq!(json["tags"], json!(["customer"]));
+    }
+
+    #[test]
+    fn test_serde_skip_empty_roundtrip() {
+        let entity = MyLeanEntity::new();
+        let copy = MyLeanEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+
+        assert_eq!(copy.id, entity.id);
+        assert_eq!(copy.notes.len(), 0);
+        assert_eq!(copy.tags.len(), 0);
+    }
+
+    #[test]
+    fn test_serialize_shared_reference() {
+        fn to_json(entity: &MyEntity, address: &Address) -> (String, String) {
+            (entity.serialize(), address.serialize())
+        }
+        let entity = MyEntity::new("Frank");
+        let address = Address::new(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 038845".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        let (entity_json, address_json) = to_json(&entity, &address);
+
+        assert_eq!(
+            MyEntity::deserialized(entity_json.as_bytes()).unwrap().id,
+            entity.id
+        );
+        assert_eq!(
+            Address::deserialized(address_json.as_bytes()).unwrap().id,
+            address.id
+        );
+    }
+}
@@ -0,0 +1,64 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("tags")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingSetters)]
+    struct MyEntity {
+        name: String,
+        count: u32,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("tags")]
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+                count: 0,
+            }
+        }
+    }
+
+    #[scaffolding_struct]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingSetters)]
+    #[scaffolding_setters("log")]
+    struct MyLoggedEntity<T: Clone> {
+        value: T,
+    }
+
+    impl<T: Clone> MyLoggedEntity<T> {
+        #[scaffolding_fn]
+        fn new(value: T) -> Self {
+            Self { value }
+        }
+    }
+
+    #[test]
+    fn test_setters() {
+        let mut entity = MyEntity::new("Frank");
+        entity.modified_dtm = 1711281600;
+        entity.set_name("Frank Sinatra");
+        entity.set_count(3u32);
+
+        assert_eq!(entity.name, "Frank Sinatra".to_string());
+        assert_eq!(entity.count, 3);
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.activity.len(), 0);
+    }
+
+    #[test]
+    fn test_setters_log() {
+        let mut entity = MyLoggedEntity::new(1);
+        entity.set_value(2);
+
+        assert_eq!(entity.value, 2);
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.get_activity("updated".to_string()).len(), 1);
+        assert_eq!(
+            entity.activity[0].description,
+            "The value attribute has been updated".to_string()
+        );
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "signing")]
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("tags")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTags)]
+    struct MyEntity {
+        b: bool,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("tags")]
+        fn new(arg: bool) -> Self {
+            Self { b: arg }
+        }
+    }
+
+    #[test]
+    fn test_entity_sign_verify() {
+        let mut entity = MyEntity::new(true);
+        entity.add_tag("tag_1".to_string());
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let signature = entity.sign(&key);
+
+        assert_eq!(signature.len(), 128);
+        assert!(entity.verify(&key.verifying_key(), &signature));
+
+        // signatures survive the round trip between services
+        let received = MyEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+        assert!(received.verify(&key.verifying_key(), &signature));
+    }
+
+    #[test]
+    fn test_entity_verify_modified() {
+        let mut entity = MyEntity::new(true);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let signature = entity.sign(&key);
+
+        entity.b = false;
+        assert!(!entity.verify(&key.verifying_key(), &signature));
+    }
+
+    #[test]
+    fn test_entity_verify_wrong_key() {
+        let entity = MyEntity::new(true);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let other = SigningKey::from_bytes(&[8u8; 32]);
+        let signature = entity.sign(&key);
+
+        assert!(!entity.verify(&other.verifying_key(), &signature));
+    }
+
+    #[test]
+    fn test_entity_verify_bad_signature() {
+        let entity = MyEntity::new(true);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+
+        assert!(!entity.verify(&key.verifying_key(), "not a signature"));
+        assert!(!entity.verify(&key.verifying_key(), "abcd"));
+    }
+}
@@ -0,0 +1,93 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("soft_delete", "tenant")]
+    #[derive(
+        Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingSoftDelete, ScaffoldingTenant,
+    )]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("soft_delete", "tenant")]
+        fn new() -> Self {
+            Self {
+                tenant_id: "acme".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_entity_new() {
+        let entity = MyEntity::new();
+
+        assert_eq!(entity.deleted_dtm, None);
+        assert!(!entity.is_deleted());
+    }
+
+    #[test]
+    fn test_entity_delete() {
+        let mut entity = MyEntity::new();
+        entity.modified_dtm = 1711802687;
+        let expired_dtm = entity.expired_dtm;
+
+        entity.delete();
+
+        assert!(entity.is_deleted());
+        assert_eq!(entity.deleted_dtm, Some(defaults::now()));
+        assert_eq!(entity.modified_dtm, defaults::now());
+        assert_eq!(entity.expired_dtm, expired_dtm);
+        assert_eq!(entity.get_activity("deleted".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_entity_restore() {
+        let mut entity = MyEntity::new();
+        entity.delete();
+
+        assert!(entity.restore().is_ok());
+        assert!(!entity.is_deleted());
+        assert_eq!(entity.deleted_dtm, None);
+        assert_eq!(entity.get_activity("restored".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_entity_restore_expired() {
+        let mut entity = MyEntity::new();
+        entity.delete();
+        entity.expired_dtm = defaults::add_days(defaults::now(), -1);
+
+        assert!(entity.restore().is_err());
+        assert!(entity.is_deleted());
+    }
+
+    #[test]
+    fn test_entity_delete_serialization() {
+        let mut entity = MyEntity::new();
+        entity.delete();
+
+        let deserialized = MyEntity::deserialized(entity.serialize().as_bytes()).unwrap();
+        assert!(deserialized.is_deleted());
+    }
+
+    #[test]
+    fn test_collection_excludes_deleted() {
+        let mut deleted = MyEntity::new();
+        deleted.delete();
+        let mut collection = EntityCollection::new();
+        collection.list.push(MyEntity::new());
+        collection.list.push(deleted.clone());
+        collection.list.push(MyEntity::new());
+
+        assert_eq!(collection.list.len(), 3);
+        assert_eq!(collection.search_not_deleted().len(), 2);
+        assert_eq!(collection.search_by_tenant("acme".to_string()).len(), 2);
+
+        // deleted entities are still available for audits
+        let audit = collection.search_deleted();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].id, deleted.id);
+    }
+}
@@ -0,0 +1,109 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("status")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingStatus)]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("status")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[scaffolding_struct("status")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingStatus)]
+    #[scaffolding_status("open -> in_progress", "in_progress -> resolved", "resolved -> open")]
+    struct MyTicket {}
+
+    impl MyTicket {
+        #[scaffolding_fn("status")]
+        fn new() -> Self {
+            Self {
+                status: "open".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_status_transitions_default() {
+        let transitions = StatusTransitions::default();
+
+        assert!(transitions.is_allowed("draft", "active"));
+        assert!(transitions.is_allowed("active", "suspended"));
+        assert!(transitions.is_allowed("suspended", "active"));
+        assert!(transitions.is_allowed("active", "closed"));
+        assert!(transitions.is_allowed("suspended", "closed"));
+        assert!(!transitions.is_allowed("closed", "active"));
+        assert!(!transitions.is_allowed("draft", "suspended"));
+        assert!(!transitions.is_allowed("unknown", "active"));
+    }
+
+    #[test]
+    fn test_status_transitions_from_rules() {
+        let transitions = StatusTransitions::from_rules(vec!["a -> b", " b->c ", "invalid"]);
+
+        assert!(transitions.is_allowed("a", "b"));
+        assert!(transitions.is_allowed("b", "c"));
+        assert!(!transitions.is_allowed("a", "c"));
+        assert_eq!(transitions.transitions.len(), 2);
+    }
+
+    #[test]
+    fn test_entity_transition_to() {
+        let mut entity = MyEntity::new();
+        entity.modified_dtm = 1711802687;
+
+        assert_eq!(entity.status, "draft".to_string());
+        assert!(entity.transition_to("active".to_string()).is_ok());
+        assert!(entity.transition_to("suspended".to_string()).is_ok());
+        assert!(entity.transition_to("closed".to_string()).is_ok());
+
+        assert_eq!(entity.status, "closed".to_string());
+        assert_eq!(entity.modified_dtm, defaults::now());
+
+        let changes = entity.get_activity("status_changed".to_string());
+        assert_eq!(changes.len(), 3);
+        assert_eq!(
+            changes[0].description,
+            "The status changed from draft to active"
+        );
+        assert_eq!(
+            changes[2].data,
+            Some(json!({"old": "suspended", "new": "closed"}))
+        );
+    }
+
+    #[test]
+    fn test_entity_transition_to_not_allowed() {
+        let mut entity = MyEntity::new();
+        entity.modified_dtm = 1711802687;
+
+        assert_eq!(
+            entity
+                .transition_to("closed".to_string())
+                .unwrap_err()
+                .to_string(),
+            "Status transition from draft to closed is not allowed.".to_string()
+        );
+        assert_eq!(entity.status, "draft".to_string());
+        assert_eq!(entity.modified_dtm, 1711802687);
+        assert_eq!(entity.activity.len(), 0);
+    }
+
+    #[test]
+    fn test_entity_custom_transitions() {
+        let mut ticket = MyTicket::new();
+
+        assert_eq!(ticket.status, "open".to_string());
+        assert!(!ticket.can_transition_to("resolved".to_string()));
+        assert!(ticket.transition_to("in_progress".to_string()).is_ok());
+        assert!(ticket.transition_to("resolved".to_string()).is_ok());
+        assert!(ticket.transition_to("open".to_string()).is_ok());
+        assert!(ticket.transition_to("active".to_string()).is_err());
+    }
+}
@@ -35,4 +35,312 @@ mod tests {
         assert!(entity.has_tag("tag_1".to_string()));
         assert!(entity.has_tag("tag_3".to_string()));
     }
+
+    #[test]
+    fn test_tags_normalized() {
+        let mut entity = MyEntity::new();
+
+        entity.add_tag("VIP".to_string());
+        entity.add_tag("vip".to_string());
+        entity.add_tag("  Key \t Account ".to_string());
+        entity.add_tag("key account".to_string());
+
+        assert_eq!(
+            entity.tags,
+            vec!["vip".to_string(), "key account".to_string()]
+        );
+        assert!(entity.has_tag("Vip ".to_string()));
+        assert!(entity.has_tag("KEY ACCOUNT".to_string()));
+
+        entity.remove_tag("KEY  account".to_string());
+        assert_eq!(entity.tags, vec!["vip".to_string()]);
+
+        // tags that were stored before they were normalized
+        entity.tags.push("Legacy Tag".to_string());
+        assert!(entity.has_tag("legacy tag".to_string()));
+        entity.remove_tag("legacy tag".to_string());
+        assert_eq!(entity.tags.len(), 1);
+    }
+
+    #[test]
+    fn test_tags_with_policy() {
+        let mut entity = MyEntity::new();
+        let exact = TagPolicy::new(false, false, false);
+
+        entity.add_tag_with_policy("VIP".to_string(), &exact);
+        entity.add_tag_with_policy("vip".to_string(), &exact);
+        entity.add_tag_with_policy(" vip".to_string(), &exact);
+        assert_eq!(entity.tags.len(), 3);
+
+        assert!(entity.has_tag_with_policy("VIP".to_string(), &exact));
+        assert!(!entity.has_tag_with_policy("Vip".to_string(), &exact));
+
+        entity.remove_tag_with_policy("vip".to_string(), &exact);
+        assert_eq!(entity.tags, vec!["VIP".to_string(), " vip".to_string()]);
+
+        // the default policy treats the remaining tags as the same tag
+        entity.remove_tag("VIP".to_string());
+        assert!(entity.tags.is_empty());
+    }
+
+    #[test]
+    fn test_try_add_tag() {
+        let mut entity = MyEntity::new();
+        let exact = TagPolicy::new(false, false, false);
+
+        assert!(entity.try_add_tag("VIP".to_string()));
+        assert!(!entity.try_add_tag(" vip ".to_string()));
+        assert!(entity.try_add_tag_with_policy("Vip".to_string(), &exact));
+        assert!(!entity.try_add_tag_with_policy("Vip".to_string(), &exact));
+        assert_eq!(entity.tags, vec!["vip".to_string(), "Vip".to_string()]);
+    }
+
+    #[test]
+    fn test_tags_hierarchical() {
+        let mut entity = MyEntity::new();
+        entity.add_tag("region".to_string());
+        entity.add_tag("region:emea:uk".to_string());
+        entity.add_tag("region:emea:de".to_string());
+        entity.add_tag("region:apac".to_string());
+        entity.add_tag("regional".to_string());
+        entity.add_tag("segment:enterprise".to_string());
+
+        assert!(entity.has_tag_prefix("region:emea".to_string()));
+        assert!(entity.has_tag_prefix("Region:APAC".to_string()));
+        assert!(!entity.has_tag_prefix("region:amer".to_string()));
+        assert!(!entity.has_tag_prefix("segment:enter".to_string()));
+
+        assert_eq!(
+            entity.tags_in_namespace("region".to_string()),
+            vec![
+                "region:emea:uk".to_string(),
+                "region:emea:de".to_string(),
+                "region:apac".to_string()
+            ]
+        );
+        assert_eq!(entity.tags_in_namespace("region:emea".to_string()).len(), 2);
+        assert!(entity
+            .tags_in_namespace("segment:enterprise".to_string())
+            .is_empty());
+
+        assert_eq!(
+            entity.search_tags_matching("region:*:uk".to_string()),
+            vec!["region:emea:uk".to_string()]
+        );
+        assert_eq!(entity.search_tags_matching("region:*".to_string()).len(), 1);
+        assert_eq!(
+            entity.search_tags_matching("region:**".to_string()).len(),
+            4
+        );
+        assert_eq!(entity.search_tags_matching("**:uk".to_string()).len(), 1);
+        assert_eq!(entity.search_tags_matching("*".to_string()).len(), 2);
+    }
+
+    #[test]
+    fn test_tags_key_value() {
+        let mut entity = MyEntity::new();
+        entity.add_tag("vip".to_string());
+        entity.add_tag_value("Priority".to_string(), "Low".to_string());
+        entity.add_tag_value("source".to_string(), "import-2024".to_string());
+        entity.add_tag_value("priority".to_string(), "high".to_string());
+
+        assert_eq!(
+            entity.tags,
+            vec![
+                "vip".to_string(),
+                "source=import-2024".to_string(),
+                "priority=high".to_string()
+            ]
+        );
+        assert_eq!(
+            entity.get_tag_value("PRIORITY".to_string()),
+            Some("high".to_string())
+        );
+        assert_eq!(entity.get_tag_value("vip".to_string()), None);
+        assert_eq!(entity.get_tag_value("missing".to_string()), None);
+
+        // search by key or key and value
+        assert!(entity.has_tag_value("vip".to_string(), None));
+        assert!(entity.has_tag_value("source".to_string(), None));
+        assert!(entity.has_tag_value("source".to_string(), Some("Import-2024".to_string())));
+        assert!(!entity.has_tag_value("source".to_string(), Some("import-2023".to_string())));
+        assert!(!entity.has_tag_value("vip".to_string(), Some("yes".to_string())));
+
+        // the key-value tags are tags
+        assert!(entity.has_tag("priority=high".to_string()));
+        entity.remove_tag("priority=high".to_string());
+        assert!(!entity.has_tag_value("priority".to_string(), None));
+    }
+
+    #[test]
+    fn test_collection_search_by_tag_value() {
+        let mut collection = EntityCollection::new();
+        for source in ["import-2024", "import-2023", "import-2024"] {
+            let mut entity = MyEntity::new();
+            entity.add_tag_value("source".to_string(), source.to_string());
+            collection.list.push(entity);
+        }
+        collection.list.push(MyEntity::new());
+
+        assert_eq!(
+            collection
+                .search_by_tag_value("source".to_string(), None)
+                .len(),
+            3
+        );
+        assert_eq!(
+            collection
+                .search_by_tag_value("source".to_string(), Some("import-2024".to_string()))
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_tags_rename_and_replace() {
+        let mut entity = MyEntity::new();
+        entity.add_tag("customer".to_string());
+        entity.add_tag("vip".to_string());
+        entity.add_tag("client".to_string());
+
+        // the position is kept
+        assert!(entity.rename_tag("VIP".to_string(), "Gold".to_string()));
+        assert_eq!(
+            entity.tags,
+            vec![
+                "customer".to_string(),
+                "gold".to_string(),
+                "client".to_string()
+            ]
+        );
+
+        // renaming to an existing tag removes the duplicate
+        assert!(entity.rename_tag("customer".to_string(), "client".to_string()));
+        assert_eq!(entity.tags, vec!["client".to_string(), "gold".to_string()]);
+
+        // renaming a missing tag or to itself is safe
+        assert!(!entity.rename_tag("prospect".to_string(), "lead".to_string()));
+        assert!(entity.rename_tag("gold".to_string(), "GOLD".to_string()));
+        assert_eq!(entity.tags, vec!["client".to_string(), "gold".to_string()]);
+
+        entity.replace_tag("client".to_string(), "partner".to_string());
+        entity.replace_tag("trial".to_string(), "paid".to_string());
+        entity.replace_tag("missing".to_string(), "gold".to_string());
+        assert_eq!(
+            entity.tags,
+            vec![
+                "partner".to_string(),
+                "gold".to_string(),
+                "paid".to_string()
+            ]
+        );
+
+        // removing a missing tag is ignored
+        entity.remove_tag("missing".to_string());
+        assert_eq!(entity.tags.len(), 3);
+    }
+
+    #[test]
+    fn test_tags_search_and_suggest() {
+        let mut entity = MyEntity::new();
+        for tag in [
+            "region:emea:ireland",
+            "regional",
+            "key account",
+            "account",
+            "accounting",
+            "vip",
+        ] {
+            entity.add_tag(tag.to_string());
+        }
+
+        assert_eq!(
+            entity.search_tags("REGION".to_string()),
+            vec!["region:emea:ireland".to_string(), "regional".to_string()]
+        );
+        assert!(entity.search_tags("emea".to_string()).is_empty());
+        assert_eq!(entity.search_tags("".to_string()).len(), 6);
+
+        assert_eq!(
+            entity.suggest_tags("acc".to_string()),
+            vec![
+                "account".to_string(),
+                "accounting".to_string(),
+                "key account".to_string()
+            ]
+        );
+        assert_eq!(
+            entity.suggest_tags("ire".to_string()),
+            vec!["region:emea:ireland".to_string()]
+        );
+        assert_eq!(
+            entity.suggest_tags("gion".to_string()),
+            vec!["region:emea:ireland".to_string(), "regional".to_string()]
+        );
+        assert_eq!(entity.suggest_tags("emr".to_string()).len(), 1);
+        assert!(entity.suggest_tags("xyz".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_collection_search_and_suggest_tags() {
+        let mut collection = EntityCollection::new();
+        for tags in [
+            vec!["vip", "region:emea"],
+            vec!["VIP"],
+            vec!["region:apac", "vendor"],
+        ] {
+            let mut entity = MyEntity::new();
+            for tag in tags {
+                entity.add_tag(tag.to_string());
+            }
+            collection.list.push(entity);
+        }
+
+        assert_eq!(
+            collection.search_tags("v".to_string()),
+            vec!["vendor".to_string(), "vip".to_string()]
+        );
+        assert_eq!(
+            collection.suggest_tags("apac".to_string()),
+            vec!["region:apac".to_string()]
+        );
+        assert_eq!(collection.suggest_tags("r".to_string()).len(), 3);
+    }
+
+    #[test]
+    fn test_collection_tag_statistics() {
+        let mut collection = EntityCollection::new();
+        for tags in [
+            vec!["vip", "customer"],
+            vec!["Customer", "region:emea"],
+            vec!["customer", "vip", "region:emea"],
+            vec![],
+        ] {
+            let mut entity = MyEntity::new();
+            for tag in tags {
+                entity.add_tag(tag.to_string());
+            }
+            collection.list.push(entity);
+        }
+        // tags stored before they were normalized are counted once per entity
+        collection.list[3].tags = vec!["VIP".to_string(), "vip ".to_string()];
+
+        let counts = collection.tag_counts();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts.get("customer"), Some(&3));
+        assert_eq!(counts.get("vip"), Some(&3));
+        assert_eq!(counts.get("region:emea"), Some(&2));
+
+        assert_eq!(collection.entities_with_tag("VIP".to_string()).len(), 3);
+        assert_eq!(
+            collection
+                .entities_with_all_tags(&["vip".to_string(), "region:emea".to_string()])
+                .len(),
+            1
+        );
+        assert_eq!(collection.entities_with_all_tags(&[]).len(), 4);
+        assert!(collection
+            .entities_with_tag("missing".to_string())
+            .is_empty());
+    }
 }
@@ -0,0 +1,77 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("tenant")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTenant)]
+    struct MyEntity {
+        b: bool,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("tenant")]
+        fn new(tenant: &str) -> Self {
+            Self {
+                b: true,
+                tenant_id: tenant.to_string(),
+            }
+        }
+    }
+
+    #[scaffolding_struct("tenant")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingTenant)]
+    struct MyDefaultEntity {
+        b: bool,
+    }
+
+    impl MyDefaultEntity {
+        #[scaffolding_fn("tenant")]
+        fn new(arg: bool) -> Self {
+            Self { b: arg }
+        }
+    }
+
+    #[test]
+    fn test_entity_new() {
+        let entity = MyDefaultEntity::new(true);
+
+        assert_eq!(entity.tenant_id, "".to_string());
+        assert!(entity.b);
+    }
+
+    #[test]
+    fn test_entity_tenant_provided() {
+        let entity = MyEntity::new("acme");
+
+        assert_eq!(entity.tenant_id, "acme".to_string());
+        assert!(entity.belongs_to_tenant("acme".to_string()));
+        assert!(!entity.belongs_to_tenant("globex".to_string()));
+    }
+
+    #[test]
+    fn test_entity_tenant_serialization() {
+        let mut entity = MyDefaultEntity::new(true);
+        entity.tenant_id = "acme".to_string();
+
+        let json = entity.serialize();
+        assert!(json.contains(r#""tenant_id":"acme""#));
+
+        let deserialized = MyDefaultEntity::deserialized(json.as_bytes()).unwrap();
+        assert!(deserialized.belongs_to_tenant("acme".to_string()));
+    }
+
+    #[test]
+    fn test_collection_search_by_tenant() {
+        let mut collection = EntityCollection::new();
+        collection.list.push(MyEntity::new("acme"));
+        collection.list.push(MyEntity::new("globex"));
+        collection.list.push(MyEntity::new("acme"));
+
+        let acme = collection.search_by_tenant("acme".to_string());
+        assert_eq!(acme.len(), 2);
+        assert!(acme.iter().all(|e| e.tenant_id == "acme"));
+        assert_eq!(collection.search_by_tenant("initech".to_string()).len(), 0);
+    }
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "timezones")]
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    fn address(country_code: &str) -> Address {
+        Address::new(
+            "office".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            country_code.to_string(),
+            country_code.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_local_time() {
+        // 2024-01-15 12:00:00 UTC
+        let dtm = 1705320000;
+
+        assert_eq!(
+            address("USA").local_time(dtm).unwrap().to_rfc3339(),
+            "2024-01-15T07:00:00-05:00"
+        );
+        assert_eq!(
+            address("jpn").local_time(dtm).unwrap().to_rfc3339(),
+            "2024-01-15T21:00:00+09:00"
+        );
+        assert!(address("XXX").local_time(dtm).is_none());
+    }
+
+    #[test]
+    fn test_local_time_region() {
+        // 2024-01-15 12:00:00 UTC
+        let dtm = 1705320000;
+        let local = |line_3: &str, country_code: &str| {
+            Address::new(
+                "office".to_string(),
+                "acmes company".to_string(),
+                "14 Main Street".to_string(),
+                line_3.to_string(),
+                country_code.to_string(),
+                country_code.to_string(),
+            )
+            .local_time(dtm)
+            .map(|t| t.to_rfc3339())
+        };
+
+        assert_eq!(
+            local("Sydney, NSW 2000", "AUS"),
+            Some("2024-01-15T23:00:00+11:00".to_string())
+        );
+        assert_eq!(
+            local("Perth, WA 6000", "AUS"),
+            Some("2024-01-15T20:00:00+08:00".to_string())
+        );
+        assert_eq!(
+            local("Toronto, ON M5H 2N2", "CAN"),
+            Some("2024-01-15T07:00:00-05:00".to_string())
+        );
+        assert_eq!(
+            local("Los Angeles, CA 90012", "USA"),
+            Some("2024-01-15T04:00:00-08:00".to_string())
+        );
+        // the time zone can't be guessed without the region
+        assert_eq!(local("Sydney", "AUS"), None);
+    }
+
+    #[test]
+    fn test_local_time_daylight_saving() {
+        let address = address("DEU");
+
+        // 2024-01-15 12:00:00 UTC and 2024-07-15 12:00:00 UTC
+        assert_eq!(
+            address.local_time(1705320000).unwrap().to_rfc3339(),
+            "2024-01-15T13:00:00+01:00"
+        );
+        assert_eq!(
+            address.local_time(1721044800).unwrap().to_rfc3339(),
+            "2024-07-15T14:00:00+02:00"
+        );
+    }
+
+    #[test]
+    fn test_timezones_are_known() {
+        let countries = Countries::new();
+
+        for country in countries.list.iter() {
+            for timezone in country
+                .timezones
+                .iter()
+                .chain(country.region_timezones.values())
+            {
+                assert!(
+                    timezone.parse::<chrono_tz::Tz>().is_ok(),
+                    "{} is not a known time zone",
+                    timezone
+                );
+            }
+            for code in country.region_timezones.keys() {
+                assert!(
+                    country.get_subdivision(code).is_some(),
+                    "{} is not a subdivision of {}",
+                    code,
+                    country.iso_3_code
+                );
+            }
+        }
+    }
+}
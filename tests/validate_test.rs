@@ -0,0 +1,215 @@
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::*;
+
+    #[scaffolding_struct("addresses", "email_addresses", "phone_numbers")]
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        Scaffolding,
+        ScaffoldingAddresses,
+        ScaffoldingEmailAddresses,
+        ScaffoldingPhoneNumbers,
+        ScaffoldingValidate,
+    )]
+    struct MyEntity {
+        #[validate(length(min = 1, max = 10))]
+        name: String,
+        #[validate(range(min = -10, max = 99.5))]
+        score: f64,
+        #[validate(required, email)]
+        login: Option<String>,
+        #[validate(length(max = 2))]
+        nicknames: Vec<String>,
+        #[validate(range(max = 5))]
+        rank: Option<u8>,
+        comment: String,
+    }
+
+    impl MyEntity {
+        #[scaffolding_fn("addresses", "email_addresses", "phone_numbers")]
+        fn new() -> Self {
+            Self {
+                name: "Frank".to_string(),
+                score: 50.0,
+                login: Some("frank@example.com".to_string()),
+                nicknames: Vec::new(),
+                rank: None,
+                comment: String::new(),
+            }
+        }
+    }
+
+    #[scaffolding_struct]
+    #[derive(Clone, Debug, Scaffolding, ScaffoldingValidate)]
+    struct MyPlainEntity {}
+
+    impl MyPlainEntity {
+        #[scaffolding_fn]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    fn fields(report: &ValidationReport) -> Vec<String> {
+        report.violations.iter().map(|v| v.field.clone()).collect()
+    }
+
+    #[test]
+    fn test_validate_ok() {
+        assert!(MyEntity::new().validate().is_ok());
+        assert!(MyPlainEntity::new().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_length() {
+        let mut entity = MyEntity::new();
+        entity.name = String::new();
+        entity.nicknames = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let report = entity.validate().unwrap_err();
+        assert_eq!(fields(&report), vec!["name", "nicknames"]);
+        assert_eq!(
+            report.violations[0].message,
+            "must have a length of at least 1"
+        );
+        assert_eq!(
+            report.violations[1].message,
+            "must have a length of at most 2"
+        );
+
+        // the characters are counted, (not the bytes)
+        entity.name = "Zoë Müller".to_string();
+        entity.nicknames.clear();
+        assert!(entity.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_range() {
+        let mut entity = MyEntity::new();
+        entity.score = -10.5;
+        entity.rank = Some(6);
+
+        let report = entity.validate().unwrap_err();
+        assert_eq!(fields(&report), vec!["score", "rank"]);
+        assert_eq!(report.violations[0].message, "must be at least -10");
+        assert_eq!(report.violations[1].message, "must be at most 5");
+
+        entity.score = 99.5;
+        entity.rank = Some(5);
+        assert!(entity.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_required_email() {
+        let mut entity = MyEntity::new();
+        entity.login = Some("frank".to_string());
+        assert_eq!(
+            entity.validate().unwrap_err().violations[0].message,
+            "must be a valid email address"
+        );
+
+        entity.login = None;
+        let report = entity.validate().unwrap_err();
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].message, "is required");
+    }
+
+    #[test]
+    fn test_validate_embedded() {
+        let mut entity = MyEntity::new();
+        let address = entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            "Big City, ZZ 038".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+        let email = entity.insert_email_address("home".to_string(), "frank@".to_string());
+        let phone = entity.insert_phone_number(
+            "home".to_string(),
+            "249-3561".to_string(),
+            "USA".to_string(),
+        );
+        entity.insert_phone_number(
+            "work".to_string(),
+            "(848) 249-3561".to_string(),
+            "USA".to_string(),
+        );
+
+        let report = entity.validate().unwrap_err();
+        assert_eq!(
+            fields(&report),
+            vec![
+                format!("addresses.{}.postal_code", address),
+                format!("addresses.{}.region", address),
+                format!("email_addresses.{}.address", email),
+                format!("phone_numbers.{}.number", phone),
+            ]
+        );
+        assert_eq!(
+            report.violations[3].message,
+            "The phone number is too short for the country."
+        );
+    }
+
+    #[test]
+    fn test_validation_report_accumulates() {
+        let mut entity = MyEntity::new();
+        entity.name = String::new();
+        entity.score = 100.0;
+        entity.login = None;
+        entity.insert_email_address("home".to_string(), "frank@".to_string());
+
+        let report = entity.validate().unwrap_err();
+        let codes: Vec<&str> = report.violations.iter().map(|v| v.code.as_str()).collect();
+
+        assert_eq!(report.len(), 4);
+        assert_eq!(codes, vec!["length", "range", "required", "email"]);
+        assert_eq!(report.get_violations("email_addresses").len(), 1);
+        assert_eq!(
+            report.to_string().lines().next().unwrap(),
+            "name must have a length of at least 1"
+        );
+    }
+
+    #[test]
+    fn test_validation_report_serde() {
+        let mut entity = MyEntity::new();
+        entity.rank = Some(9);
+        let report = entity.validate().unwrap_err();
+
+        let json = report.serialize();
+        assert_eq!(
+            json,
+            r#"{"violations":[{"field":"rank","code":"range","message":"must be at most 5"}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ValidationReport>(&json).unwrap(),
+            report
+        );
+    }
+
+    #[test]
+    fn test_validation_report_nested() {
+        use scaffolding_core::errors::ScaffoldingError;
+
+        let mut entity = MyEntity::new();
+        entity.login = None;
+        let mut report = ValidationReport::new();
+        report.add("title", "length", "must have a length of at least 1");
+        report.merge(Some("owner"), entity.validate().unwrap_err());
+
+        assert_eq!(report.violations[1].field, "owner.login");
+        assert_eq!(report.get_violations("owner").len(), 1);
+
+        let err = ScaffoldingError::from(report);
+        assert!(matches!(&err, ScaffoldingError::Validation(r) if r.len() == 2));
+        assert_eq!(err.to_string(), "The object is not valid, (2 violations).");
+    }
+}
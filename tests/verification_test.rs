@@ -0,0 +1,136 @@
+#![cfg(feature = "verification")]
+extern crate scaffolding_core;
+
+#[cfg(test)]
+mod tests {
+    use scaffolding_core::errors::ScaffoldingError;
+    use scaffolding_core::*;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    #[scaffolding_struct("addresses")]
+    #[derive(Clone, Debug, Deserialize, Serialize, Scaffolding, ScaffoldingAddresses)]
+    struct MyEntity {}
+
+    impl MyEntity {
+        #[scaffolding_fn("addresses")]
+        fn new() -> Self {
+            Self {}
+        }
+    }
+
+    // corrects the postal code of the addresses in Big City, (the rest are not deliverable)
+    struct PostOffice;
+
+    impl AddressVerifier for PostOffice {
+        async fn verify(&self, address: &Address) -> Result<AddressVerification, ScaffoldingError> {
+            match address.city.as_deref() {
+                Some("Big City") => Ok(AddressVerification::new(
+                    true,
+                    Some(vec![
+                        address.line_1.clone(),
+                        address.line_2.clone(),
+                        "Big City, NY 03884".to_string(),
+                        address.line_4.clone(),
+                    ]),
+                    0.8,
+                )),
+                Some(_) => Ok(AddressVerification::new(false, None, 0.9)),
+                None => Err(ScaffoldingError::Verification),
+            }
+        }
+    }
+
+    // the verifier is always ready, so a single poll is enough
+    fn block_on<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the future is not ready"),
+        }
+    }
+
+    fn insert(entity: &mut MyEntity, line_3: &str) -> String {
+        entity.insert_address(
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "14 Main Street".to_string(),
+            line_3.to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_verify_address_deliverable() {
+        let mut entity = MyEntity::new();
+        let id = insert(&mut entity, "Big City, NY 038845");
+
+        let verification = block_on(entity.verify_address(id.clone(), &PostOffice)).unwrap();
+
+        assert!(verification.deliverable);
+        assert_eq!(verification.confidence, 0.8);
+        assert_eq!(
+            entity.get_address(id).unwrap().verification,
+            Some(verification)
+        );
+        assert_eq!(entity.activity.len(), 1);
+        assert_eq!(entity.activity[0].action, "verified".to_string());
+    }
+
+    #[test]
+    fn test_verify_address_not_deliverable() {
+        let mut entity = MyEntity::new();
+        let id = insert(&mut entity, "Tiny Town, VT 04456");
+
+        let verification = block_on(entity.verify_address(id.clone(), &PostOffice)).unwrap();
+
+        assert!(!verification.deliverable);
+        assert_eq!(verification.corrected, None);
+        assert!(
+            !entity
+                .get_address(id)
+                .unwrap()
+                .verification
+                .as_ref()
+                .unwrap()
+                .deliverable
+        );
+    }
+
+    #[test]
+    fn test_verify_address_errors() {
+        let mut entity = MyEntity::new();
+        let id = insert(&mut entity, "");
+
+        // provider failure
+        assert!(block_on(entity.verify_address(id.clone(), &PostOffice)).is_err());
+        assert!(entity.get_address(id).unwrap().verification.is_none());
+
+        // unknown address
+        assert!(matches!(
+            block_on(entity.verify_address("unknown".to_string(), &PostOffice)),
+            Err(ScaffoldingError::NotFound { id }) if id == "unknown"
+        ));
+        assert_eq!(entity.activity.len(), 0);
+    }
+
+    #[test]
+    fn test_verification_cleared_on_update() {
+        let mut entity = MyEntity::new();
+        let id = insert(&mut entity, "Big City, NY 038845");
+        let _ = block_on(entity.verify_address(id.clone(), &PostOffice));
+
+        entity.modify_address(
+            id.clone(),
+            "shipping".to_string(),
+            "acmes company".to_string(),
+            "16 Main Street".to_string(),
+            "Big City, NY 03884".to_string(),
+            "USA".to_string(),
+            "USA".to_string(),
+        );
+
+        assert!(entity.get_address(id).unwrap().verification.is_none());
+    }
+}